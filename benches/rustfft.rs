@@ -41,6 +41,7 @@ fn bench_fft(b: &mut Bencher, len: usize) {
 // Prime lengths
 #[bench] fn complex_prime_00005(b: &mut Bencher) { bench_fft(b, 5); }
 #[bench] fn complex_prime_00017(b: &mut Bencher) { bench_fft(b, 17); }
+#[bench] fn complex_prime_00031(b: &mut Bencher) { bench_fft(b, 31); }
 #[bench] fn complex_prime_00151(b: &mut Bencher) { bench_fft(b, 151); }
 #[bench] fn complex_prime_00257(b: &mut Bencher) { bench_fft(b, 257); }
 #[bench] fn complex_prime_01009(b: &mut Bencher) { bench_fft(b, 1009); }
@@ -83,6 +84,7 @@ fn bench_good_thomas(b: &mut Bencher, width: usize, height: usize) {
 #[bench] fn good_thomas_0003_4(b: &mut Bencher) { bench_good_thomas(b,  3, 4); }
 #[bench] fn good_thomas_0004_5(b: &mut Bencher) { bench_good_thomas(b,  4, 5); }
 #[bench] fn good_thomas_0007_32(b: &mut Bencher) { bench_good_thomas(b, 7, 32); }
+#[bench] fn good_thomas_0029_31(b: &mut Bencher) { bench_good_thomas(b,  29, 31); }
 #[bench] fn good_thomas_0032_27(b: &mut Bencher) { bench_good_thomas(b,  32, 27); }
 #[bench] fn good_thomas_0256_243(b: &mut Bencher) { bench_good_thomas(b,  256, 243); }
 #[bench] fn good_thomas_2048_3(b: &mut Bencher) { bench_good_thomas(b,  2048, 3); }
@@ -135,6 +137,77 @@ fn bench_mixed_radix(b: &mut Bencher, width: usize, height: usize) {
 #[bench] fn mixed_radix_2048_3(b: &mut Bencher) { bench_mixed_radix(b,  2048, 3); }
 #[bench] fn mixed_radix_2048_2187(b: &mut Bencher) { bench_mixed_radix(b,  2048, 2187); }
 
+/// Times just the FFT execution (not allocation and pre-calculation)
+/// for a given length, specific to the FourStep algorithm
+fn bench_four_step(b: &mut Bencher, width: usize, height: usize) {
+
+    let mut planner = rustfft::FFTplanner::new(false);
+    let width_fft = planner.plan_fft(width);
+    let height_fft = planner.plan_fft(height);
+
+    let fft : Arc<FFT<_>> = Arc::new(FourStep::new(width_fft, height_fft));
+
+    let mut signal = vec![Complex{re: 0_f32, im: 0_f32}; width * height];
+    let mut spectrum = signal.clone();
+    b.iter(|| {fft.process(&mut signal, &mut spectrum);} );
+}
+
+#[bench] fn four_step_0256_0256(b: &mut Bencher) { bench_four_step(b,  256, 256); }
+#[bench] fn four_step_1024_1024(b: &mut Bencher) { bench_four_step(b,  1024, 1024); }
+#[bench] fn four_step_2048_2048(b: &mut Bencher) { bench_four_step(b,  2048, 2048); }
+#[bench] fn four_step_2048_2187(b: &mut Bencher) { bench_four_step(b,  2048, 2187); }
+
+
+/// Times just the FFT execution (not allocation and pre-calculation)
+/// for a given power-of-two length, comparing the Radix4 and split-radix algorithms
+fn bench_radix4(b: &mut Bencher, len: usize) {
+    let fft : Arc<FFT<_>> = Arc::new(Radix4::new(len, false));
+
+    let mut signal = vec![Complex{re: 0_f32, im: 0_f32}; len];
+    let mut spectrum = signal.clone();
+    b.iter(|| {fft.process(&mut signal, &mut spectrum);} );
+}
+fn bench_split_radix(b: &mut Bencher, len: usize) {
+    let fft : Arc<FFT<_>> = Arc::new(SplitRadix::new(len, false));
+
+    let mut signal = vec![Complex{re: 0_f32, im: 0_f32}; len];
+    let mut spectrum = signal.clone();
+    b.iter(|| {fft.process(&mut signal, &mut spectrum);} );
+}
+
+#[bench] fn radix4_00000064(b: &mut Bencher) { bench_radix4(b,      64); }
+#[bench] fn radix4_00000512(b: &mut Bencher) { bench_radix4(b,     512); }
+#[bench] fn radix4_00004096(b: &mut Bencher) { bench_radix4(b,    4096); }
+#[bench] fn radix4_00032768(b: &mut Bencher) { bench_radix4(b,   32768); }
+#[bench] fn radix4_00262144(b: &mut Bencher) { bench_radix4(b,  262144); }
+#[bench] fn radix4_01048576(b: &mut Bencher) { bench_radix4(b, 1048576); }
+#[bench] fn split_radix_00000064(b: &mut Bencher) { bench_split_radix(b,      64); }
+#[bench] fn split_radix_00000512(b: &mut Bencher) { bench_split_radix(b,     512); }
+#[bench] fn split_radix_00004096(b: &mut Bencher) { bench_split_radix(b,    4096); }
+#[bench] fn split_radix_00032768(b: &mut Bencher) { bench_split_radix(b,   32768); }
+#[bench] fn split_radix_00262144(b: &mut Bencher) { bench_split_radix(b,  262144); }
+#[bench] fn split_radix_01048576(b: &mut Bencher) { bench_split_radix(b, 1048576); }
+
+/// Times just the FFT execution (not allocation and pre-calculation)
+/// for a given length, specific to the Stockham algorithm
+fn bench_stockham(b: &mut Bencher, len: usize) {
+    let fft : Arc<FFT<_>> = Arc::new(Stockham::new(len, false));
+
+    let mut signal = vec![Complex{re: 0_f32, im: 0_f32}; len];
+    let mut spectrum = signal.clone();
+    b.iter(|| {fft.process(&mut signal, &mut spectrum);} );
+}
+
+#[bench] fn stockham_00000064(b: &mut Bencher) { bench_stockham(b,      64); }
+#[bench] fn stockham_00000512(b: &mut Bencher) { bench_stockham(b,     512); }
+#[bench] fn stockham_00001200(b: &mut Bencher) { bench_stockham(b,    1200); }
+#[bench] fn stockham_00004096(b: &mut Bencher) { bench_stockham(b,    4096); }
+#[bench] fn stockham_00032768(b: &mut Bencher) { bench_stockham(b,   32768); }
+#[bench] fn stockham_00048000(b: &mut Bencher) { bench_stockham(b,   48000); }
+#[bench] fn stockham_00262144(b: &mut Bencher) { bench_stockham(b,  262144); }
+#[bench] fn stockham_01048576(b: &mut Bencher) { bench_stockham(b, 1048576); }
+#[bench] fn complex_smooth_00001200(b: &mut Bencher) { bench_fft(b,  1200); }
+#[bench] fn complex_smooth_00048000(b: &mut Bencher) { bench_fft(b, 48000); }
 
 
 fn plan_butterfly(len: usize) -> Arc<FFTButterfly<f32>> {
@@ -146,12 +219,59 @@ fn plan_butterfly(len: usize) -> Arc<FFTButterfly<f32>> {
             6 => Arc::new(Butterfly6::new(false)),
             7 => Arc::new(Butterfly7::new(false)),
             8 => Arc::new(Butterfly8::new(false)),
+            9 => Arc::new(Butterfly9::new(false)),
+            11 => Arc::new(Butterfly11::new(false)),
+            12 => Arc::new(Butterfly12::new(false)),
+            13 => Arc::new(Butterfly13::new(false)),
+            15 => Arc::new(Butterfly15::new(false)),
             16 => Arc::new(Butterfly16::new(false)),
+            17 => Arc::new(Butterfly17::new(false)),
+            19 => Arc::new(Butterfly19::new(false)),
+            23 => Arc::new(Butterfly23::new(false)),
+            24 => Arc::new(Butterfly24::new(false)),
+            27 => Arc::new(Butterfly27::new(false)),
+            29 => Arc::new(Butterfly29::new(false)),
+            31 => Arc::new(Butterfly31::new(false)),
             32 => Arc::new(Butterfly32::new(false)),
+            64 => Arc::new(Butterfly64::new(false)),
             _ => panic!("Invalid butterfly size: {}", len),
         }
     }
 
+/// Times just the FFT execution (not allocation and pre-calculation)
+/// for the RadixN algorithm with the given butterfly sizes
+fn bench_radix_n(b: &mut Bencher, radices: &[usize]) {
+    let butterflies = radices.iter().map(|&radix| plan_butterfly(radix)).collect();
+    let fft : Arc<FFT<_>> = Arc::new(RadixN::new(butterflies));
+
+    let mut signal = vec![Complex{re: 0_f32, im: 0_f32}; fft.len()];
+    let mut spectrum = signal.clone();
+    b.iter(|| {fft.process(&mut signal, &mut spectrum);} );
+}
+
+#[bench] fn radix_n_00001331(b: &mut Bencher) { bench_radix_n(b, &[11, 11, 11]); }
+#[bench] fn radix_n_00016807(b: &mut Bencher) { bench_radix_n(b, &[7, 7, 7, 7, 7]); }
+#[bench] fn radix_n_00028561(b: &mut Bencher) { bench_radix_n(b, &[13, 13, 13, 13]); }
+#[bench] fn radix_n_00059049(b: &mut Bencher) { bench_radix_n(b, &[9, 9, 9, 9, 9]); }
+#[bench] fn complex_radix_n_00000441(b: &mut Bencher) { bench_fft(b,   441); }
+#[bench] fn complex_radix_n_00000504(b: &mut Bencher) { bench_fft(b,   504); }
+
+/// Times just the FFT execution (not allocation and pre-calculation)
+/// for the PrimePower algorithm of size `radix^exponent`
+fn bench_prime_power(b: &mut Bencher, radix: usize, exponent: usize) {
+    let mut planner = rustfft::FFTplanner::new(false);
+    let inner_fft = planner.plan_fft(radix);
+    let fft : Arc<FFT<_>> = Arc::new(PrimePower::new(inner_fft, exponent));
+
+    let mut signal = vec![Complex{re: 0_f32, im: 0_f32}; fft.len()];
+    let mut spectrum = signal.clone();
+    b.iter(|| {fft.process(&mut signal, &mut spectrum);} );
+}
+
+#[bench] fn prime_power_7_5(b: &mut Bencher) { bench_prime_power(b, 7, 5); }
+#[bench] fn prime_power_13_4(b: &mut Bencher) { bench_prime_power(b, 13, 4); }
+#[bench] fn prime_power_211_2(b: &mut Bencher) { bench_prime_power(b, 211, 2); }
+
 /// Times just the FFT execution (not allocation and pre-calculation)
 /// for a given length, specific to the Mixed-Radix Double Butterfly algorithm
 fn bench_mixed_radix_butterfly(b: &mut Bencher, width: usize, height: usize) {
@@ -189,6 +309,8 @@ fn bench_good_thomas_butterfly(b: &mut Bencher, width: usize, height: usize) {
 #[bench] fn good_thomas_butterfly_0003_4(b: &mut Bencher) { bench_good_thomas_butterfly(b,  3, 4); }
 #[bench] fn good_thomas_butterfly_0004_5(b: &mut Bencher) { bench_good_thomas_butterfly(b,  4, 5); }
 #[bench] fn good_thomas_butterfly_0007_32(b: &mut Bencher) { bench_good_thomas_butterfly(b, 7, 32); }
+#[bench] fn good_thomas_butterfly_0011_13(b: &mut Bencher) { bench_good_thomas_butterfly(b, 11, 13); }
+#[bench] fn good_thomas_butterfly_0023_64(b: &mut Bencher) { bench_good_thomas_butterfly(b, 23, 64); }
 
 
 /// Times just the FFT execution (not allocation and pre-calculation)
@@ -214,6 +336,26 @@ fn bench_raders(b: &mut Bencher, len: usize) {
 #[bench] fn raders_65537(b: &mut Bencher) { bench_raders(b, 65537); }
 #[bench] fn raders_746497(b: &mut Bencher) { bench_raders(b,746497); }
 
+/// Times just the FFT execution (not allocation and pre-calculation)
+/// for Rader's algorithm with its convolution zero padded to `inner_len`
+fn bench_raders_zero_padded(b: &mut Bencher, len: usize, inner_len: usize) {
+
+    let mut planner = rustfft::FFTplanner::new(false);
+    let inner_fft = planner.plan_fft(inner_len);
+
+    let fft : Arc<FFT<_>> = Arc::new(RadersAlgorithm::new(len, inner_fft));
+
+    let mut signal = vec![Complex{re: 0_f32, im: 0_f32}; len];
+    let mut spectrum = signal.clone();
+    b.iter(|| {fft.process(&mut signal, &mut spectrum);} );
+}
+
+// 10006 = 2 * 5003, so without padding, the inner FFTs need Rader's algorithm again
+#[bench] fn raders_10007(b: &mut Bencher) { bench_raders(b, 10007); }
+#[bench] fn raders_zero_padded_10007_20250(b: &mut Bencher) { bench_raders_zero_padded(b, 10007, 20250); }
+#[bench] fn raders_zero_padded_10007_32768(b: &mut Bencher) { bench_raders_zero_padded(b, 10007, 32768); }
+#[bench] fn raders_zero_padded_65537_131072(b: &mut Bencher) { bench_raders_zero_padded(b, 65537, 131072); }
+
 /// Times just the FFT setup (not execution)
 /// for a given length, specific to Rader's algorithm
 fn bench_raders_setup(b: &mut Bencher, len: usize) {
@@ -234,4 +376,52 @@ fn bench_raders_setup(b: &mut Bencher, len: usize) {
 #[bench] fn raders_setup_1009(b: &mut Bencher) { bench_raders_setup(b,  1009); }
 #[bench] fn raders_setup_2017(b: &mut Bencher) { bench_raders_setup(b,  2017); }
 #[bench] fn raders_setup_65537(b: &mut Bencher) { bench_raders_setup(b, 65537); }
-#[bench] fn raders_setup_746497(b: &mut Bencher) { bench_raders_setup(b,746497); }
\ No newline at end of file
+#[bench] fn raders_setup_746497(b: &mut Bencher) { bench_raders_setup(b,746497); }
+/// Times just the FFT execution (not allocation and pre-calculation)
+/// for a FixedFFT of size N
+#[cfg(feature = "const-generics")]
+fn bench_fixed<const N: usize>(b: &mut Bencher) {
+    let fft = FixedFFT::<f32, N>::new(false);
+
+    let mut signal = [Complex{re: 0_f32, im: 0_f32}; N];
+    let mut spectrum = signal;
+    b.iter(|| {fft.process(&mut signal, &mut spectrum);} );
+}
+
+#[cfg(feature = "const-generics")] #[bench] fn fixed_0016(b: &mut Bencher) { bench_fixed::<16>(b); }
+#[cfg(feature = "const-generics")] #[bench] fn fixed_0064(b: &mut Bencher) { bench_fixed::<64>(b); }
+#[cfg(feature = "const-generics")] #[bench] fn fixed_0441(b: &mut Bencher) { bench_fixed::<441>(b); }
+#[cfg(feature = "const-generics")] #[bench] fn fixed_1024(b: &mut Bencher) { bench_fixed::<1024>(b); }
+
+/// Times just the FFT execution (not allocation and pre-calculation)
+/// for a given length, using fixed-point data
+fn bench_fixed_point<T: rustfft::fixed_point::FixedPoint + Default>(b: &mut Bencher, len: usize) {
+    let fft = rustfft::fixed_point::FixedPointFFT::<T>::new(len, false);
+
+    let signal = vec![Complex{re: T::default(), im: T::default()}; len];
+    let mut spectrum = signal.clone();
+    b.iter(|| {fft.process(&signal, &mut spectrum);} );
+}
+
+#[bench] fn fixed_point_q15_0256(b: &mut Bencher) { bench_fixed_point::<i16>(b, 256); }
+#[bench] fn fixed_point_q15_1024(b: &mut Bencher) { bench_fixed_point::<i16>(b, 1024); }
+#[bench] fn fixed_point_q15_1000(b: &mut Bencher) { bench_fixed_point::<i16>(b, 1000); }
+#[bench] fn fixed_point_q31_1024(b: &mut Bencher) { bench_fixed_point::<i32>(b, 1024); }
+
+/// Times just the FFT execution (not allocation and pre-calculation)
+/// for a given length, using half-precision data
+#[cfg(feature = "half")]
+fn bench_half(b: &mut Bencher, len: usize) {
+    use rustfft::half::f16;
+    use rustfft::num_traits::Zero;
+
+    let mut planner = rustfft::FFTplanner::<f16>::new(false);
+    let fft = planner.plan_fft(len);
+
+    let mut signal = vec![Complex::<f16>::zero(); len];
+    let mut spectrum = signal.clone();
+    b.iter(|| {fft.process(&mut signal, &mut spectrum);} );
+}
+
+#[cfg(feature = "half")] #[bench] fn half_f16_1024(b: &mut Bencher) { bench_half(b, 1024); }
+#[cfg(feature = "half")] #[bench] fn half_f16_1200(b: &mut Bencher) { bench_half(b, 1200); }
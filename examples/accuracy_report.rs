@@ -0,0 +1,111 @@
+//! Measures the numerical accuracy of planned FFTs over a range of sizes, for both f32 and f64
+//!
+//! For each size, we report two relative RMS errors:
+//!  - "tone": the forward FFT of a pure complex tone, whose exact spectrum is a single spike of height `len`
+//!  - "roundtrip": a random signal passed through a forward FFT, then an inverse FFT, then normalized
+//!
+//! Both compare against values that are known exactly, rather than against another FFT library's output. The errors
+//! are then checked against the fixed bounds `MAX_F32_ERROR` and `MAX_F64_ERROR`.
+//!
+//! Run with `cargo run --release --example accuracy_report`. CI runs it too, and it exits with an error if any error
+//! is above those bounds, so that accuracy regressions don't go unnoticed.
+
+extern crate rustfft;
+extern crate rand;
+
+use std::f64;
+use std::process;
+
+use rand::{StdRng, SeedableRng};
+use rand::distributions::{Normal, Distribution};
+
+use rustfft::{FFTplanner, FFTnum};
+use rustfft::num_complex::Complex;
+use rustfft::num_traits::{Zero, ToPrimitive};
+
+/// The largest errors this example accepts. They aren't derived from an error analysis: They're regression thresholds,
+/// set to two to three times the largest error measured for any of the sizes below, which leaves room for differences
+/// between platforms
+const MAX_F32_ERROR: f64 = 1e-6;
+const MAX_F64_ERROR: f64 = 2e-15;
+
+const RNG_SEED: [u8; 32] = [1, 9, 1, 0, 1, 1, 4, 3, 1, 4, 9, 8,
+    4, 1, 4, 8, 2, 8, 1, 2, 2, 2, 6, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+/// Computes the relative RMS difference between `actual` and `expected`, in f64
+fn relative_rms_error<T: FFTnum + ToPrimitive>(actual: &[Complex<T>], expected: &[Complex<f64>]) -> f64 {
+    let mut error_sum = 0f64;
+    let mut magnitude_sum = 0f64;
+    for (a, e) in actual.iter().zip(expected.iter()) {
+        let a = Complex::new(a.re.to_f64().unwrap(), a.im.to_f64().unwrap());
+        error_sum += (a - e).norm_sqr();
+        magnitude_sum += e.norm_sqr();
+    }
+    (error_sum / magnitude_sum).sqrt()
+}
+
+fn tone_error<T: FFTnum + ToPrimitive>(len: usize) -> f64 {
+    // pick a frequency that isn't a simple fraction of the length, and compute the tone with exact index reduction
+    let frequency = len / 3 + 1;
+    let mut signal: Vec<Complex<T>> = (0..len).map(|i| {
+        let phase = ((i as u128 * frequency as u128) % len as u128) as f64 / len as f64;
+        let angle = 2f64 * f64::consts::PI * phase;
+        let value = Complex::new(angle.cos(), angle.sin());
+        Complex::new(T::from_f64(value.re).unwrap(), T::from_f64(value.im).unwrap())
+    }).collect();
+    let mut spectrum = vec![Zero::zero(); len];
+
+    let mut expected = vec![Complex::zero(); len];
+    expected[frequency % len] = Complex::new(len as f64, 0f64);
+
+    let mut planner = FFTplanner::new(false);
+    planner.plan_fft(len).process(&mut signal, &mut spectrum);
+
+    relative_rms_error(&spectrum, &expected)
+}
+
+fn roundtrip_error<T: FFTnum + ToPrimitive>(len: usize) -> f64 {
+    let normal = Normal::new(0.0, 1.0);
+    let mut rng: StdRng = SeedableRng::from_seed(RNG_SEED);
+    let original: Vec<Complex<f64>> = (0..len).map(|_| Complex::new(normal.sample(&mut rng), normal.sample(&mut rng))).collect();
+
+    let mut signal: Vec<Complex<T>> = original.iter().map(|c| Complex::new(T::from_f64(c.re).unwrap(), T::from_f64(c.im).unwrap())).collect();
+    let mut spectrum = vec![Zero::zero(); len];
+
+    let mut forward_planner = FFTplanner::new(false);
+    let mut inverse_planner = FFTplanner::new(true);
+    forward_planner.plan_fft(len).process(&mut signal, &mut spectrum);
+    inverse_planner.plan_fft(len).process(&mut spectrum, &mut signal);
+
+    let scale = T::from_f64(1f64 / len as f64).unwrap();
+    for element in &mut signal {
+        *element = *element * scale;
+    }
+
+    // compare against the input after rounding it to T, so that we only measure the error added by the FFTs
+    let rounded: Vec<Complex<f64>> = original.iter().map(|c| Complex::new(
+        T::from_f64(c.re).unwrap().to_f64().unwrap(),
+        T::from_f64(c.im).unwrap().to_f64().unwrap())).collect();
+    relative_rms_error(&signal, &rounded)
+}
+
+fn main() {
+    let sizes = [64, 1000, 1024, 1201, 4096, 10000, 16384, 65536, 65537, 100000, 262144, 1048576];
+
+    let mut passed = true;
+    println!("{:>8} | {:>12} {:>12} | {:>12} {:>12}", "len", "f32 tone", "f32 trip", "f64 tone", "f64 trip");
+    for &len in &sizes {
+        let f32_errors = [tone_error::<f32>(len), roundtrip_error::<f32>(len)];
+        let f64_errors = [tone_error::<f64>(len), roundtrip_error::<f64>(len)];
+        println!("{:>8} | {:>12.3e} {:>12.3e} | {:>12.3e} {:>12.3e}",
+            len, f32_errors[0], f32_errors[1], f64_errors[0], f64_errors[1]);
+
+        passed &= f32_errors.iter().all(|&error| error < MAX_F32_ERROR);
+        passed &= f64_errors.iter().all(|&error| error < MAX_F64_ERROR);
+    }
+
+    if !passed {
+        println!("error above the limit of {:e} for f32 or {:e} for f64", MAX_F32_ERROR, MAX_F64_ERROR);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,251 @@
+//! Measures how long each FFT algorithm takes next to the default cost model's estimate for it, so that the cost
+//! model's constants can be checked, or retuned, on a particular machine
+//!
+//! Run with `cargo run --release --example tune_cost_model`, optionally followed by the log2 of the largest power of
+//! two to time, which defaults to 22. Every time is the fastest of several runs, since on a busy machine, the noise
+//! only ever makes a run slower.
+//!
+//! The first table times each algorithm by itself. The cost model's methods only estimate the cost of the algorithm
+//! itself, not of its inner FFTs, so for algorithms with inner FFTs, we time the inner FFTs separately on the same
+//! amount of data and subtract them. The planner only ever compares estimates with each other, so the units don't
+//! matter: the cost model is well tuned when the last column, nanoseconds per unit of estimated cost, is about the
+//! same for every row. To retune a constant, scale it by how far its rows' ratios are from the others'.
+//!
+//! The second table times whole power-of-two FFTs with each algorithm that can compute them, including FourStep with
+//! the planner's inner FFTs, next to the FFT the planner returns. This is where a cache penalty that's too large or
+//! too small shows up.
+//!
+//! The default constants were last tuned with two runs of this example with f32 on a single-core x86-64 VM (48K L1,
+//! 2M L2). Timing that machine was noisy, so individual rows varied by up to a factor of two between runs, and a
+//! constant was only changed when all of its rows disagreed with the rest. These are the ranges of nanoseconds per unit
+//! it measured:
+//!
+//! ~~~text
+//! method                         sizes           ns per unit
+//! dft                            16, 64          0.82 - 1.76
+//! butterfly                      4 - 32          0.29 - 0.97
+//! butterfly                      11, 23          0.45 - 1.14
+//! radix4                         2^10 - 2^24     0.58 - 2.41
+//! stockham                       1024 - 2^18     0.57 - 1.59
+//! stockham                       2^20 - 2^24     0.61 - 0.75
+//! radix_n                        441, 8064       0.82 - 1.43
+//! radix_n                        2^20            1.89 - 2.17
+//! prime_power                    7^6, 211^2      0.20 - 2.37
+//! mixed_radix                    1152 - 2^18     0.76 - 1.21
+//! mixed_radix                    2^19 - 2^24     0.27 - 1.95
+//! four_step                      1152 - 2^18     0.62 - 1.63
+//! four_step                      2^19 - 2^24     0.68 - 1.55
+//! mixed_radix_double_butterfly   143, 224        0.60 - 1.74
+//! good_thomas                    4032            0.79 - 2.59
+//! good_thomas                    1025024         0.94 - 1.09
+//! good_thomas_double_butterfly   143, 224        0.38 - 1.58
+//! raders                         1201, 10007     0.25 - 1.52
+//! raders_zero_padded             10007           0.71 - 0.93
+//! ~~~
+//!
+//! `prime_power`, `raders`, and the smaller two-factor sizes spend most of their time in their inner FFTs, so their
+//! ratios are the noisiest: They're the difference between two much larger times.
+//!
+//! On the same machine, SplitRadix took 1.07 to 1.35 times as long as Radix4 for every power of two from 2^6 to 2^22,
+//! which is why the planner doesn't use it.
+
+extern crate rustfft;
+
+use std::cmp;
+use std::env;
+use std::f64;
+use std::sync::Arc;
+use std::time::Instant;
+
+use rustfft::{FFT, FFTplanner, CostModel, DefaultCostModel, Length};
+use rustfft::algorithm::*;
+use rustfft::algorithm::butterflies::*;
+use rustfft::num_complex::Complex;
+use rustfft::num_traits::Zero;
+
+/// Each time is the fastest of this many runs
+const RUNS: usize = 11;
+
+/// Each run repeats the FFT until it has taken at least this long
+const MIN_RUN_NANOS: f64 = 2e7;
+
+fn main() {
+    let max_bits: u32 = env::args().nth(1).map(|arg| arg.parse().expect("max_bits must be an integer")).unwrap_or(22);
+    let cost_model = DefaultCostModel;
+    let mut planner = FFTplanner::<f32>::new(false);
+
+    println!("{:<28} {:>10} {:>14} {:>14} {:>10}", "algorithm", "len", "nanoseconds", "estimate", "ns/unit");
+
+    for &len in &[16, 64] {
+        let fft = DFT::new(len, false);
+        report("dft", len, time_fft(&fft), cost_model.dft(len));
+    }
+
+    // each butterfly is timed over many consecutive copies, since a single one is too fast to time accurately
+    let butterflies: Vec<(&str, Arc<FFTButterfly<f32>>, f64)> = vec![
+        ("butterfly", Arc::new(Butterfly4::new(false)), cost_model.butterfly(4)),
+        ("butterfly", Arc::new(Butterfly8::new(false)), cost_model.butterfly(8)),
+        ("butterfly", Arc::new(Butterfly16::new(false)), cost_model.butterfly(16)),
+        ("butterfly", Arc::new(Butterfly32::new(false)), cost_model.butterfly(32)),
+        ("butterfly", Arc::new(Butterfly11::new(false)), cost_model.butterfly(11)),
+        ("butterfly", Arc::new(Butterfly23::new(false)), cost_model.butterfly(23)),
+    ];
+    for (name, butterfly, estimate) in butterflies {
+        let copies = 1024;
+        let nanos = time_butterflies(&*butterfly, butterfly.len() * copies) / copies as f64;
+        report(name, butterfly.len(), nanos, estimate);
+    }
+
+    let power_of_two_bits: Vec<u32> = vec![10, 14, 18, 20, 22, 24].into_iter().filter(|&bits| bits <= max_bits).collect();
+    for &bits in &power_of_two_bits {
+        let len = 1 << bits;
+        report("radix4", len, time_fft(&Radix4::new(len, false)), cost_model.radix4(len));
+    }
+    for &bits in &power_of_two_bits {
+        let len = 1 << bits;
+        report("stockham", len, time_fft(&Stockham::new(len, false)), cost_model.stockham(len));
+    }
+    for &len in &[1200, 48000] {
+        report("stockham", len, time_fft(&Stockham::new(len, false)), cost_model.stockham(len));
+    }
+
+    for radices in &[vec![9, 7, 7], vec![16, 8, 9, 7], vec![16, 16, 16, 16, 16]] {
+        let butterflies: Vec<Arc<FFTButterfly<f32>>> = radices.iter().map(|&radix| plan_butterfly(radix)).collect();
+        let fft = RadixN::new(butterflies.clone());
+        let len = fft.len();
+        let inner_nanos: f64 = butterflies.iter().map(|butterfly| time_butterflies(&**butterfly, len)).sum();
+        report("radix_n", len, time_fft(&fft) - inner_nanos, cost_model.radix_n(len, radices.len()));
+    }
+
+    for &(radix, exponent) in &[(7, 6), (211, 2)] {
+        let inner_fft = planner.plan_fft(radix);
+        let fft = PrimePower::new(Arc::clone(&inner_fft), exponent);
+        let len = fft.len();
+        let inner_nanos = exponent as f64 * time_multi(&*inner_fft, len);
+        report("prime_power", len, time_fft(&fft) - inner_nanos, cost_model.prime_power(len, exponent));
+    }
+
+    let splits: Vec<(usize, usize)> = vec![(32, 36), (256, 256), (512, 1024), (1024, 1024), (2048, 2048), (4096, 4096)]
+        .into_iter().filter(|&(width, height)| width * height <= 1 << max_bits).collect();
+    for &(width, height) in &splits {
+        let (width_fft, height_fft) = (planner.plan_fft(width), planner.plan_fft(height));
+        let fft = MixedRadix::new(Arc::clone(&width_fft), Arc::clone(&height_fft));
+        let inner_nanos = time_inner(&*width_fft, &*height_fft);
+        report("mixed_radix", fft.len(), time_fft(&fft) - inner_nanos, cost_model.mixed_radix(width, height));
+    }
+    for &(width, height) in &splits {
+        let (width_fft, height_fft) = (planner.plan_fft(width), planner.plan_fft(height));
+        let fft = FourStep::new(Arc::clone(&width_fft), Arc::clone(&height_fft));
+        let inner_nanos = time_inner(&*width_fft, &*height_fft);
+        report("four_step", fft.len(), time_fft(&fft) - inner_nanos, cost_model.four_step(width, height));
+    }
+    for &(width, height) in &[(7, 32), (11, 13)] {
+        let (width_fft, height_fft) = (plan_butterfly(width), plan_butterfly(height));
+        let fft = MixedRadixDoubleButterfly::new(Arc::clone(&width_fft), Arc::clone(&height_fft));
+        let inner_nanos = time_butterflies(&*width_fft, fft.len()) + time_butterflies(&*height_fft, fft.len());
+        report("mixed_radix_double_butterfly", fft.len(), time_fft(&fft) - inner_nanos,
+            cost_model.mixed_radix_double_butterfly(width, height));
+    }
+    for &(width, height) in &[(63, 64), (1001, 1024)] {
+        let (width_fft, height_fft) = (planner.plan_fft(width), planner.plan_fft(height));
+        let fft = GoodThomasAlgorithm::new(Arc::clone(&width_fft), Arc::clone(&height_fft));
+        let inner_nanos = time_inner(&*width_fft, &*height_fft);
+        report("good_thomas", fft.len(), time_fft(&fft) - inner_nanos, cost_model.good_thomas(width, height));
+    }
+    for &(width, height) in &[(7, 32), (11, 13)] {
+        let (width_fft, height_fft) = (plan_butterfly(width), plan_butterfly(height));
+        let fft = GoodThomasAlgorithmDoubleButterfly::new(Arc::clone(&width_fft), Arc::clone(&height_fft));
+        let inner_nanos = time_butterflies(&*width_fft, fft.len()) + time_butterflies(&*height_fft, fft.len());
+        report("good_thomas_double_butterfly", fft.len(), time_fft(&fft) - inner_nanos,
+            cost_model.good_thomas_double_butterfly(width, height));
+    }
+
+    for &(len, inner_len) in &[(1201, 1200), (10007, 10006), (10007, 20250)] {
+        let inner_fft = planner.plan_fft(inner_len);
+        let fft = RadersAlgorithm::new(len, Arc::clone(&inner_fft));
+        let inner_nanos = 2.0 * time_fft(&*inner_fft);
+        let estimate = if inner_len == len - 1 { cost_model.raders(len) } else { cost_model.raders_zero_padded(len, inner_len) };
+        report(if inner_len == len - 1 { "raders" } else { "raders_zero_padded" }, len, time_fft(&fft) - inner_nanos, estimate);
+    }
+
+    println!();
+    println!("{:<10} {:>12} {:>12} {:>12} {:>12} {:>12}", "len", "radix4", "split_radix", "stockham", "four_step",
+        "planner");
+    for bits in 16..max_bits + 1 {
+        let len = 1 << bits;
+        let width_fft = planner.plan_fft(1 << (bits / 2));
+        let height_fft = planner.plan_fft(1 << (bits - bits / 2));
+        let per_element = |nanos: f64| nanos / len as f64;
+        println!("2^{:<8} {:>12.2} {:>12.2} {:>12.2} {:>12.2} {:>12.2}", bits,
+            per_element(time_fft(&Radix4::new(len, false))),
+            per_element(time_fft(&SplitRadix::new(len, false))),
+            per_element(time_fft(&Stockham::new(len, false))),
+            per_element(time_fft(&FourStep::new(width_fft, height_fft))),
+            per_element(time_fft(&*planner.plan_fft(len))));
+    }
+    println!("(nanoseconds per element)");
+}
+
+fn report(name: &str, len: usize, nanos: f64, estimate: f64) {
+    println!("{:<28} {:>10} {:>14.0} {:>14.0} {:>10.3}", name, len, nanos, estimate, nanos / estimate);
+}
+
+/// Returns the fastest time, in nanoseconds, that `f` took over several runs
+fn time<F: FnMut()>(mut f: F) -> f64 {
+    // the first call warms up the caches, and tells us how many calls make a run long enough to time accurately
+    let start = Instant::now();
+    f();
+    let reps = cmp::max(1, (MIN_RUN_NANOS / nanos_since(start).max(1.0)) as usize);
+
+    let mut best = f64::MAX;
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        for _ in 0..reps {
+            f();
+        }
+        best = best.min(nanos_since(start) / reps as f64);
+    }
+    best
+}
+
+fn nanos_since(start: Instant) -> f64 {
+    let elapsed = start.elapsed();
+    elapsed.as_secs() as f64 * 1e9 + elapsed.subsec_nanos() as f64
+}
+
+fn time_fft(fft: &FFT<f32>) -> f64 {
+    time_multi(fft, fft.len())
+}
+
+/// Times `fft` processing `total_len` elements, as consecutive FFTs of its size
+fn time_multi(fft: &FFT<f32>, total_len: usize) -> f64 {
+    let mut input = vec![Complex::zero(); total_len];
+    let mut output = vec![Complex::zero(); total_len];
+    time(|| fft.process_multi(&mut input, &mut output))
+}
+
+/// Times the inner FFTs of a two-factor algorithm, which computes `height` FFTs of size `width` and `width` FFTs of
+/// size `height`
+fn time_inner(width_fft: &FFT<f32>, height_fft: &FFT<f32>) -> f64 {
+    let len = width_fft.len() * height_fft.len();
+    time_multi(width_fft, len) + time_multi(height_fft, len)
+}
+
+/// Times `butterfly` processing `total_len` elements, as consecutive butterflies
+fn time_butterflies(butterfly: &FFTButterfly<f32>, total_len: usize) -> f64 {
+    let mut buffer = vec![Complex::zero(); total_len];
+    time(|| unsafe { butterfly.process_multi_inplace(&mut buffer) })
+}
+
+fn plan_butterfly(len: usize) -> Arc<FFTButterfly<f32>> {
+    match len {
+        7 => Arc::new(Butterfly7::new(false)),
+        8 => Arc::new(Butterfly8::new(false)),
+        9 => Arc::new(Butterfly9::new(false)),
+        11 => Arc::new(Butterfly11::new(false)),
+        13 => Arc::new(Butterfly13::new(false)),
+        16 => Arc::new(Butterfly16::new(false)),
+        32 => Arc::new(Butterfly32::new(false)),
+        _ => panic!("Invalid butterfly size: {}", len),
+    }
+}
@@ -0,0 +1,523 @@
+//! Generates straight-line butterfly kernels for RustFFT
+//!
+//! Usage, from the root of the repository:
+//!
+//! ~~~text
+//! cargo run -p gen-butterflies > src/algorithm/butterflies_generated.rs
+//! ~~~
+//!
+//! By default, this generates every size in `DEFAULT_SIZES`. Passing sizes on the command line generates those
+//! sizes instead. Passing `--min-multiplications` generates each prime with whichever algorithm below needs the fewest
+//! multiplications, for targets where multiplications are much more expensive than additions.
+//!
+//! Each butterfly is generated by recursively splitting its size, the same way the planner would, but with every
+//! intermediate value kept in a local variable instead of a buffer:
+//!
+//! - Sizes 1, 2, and 4 are written out directly. Size 4 needs no multiplications at all.
+//! - Other primes fold each pair of inputs k and p - k into their sum and difference, since those inputs are always
+//!   multiplied by conjugate twiddle factors. Outputs m and p - m are then computed together from the same two sums,
+//!   which roughly halves the number of multiplications compared to a naive DFT.
+//! - With `--min-multiplications`, primes can instead be computed the way Winograd's small-prime FFTs are: Rader's
+//!   reordering turns the DFT into a cyclic convolution of size p - 1, which is computed with two generated DFTs of
+//!   size p - 1 and a multiplication by a precomputed constant for each element. This needs far fewer multiplications
+//!   for primes from 11 up, but more additions, and the checked-in butterflies weren't faster with it on x86-64, so
+//!   it isn't the default.
+//! - Sizes with two coprime factors use a step of the Good-Thomas Algorithm, which needs no twiddle factors.
+//! - Other sizes use a step of the Mixed-Radix algorithm, preferring radix 4. Twiddle factors of 1, -1, and +/-i are
+//!   applied with a negation or a rotation instead of a multiplication.
+//!
+//! The twiddle factors are stored in the butterfly struct, so the generated code works for both forward and inverse
+//! FFTs, and for both f32 and f64.
+
+use std::env;
+use std::f64;
+use std::fmt::Write;
+
+/// The sizes generated when none are given on the command line
+const DEFAULT_SIZES: [usize; 12] = [9, 11, 12, 13, 15, 17, 19, 23, 24, 27, 29, 31];
+
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let min_multiplications = args.iter().any(|arg| arg == "--min-multiplications");
+    args.retain(|arg| arg != "--min-multiplications");
+
+    let sizes: Vec<usize> = if args.is_empty() {
+        DEFAULT_SIZES.to_vec()
+    } else {
+        args.iter().map(|arg| arg.parse().expect("sizes must be positive integers")).collect()
+    };
+
+    print!("{}", generate_file(&sizes, min_multiplications));
+}
+
+fn generate_file(sizes: &[usize], min_multiplications: bool) -> String {
+    let mut file = String::new();
+    writeln!(file, "// This file is generated by the gen-butterflies crate. Don't edit it by hand. Instead, run").unwrap();
+    writeln!(file, "// `cargo run -p gen-butterflies > src/algorithm/butterflies_generated.rs` from the root of the repository").unwrap();
+    writeln!(file).unwrap();
+    writeln!(file, "use num_complex::Complex;").unwrap();
+    writeln!(file).unwrap();
+    writeln!(file, "use common::{{FFTnum, verify_length, verify_length_divisible}};").unwrap();
+    writeln!(file).unwrap();
+    writeln!(file, "use twiddles;").unwrap();
+    writeln!(file, "use ::{{Length, IsInverse, FFT}};").unwrap();
+    writeln!(file, "use super::FFTButterfly;").unwrap();
+
+    for &size in sizes {
+        assert!(size > 0, "sizes must be positive integers");
+        writeln!(file).unwrap();
+        writeln!(file).unwrap();
+        writeln!(file).unwrap();
+        file.push_str(&generate_butterfly(size, min_multiplications));
+    }
+    file
+}
+
+fn generate_butterfly(len: usize, min_multiplications: bool) -> String {
+    let mut generator = Generator {
+        lines: Vec::new(),
+        next_temp: 0,
+        constants: Vec::new(),
+        multiplications: 0,
+        min_multiplications,
+    };
+
+    let inputs: Vec<String> = (0..len).map(|i| {
+        let name = format!("x{}", i);
+        generator.lines.push(format!("let {} = *buffer.get_unchecked({});", name, i));
+        name
+    }).collect();
+    let outputs = generator.dft(&inputs);
+
+    let mut code = String::new();
+    writeln!(code, "pub struct Butterfly{}<T> {{", len).unwrap();
+    writeln!(code, "    twiddles: [Complex<T>; {}],", generator.constants.len()).unwrap();
+    writeln!(code, "    inverse: bool,").unwrap();
+    writeln!(code, "}}").unwrap();
+    writeln!(code, "impl<T: FFTnum> Butterfly{}<T> {{", len).unwrap();
+    writeln!(code, "    pub fn new(inverse: bool) -> Self {{").unwrap();
+    writeln!(code, "        Butterfly{} {{", len).unwrap();
+    if generator.constants.is_empty() {
+        writeln!(code, "            twiddles: [],").unwrap();
+    } else {
+        writeln!(code, "            twiddles: [").unwrap();
+        for constant in &generator.constants {
+            match *constant {
+                Constant::Twiddle { index, len } =>
+                    writeln!(code, "                twiddles::single_twiddle({}, {}, inverse),", index, len).unwrap(),
+                Constant::RaderKernel { len, primitive_root, index } => {
+                    // the inverse kernel is the conjugate of the forward kernel
+                    let (re, im) = rader_kernel(len, primitive_root, index);
+                    writeln!(code, "                Complex {{ re: T::from_f64({:?}).unwrap(), im: T::from_f64(if inverse {{ {:?} }} else {{ {:?} }}).unwrap() }},",
+                        re, -im, im).unwrap()
+                }
+            }
+        }
+        writeln!(code, "            ],").unwrap();
+    }
+    writeln!(code, "            inverse,").unwrap();
+    writeln!(code, "        }}").unwrap();
+    writeln!(code, "    }}").unwrap();
+    writeln!(code, "}}").unwrap();
+    writeln!(code, "impl<T: FFTnum> FFTButterfly<T> for Butterfly{}<T> {{", len).unwrap();
+    writeln!(code, "    #[inline(always)]").unwrap();
+    writeln!(code, "    unsafe fn process_inplace(&self, buffer: &mut [Complex<T>]) {{").unwrap();
+    for line in &generator.lines {
+        writeln!(code, "        {}", line).unwrap();
+    }
+    for (i, output) in outputs.iter().enumerate() {
+        writeln!(code, "        *buffer.get_unchecked_mut({}) = {};", i, output).unwrap();
+    }
+    writeln!(code, "    }}").unwrap();
+    writeln!(code, "    #[inline(always)]").unwrap();
+    writeln!(code, "    unsafe fn process_multi_inplace(&self, buffer: &mut [Complex<T>]) {{").unwrap();
+    writeln!(code, "        for chunk in buffer.chunks_mut(self.len()) {{").unwrap();
+    writeln!(code, "            self.process_inplace(chunk);").unwrap();
+    writeln!(code, "        }}").unwrap();
+    writeln!(code, "    }}").unwrap();
+    writeln!(code, "}}").unwrap();
+    writeln!(code, "boilerplate_fft_butterfly!(Butterfly{}, {});", len, len).unwrap();
+    code
+}
+
+/// A value stored in a butterfly's `twiddles` array, computed when the butterfly is created
+#[derive(Clone, Copy, PartialEq)]
+enum Constant {
+    /// `twiddles::single_twiddle(index, len, inverse)`. `index` and `len` are always coprime, so each twiddle factor has
+    /// exactly one representation
+    Twiddle { index: usize, len: usize },
+
+    /// Element `index` of the DFT of the kernel that Rader's Algorithm convolves a prime-size input with, divided by the
+    /// kernel's length `len - 1`. These are written into the generated code as literals, see `rader_kernel`
+    RaderKernel { len: usize, primitive_root: usize, index: usize },
+}
+
+/// Emits the body of a single butterfly's `process_inplace` method, one `let` statement at a time
+#[derive(Clone)]
+struct Generator {
+    lines: Vec<String>,
+    next_temp: usize,
+
+    // the contents of the butterfly's `twiddles` array
+    constants: Vec<Constant>,
+
+    // the number of real multiplications in the emitted code, which is what we minimize when there's a choice
+    multiplications: usize,
+    min_multiplications: bool,
+}
+
+impl Generator {
+    /// Emits a statement computing `expression`, and returns the name of the variable holding its value
+    fn temp(&mut self, expression: String) -> String {
+        let name = format!("t{}", self.next_temp);
+        self.next_temp += 1;
+        self.lines.push(format!("let {} = {};", name, expression));
+        name
+    }
+
+    /// Returns an expression for `constant`, adding it to the struct if necessary
+    fn constant(&mut self, constant: Constant) -> String {
+        let position = match self.constants.iter().position(|&existing| existing == constant) {
+            Some(position) => position,
+            None => {
+                self.constants.push(constant);
+                self.constants.len() - 1
+            }
+        };
+        format!("self.twiddles[{}]", position)
+    }
+
+    /// Returns an expression for twiddle factor `index` of a FFT of size `len`
+    fn twiddle(&mut self, index: usize, len: usize) -> String {
+        let index = index % len;
+        let divisor = gcd(index, len);
+        self.constant(Constant::Twiddle { index: index / divisor, len: len / divisor })
+    }
+
+    /// Returns the name of a variable holding `value` multiplied by twiddle factor `index` of a FFT of size `len`
+    fn multiply_twiddle(&mut self, value: &str, index: usize, len: usize) -> String {
+        let index = index % len;
+        if index == 0 {
+            value.to_string()
+        } else if 2 * index == len {
+            self.temp(format!("-{}", value))
+        } else if 4 * index == len {
+            self.temp(format!("twiddles::rotate_90({}, self.inverse)", value))
+        } else if 4 * index == 3 * len {
+            self.temp(format!("-twiddles::rotate_90({}, self.inverse)", value))
+        } else {
+            let twiddle = self.twiddle(index, len);
+            self.multiplications += 4;
+            self.temp(format!("{} * {}", value, twiddle))
+        }
+    }
+
+    /// Emits a DFT of `inputs`, and returns the names of the variables holding its outputs, in order
+    fn dft(&mut self, inputs: &[String]) -> Vec<String> {
+        let len = inputs.len();
+        match len {
+            1 => inputs.to_vec(),
+            2 => vec![
+                self.temp(format!("{} + {}", inputs[0], inputs[1])),
+                self.temp(format!("{} - {}", inputs[0], inputs[1])),
+            ],
+            4 => self.dft4(inputs),
+            _ => {
+                let factor = smallest_factor(len);
+                if factor == len {
+                    self.dft_prime(inputs)
+                } else if let Some((width, height)) = coprime_split(len) {
+                    self.dft_good_thomas(inputs, width, height)
+                } else if len % 4 == 0 {
+                    self.dft_mixed_radix(inputs, 4)
+                } else {
+                    self.dft_mixed_radix(inputs, factor)
+                }
+            }
+        }
+    }
+
+    fn dft4(&mut self, inputs: &[String]) -> Vec<String> {
+        let sum02 = self.temp(format!("{} + {}", inputs[0], inputs[2]));
+        let diff02 = self.temp(format!("{} - {}", inputs[0], inputs[2]));
+        let sum13 = self.temp(format!("{} + {}", inputs[1], inputs[3]));
+        let diff13 = self.temp(format!("{} - {}", inputs[1], inputs[3]));
+
+        // multiplying by twiddle 1 of a size 4 FFT is just a rotation
+        let rotated = self.temp(format!("twiddles::rotate_90({}, self.inverse)", diff13));
+        vec![
+            self.temp(format!("{} + {}", sum02, sum13)),
+            self.temp(format!("{} + {}", diff02, rotated)),
+            self.temp(format!("{} - {}", sum02, sum13)),
+            self.temp(format!("{} - {}", diff02, rotated)),
+        ]
+    }
+
+    /// Emits a pair-folding DFT of a prime size, or with `--min-multiplications`, whichever of the pair-folding and
+    /// Rader-Winograd DFTs needs fewer multiplications
+    fn dft_prime(&mut self, inputs: &[String]) -> Vec<String> {
+        if !self.min_multiplications {
+            return self.dft_prime_folded(inputs);
+        }
+
+        let mut folded = self.clone();
+        let folded_outputs = folded.dft_prime_folded(inputs);
+
+        let mut rader = self.clone();
+        let rader_outputs = rader.dft_rader_winograd(inputs);
+
+        if rader.multiplications < folded.multiplications {
+            *self = rader;
+            rader_outputs
+        } else {
+            *self = folded;
+            folded_outputs
+        }
+    }
+
+    fn dft_prime_folded(&mut self, inputs: &[String]) -> Vec<String> {
+        let len = inputs.len();
+        let half_len = len / 2;
+
+        let mut sums = Vec::with_capacity(half_len);
+        let mut differences = Vec::with_capacity(half_len);
+        for k in 1..half_len + 1 {
+            sums.push(self.temp(format!("{} + {}", inputs[k], inputs[len - k])));
+            differences.push(self.temp(format!("{} - {}", inputs[k], inputs[len - k])));
+        }
+
+        let mut outputs = vec![String::new(); len];
+        outputs[0] = self.temp(format!("{} + {}", inputs[0], sums.join(" + ")));
+
+        for m in 1..half_len + 1 {
+            let mut real_terms = vec![inputs[0].clone()];
+            let mut imag_expression = String::new();
+            for k in 1..half_len + 1 {
+                // twiddle factor len - i is the conjugate of twiddle factor i, so we only ever store the first half
+                let index = (k * m) % len;
+                let (stored_index, negate_imag) = if index <= half_len { (index, false) } else { (len - index, true) };
+                let twiddle = self.twiddle(stored_index, len);
+
+                real_terms.push(format!("{} * {}.re", sums[k - 1], twiddle));
+
+                let term = format!("{} * {}.im", differences[k - 1], twiddle);
+                if imag_expression.is_empty() {
+                    imag_expression = if negate_imag { format!("-({})", term) } else { term };
+                } else {
+                    imag_expression = format!("{} {} {}", imag_expression, if negate_imag { "-" } else { "+" }, term);
+                }
+                self.multiplications += 4;
+            }
+            let real_sum = self.temp(real_terms.join(" + "));
+            let imag_sum = self.temp(imag_expression);
+
+            // multiply the imaginary sum by i
+            let rotated = self.temp(format!("Complex {{ re: -{}.im, im: {}.re }}", imag_sum, imag_sum));
+            outputs[m] = self.temp(format!("{} + {}", real_sum, rotated));
+            outputs[len - m] = self.temp(format!("{} - {}", real_sum, rotated));
+        }
+        outputs
+    }
+
+    /// Rewrites a DFT of prime size p as a cyclic convolution of size p - 1, like Rader's Algorithm, then computes the
+    /// convolution with two straight-line DFTs of size p - 1 and one multiplication by a precomputed constant per
+    /// element. As in Winograd's small-prime FFTs, every multiplication outside of the inner DFTs is by a constant that
+    /// only depends on the kernel, so when p - 1 has small factors, this needs far fewer multiplications than folding
+    fn dft_rader_winograd(&mut self, inputs: &[String]) -> Vec<String> {
+        let len = inputs.len();
+        let kernel_len = len - 1;
+        let primitive_root = primitive_root(len);
+        let root_powers: Vec<usize> = (0..kernel_len)
+            .scan(1, |power, _| { let current = *power; *power = *power * primitive_root % len; Some(current) })
+            .collect();
+
+        // output g^q is x0 plus the convolution of x[g^-j] with twiddle(g^j, len). g^-j is g^(kernel_len - j)
+        let permuted: Vec<String> = (0..kernel_len).map(|j| inputs[root_powers[(kernel_len - j) % kernel_len]].clone()).collect();
+        let transformed = self.dft(&permuted);
+
+        // element 0 of the kernel's DFT is always -1/kernel_len, so it only needs a real multiplication. adding x0 to
+        // that element of the product adds x0 to every element of the convolution
+        let mut products = Vec::with_capacity(kernel_len);
+        for (m, value) in transformed.iter().enumerate() {
+            let kernel = self.constant(Constant::RaderKernel { len, primitive_root, index: m });
+            if m == 0 {
+                self.multiplications += 2;
+                products.push(self.temp(format!("{} * {}.re + {}", value, kernel, inputs[0])));
+            } else {
+                self.multiplications += 4;
+                products.push(self.temp(format!("{} * {}", value, kernel)));
+            }
+        }
+
+        // an inverse DFT is a forward DFT with its outputs in reverse order, and we already divided by kernel_len
+        let convolution = self.dft(&products);
+        let mut outputs = vec![String::new(); len];
+        outputs[0] = self.temp(format!("{} + {}", inputs[0], transformed[0]));
+        for q in 0..kernel_len {
+            outputs[root_powers[q]] = convolution[(kernel_len - q) % kernel_len].clone();
+        }
+        outputs
+    }
+
+    fn dft_good_thomas(&mut self, inputs: &[String], width: usize, height: usize) -> Vec<String> {
+        let len = inputs.len();
+
+        // input (x, y) is element (x * height + y * width) % len. the size-width FFTs then use every height-th root of
+        // unity, and the size-height FFTs use every width-th root
+        let columns: Vec<Vec<String>> = (0..height).map(|y| {
+            let column: Vec<String> = (0..width).map(|x| inputs[(x * height + y * width) % len].clone()).collect();
+            self.dft(&column)
+        }).collect();
+
+        let mut outputs = vec![String::new(); len];
+        for x in 0..width {
+            let row: Vec<String> = columns.iter().map(|column| column[x].clone()).collect();
+            let row_outputs = self.dft(&row);
+
+            // by the chinese remainder theorem, output (x, y) goes to the index that's x mod width and y mod height
+            for (y, output) in row_outputs.into_iter().enumerate() {
+                let index = (0..len).find(|&i| i % width == x && i % height == y).unwrap();
+                outputs[index] = output;
+            }
+        }
+        outputs
+    }
+
+    fn dft_mixed_radix(&mut self, inputs: &[String], radix: usize) -> Vec<String> {
+        let len = inputs.len();
+        let inner_len = len / radix;
+
+        // FFTs of size inner_len on each of the `radix` interleaved subsequences, then twiddle factors
+        let columns: Vec<Vec<String>> = (0..radix).map(|offset| {
+            let column: Vec<String> = (0..inner_len).map(|i| inputs[offset + i * radix].clone()).collect();
+            let column_outputs = self.dft(&column);
+            column_outputs.iter().enumerate()
+                .map(|(k, value)| self.multiply_twiddle(value, offset * k, len))
+                .collect()
+        }).collect();
+
+        // then FFTs of size radix across the columns. output k of the FFT for row r goes to r + k * inner_len
+        let mut outputs = vec![String::new(); len];
+        for r in 0..inner_len {
+            let row: Vec<String> = columns.iter().map(|column| column[r].clone()).collect();
+            for (k, output) in self.dft(&row).into_iter().enumerate() {
+                outputs[r + k * inner_len] = output;
+            }
+        }
+        outputs
+    }
+}
+
+fn smallest_factor(len: usize) -> usize {
+    (2..len).find(|&factor| len % factor == 0).unwrap_or(len)
+}
+
+/// Finds the most balanced way to split `len` into two coprime factors greater than one, if there is one
+fn coprime_split(len: usize) -> Option<(usize, usize)> {
+    (2..len)
+        .filter(|&width| len % width == 0 && gcd(width, len / width) == 1)
+        .min_by_key(|&width| if width * width > len { width } else { len / width })
+        .map(|width| (width, len / width))
+}
+
+/// Finds the smallest primitive root of the prime `len`, ie the smallest number whose powers mod `len` cover every
+/// nonzero value
+fn primitive_root(len: usize) -> usize {
+    (2..len).find(|&candidate| {
+        let mut power = candidate;
+        let mut order = 1;
+        while power != 1 {
+            power = power * candidate % len;
+            order += 1;
+        }
+        order == len - 1
+    }).unwrap_or(1)
+}
+
+/// Computes element `index` of the forward Rader kernel's DFT, divided by the kernel's length, as (re, im). Kernel
+/// element j is twiddle(primitive_root^j, len), and its DFT has size len - 1
+fn rader_kernel(len: usize, primitive_root: usize, index: usize) -> (f64, f64) {
+    let kernel_len = len - 1;
+    let (mut re, mut im) = (0f64, 0f64);
+    let mut root_power = 1;
+    for j in 0..kernel_len {
+        // the product of the kernel element and the DFT's twiddle factor is a single rotation by the sum of their
+        // angles. summing the fractions of a turn exactly keeps the angle below 2π
+        let turns = (root_power * kernel_len + (index * j % kernel_len) * len) % (len * kernel_len);
+        let angle = -2f64 * f64::consts::PI * turns as f64 / (len * kernel_len) as f64;
+        re += angle.cos();
+        im += angle.sin();
+        root_power = root_power * primitive_root % len;
+    }
+    (re / kernel_len as f64, im / kernel_len as f64)
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use std::fs;
+    use std::io;
+    use std::path::Path;
+    use std::process::Command;
+
+    #[test]
+    fn test_checked_in_butterflies_are_current() {
+        let checked_in = include_str!("../../src/algorithm/butterflies_generated.rs");
+        assert!(generate_file(&DEFAULT_SIZES, false) == checked_in,
+            "src/algorithm/butterflies_generated.rs is out of date, rerun `cargo run -p gen-butterflies`");
+    }
+
+    // the checked-in butterflies are already tested by rustfft's own tests, so this only matters when changing the
+    // generator. it builds rustfft from scratch once for each mode, which is slow, so it only runs in CI, with
+    // `--ignored`
+    #[test]
+    #[ignore]
+    fn test_generated_butterflies() {
+        // build a copy of rustfft with each mode's butterflies in place of the checked-in ones, and run its butterfly
+        // tests, which compare every butterfly against a DFT
+        let repository = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        let scratch = repository.join("target").join("gen-butterflies");
+        for &min_multiplications in &[false, true] {
+            let copy = scratch.join(if min_multiplications { "min-multiplications" } else { "default" });
+            if copy.exists() {
+                fs::remove_dir_all(&copy).unwrap();
+            }
+            copy_dir(&repository.join("src"), &copy.join("src")).unwrap();
+            fs::write(copy.join("src").join("algorithm").join("butterflies_generated.rs"),
+                generate_file(&DEFAULT_SIZES, min_multiplications)).unwrap();
+
+            // the copy is its own workspace, and reuses the repository's lock file if there is one
+            let manifest = fs::read_to_string(repository.join("Cargo.toml")).unwrap();
+            fs::write(copy.join("Cargo.toml"), manifest.replace("members = [\"gen-butterflies\"]", "")).unwrap();
+            if repository.join("Cargo.lock").exists() {
+                fs::copy(repository.join("Cargo.lock"), copy.join("Cargo.lock")).unwrap();
+            }
+
+            let output = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+                .args(&["test", "--lib", "test_butterfly"])
+                .current_dir(&copy)
+                .env("CARGO_TARGET_DIR", scratch.join("target"))
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "min_multiplications = {}\n{}\n{}", min_multiplications,
+                String::from_utf8_lossy(&output.stdout), String::from_utf8_lossy(&output.stderr));
+        }
+    }
+
+    fn copy_dir(from: &Path, to: &Path) -> io::Result<()> {
+        fs::create_dir_all(to)?;
+        for entry in fs::read_dir(from)? {
+            let entry = entry?;
+            if entry.file_type()?.is_dir() {
+                copy_dir(&entry.path(), &to.join(entry.file_name()))?;
+            } else {
+                fs::copy(entry.path(), to.join(entry.file_name()))?;
+            }
+        }
+        Ok(())
+    }
+}
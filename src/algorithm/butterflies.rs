@@ -1,3 +1,6 @@
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
 use num_complex::Complex;
 use num_traits::{FromPrimitive, Zero};
 
@@ -19,9 +22,53 @@ pub trait FFTButterfly<T: FFTnum>: Length + IsInverse + Sync + Send {
     /// # Safety
     /// This method performs unsafe reads/writes on `buffer`. Make sure `buffer.len()` is a multiple of `self.len()`
     unsafe fn process_multi_inplace(&self, buffer: &mut [Complex<T>]);
+
+    /// Combines every `self.len()` consecutive FFTs of size `stride` in `buffer` into one FFT, in-place
+    ///
+    /// For each chunk of `buffer` of size `self.len() * stride`, and each i < `stride`, this multiplies the element at
+    /// `i + t * stride` by `twiddles[i * (self.len() - 1) + t - 1]` for every t > 0, then computes the FFT of the
+    /// `self.len()` elements at `i + t * stride`. This is one stage of an iterative Cooley-Tukey FFT, so implementing
+    /// it here lets each butterfly's `process_inplace` be inlined into the loop.
+    ///
+    /// # Safety
+    /// This method performs unsafe reads/writes on `buffer` and `twiddles`. Make sure `buffer.len()` is a multiple of
+    /// `self.len() * stride`, and `twiddles.len()` is at least `(self.len() - 1) * stride`
+    unsafe fn process_stage_inplace(&self, buffer: &mut [Complex<T>], twiddles: &[Complex<T>], stride: usize) {
+        let radix = self.len();
+
+        // a fixed-size scratch array lets the compiler keep the butterfly's elements in registers
+        let mut scratch_array = [Zero::zero(); MAX_STAGE_RADIX];
+        let mut scratch_vec = Vec::new();
+        let scratch = if radix <= MAX_STAGE_RADIX {
+            &mut scratch_array[..radix]
+        } else {
+            scratch_vec.resize(radix, Zero::zero());
+            &mut scratch_vec[..]
+        };
+
+        for chunk in buffer.chunks_mut(radix * stride) {
+            for i in 0..stride {
+                let butterfly_twiddles = twiddles.get_unchecked(i * (radix - 1)..);
+
+                *scratch.get_unchecked_mut(0) = *chunk.get_unchecked(i);
+                for t in 1..radix {
+                    *scratch.get_unchecked_mut(t) = *chunk.get_unchecked(i + t * stride) * *butterfly_twiddles.get_unchecked(t - 1);
+                }
+
+                self.process_inplace(scratch);
+
+                for t in 0..radix {
+                    *chunk.get_unchecked_mut(i + t * stride) = *scratch.get_unchecked(t);
+                }
+            }
+        }
+    }
 }
 
 
+/// The largest butterfly whose `process_stage_inplace` can use a scratch array on the stack
+const MAX_STAGE_RADIX: usize = 64;
+
 #[inline(always)]
 unsafe fn swap_unchecked<T: Copy>(buffer: &mut [T], a: usize, b: usize) {
 	let temp = *buffer.get_unchecked(a);
@@ -29,7 +76,81 @@ unsafe fn swap_unchecked<T: Copy>(buffer: &mut [T], a: usize, b: usize) {
 	*buffer.get_unchecked_mut(b) = temp;
 }
 
+// the generated butterflies and Butterfly64 implement FFT, Length, and IsInverse identically, so we generate those impls
+// with a macro. every butterfly using it must have an `inverse` field
+macro_rules! boilerplate_fft_butterfly {
+    ($struct_name:ident, $len:expr) => (
+        impl<T: FFTnum> FFT<T> for $struct_name<T> {
+            fn process(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) {
+                verify_length(input, output, self.len());
+                output.copy_from_slice(input);
+
+                unsafe { self.process_inplace(output) };
+            }
+            fn process_multi(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) {
+                verify_length_divisible(input, output, self.len());
+                output.copy_from_slice(input);
+
+                unsafe { self.process_multi_inplace(output) };
+            }
+        }
+        impl<T> Length for $struct_name<T> {
+            #[inline(always)]
+            fn len(&self) -> usize {
+                $len
+            }
+        }
+        impl<T> IsInverse for $struct_name<T> {
+            #[inline(always)]
+            fn is_inverse(&self) -> bool {
+                self.inverse
+            }
+        }
+    )
+}
+
+// the butterflies for sizes without their own hand-written implementation are generated by the gen-butterflies crate
+#[path = "butterflies_generated.rs"]
+mod generated;
+pub use self::generated::{Butterfly9, Butterfly11, Butterfly12, Butterfly13, Butterfly15, Butterfly17, Butterfly19,
+                          Butterfly23, Butterfly24, Butterfly27, Butterfly29, Butterfly31};
+
+// every butterfly, by size. code that chooses a butterfly by its size expands `$callback` with this list, so that adding
+// a butterfly only means adding it here
+macro_rules! with_butterflies {
+    ($callback:ident) => (
+        $callback!(2 => Butterfly2, 3 => Butterfly3<T>, 4 => Butterfly4, 5 => Butterfly5<T>, 6 => Butterfly6<T>,
+                   7 => Butterfly7<T>, 8 => Butterfly8<T>, 9 => Butterfly9<T>, 11 => Butterfly11<T>,
+                   12 => Butterfly12<T>, 13 => Butterfly13<T>, 15 => Butterfly15<T>, 16 => Butterfly16<T>,
+                   17 => Butterfly17<T>, 19 => Butterfly19<T>, 23 => Butterfly23<T>, 24 => Butterfly24<T>,
+                   27 => Butterfly27<T>, 29 => Butterfly29<T>, 31 => Butterfly31<T>, 32 => Butterfly32<T>,
+                   64 => Butterfly64<T>);
+    )
+}
 
+macro_rules! butterfly_constructors {
+    ($($len:expr => $butterfly:ty),*) => (
+        /// The sizes that have a butterfly
+        pub(crate) const BUTTERFLIES: &[usize] = &[$($len),*];
+
+        /// Returns the butterfly of size `len`, which must be one of `BUTTERFLIES`
+        pub(crate) fn make_butterfly<T: FFTnum>(len: usize, inverse: bool) -> Arc<FFTButterfly<T>> {
+            match len {
+                $($len => Arc::new(<$butterfly>::new(inverse)),)*
+                _ => panic!("Invalid butterfly size: {}", len),
+            }
+        }
+
+        /// Same as `make_butterfly`, for callers that need it as a FFT
+        pub(crate) fn make_butterfly_fft<T: FFTnum>(len: usize, inverse: bool) -> Arc<FFT<T>> {
+            match len {
+                $($len => Arc::new(<$butterfly>::new(inverse)),)*
+                _ => panic!("Invalid butterfly size: {}", len),
+            }
+        }
+    )
+}
+with_butterflies!(butterfly_constructors);
 
 
 
@@ -969,6 +1090,82 @@ impl<T> IsInverse for Butterfly32<T> {
 
 
 
+pub struct Butterfly64<T> {
+    butterfly32: Butterfly32<T>,
+    butterfly16: Butterfly16<T>,
+    twiddles: [Complex<T>; 15],
+    inverse: bool,
+}
+impl<T: FFTnum> Butterfly64<T>
+{
+    #[inline(always)]
+    pub fn new(inverse: bool) -> Self {
+        let mut twiddle_factors = [Zero::zero(); 15];
+        for (i, twiddle) in twiddle_factors.iter_mut().enumerate() {
+            *twiddle = twiddles::single_twiddle(i + 1, 64, inverse);
+        }
+
+        Butterfly64 {
+            butterfly32: Butterfly32::new(inverse),
+            butterfly16: Butterfly16::new(inverse),
+            twiddles: twiddle_factors,
+            inverse,
+        }
+    }
+}
+impl<T: FFTnum> FFTButterfly<T> for Butterfly64<T> {
+    #[inline(always)]
+    unsafe fn process_inplace(&self, buffer: &mut [Complex<T>]) {
+        // we're going to hardcode a step of split radix
+        // step 1: copy and reorder the input into the scratch
+        let mut scratch_evens = [Zero::zero(); 32];
+        for (i, element) in scratch_evens.iter_mut().enumerate() {
+            *element = *buffer.get_unchecked(2 * i);
+        }
+
+        let mut scratch_odds_n1 = [Zero::zero(); 16];
+        let mut scratch_odds_n3 = [Zero::zero(); 16];
+        for i in 0..16 {
+            scratch_odds_n1[i] = *buffer.get_unchecked(4 * i + 1);
+            scratch_odds_n3[i] = *buffer.get_unchecked((4 * i + 63) % 64);
+        }
+
+        // step 2: column FFTs
+        self.butterfly32.process_inplace(&mut scratch_evens);
+        self.butterfly16.process_inplace(&mut scratch_odds_n1);
+        self.butterfly16.process_inplace(&mut scratch_odds_n3);
+
+        // step 3: apply twiddle factors
+        for i in 1..16 {
+            scratch_odds_n1[i] = scratch_odds_n1[i] * self.twiddles[i - 1];
+            scratch_odds_n3[i] = scratch_odds_n3[i] * self.twiddles[i - 1].conj();
+        }
+
+        // step 4: cross FFTs, then apply the butterfly 4 twiddle factor, which is just a rotation
+        for i in 0..16 {
+            Butterfly2::perform_fft_direct(&mut scratch_odds_n1[i], &mut scratch_odds_n3[i]);
+            scratch_odds_n3[i] = twiddles::rotate_90(scratch_odds_n3[i], self.inverse);
+        }
+
+        //step 5: copy/add/subtract data back to buffer
+        for i in 0..16 {
+            *buffer.get_unchecked_mut(i) =      scratch_evens[i] +      scratch_odds_n1[i];
+            *buffer.get_unchecked_mut(i + 16) = scratch_evens[i + 16] + scratch_odds_n3[i];
+            *buffer.get_unchecked_mut(i + 32) = scratch_evens[i] -      scratch_odds_n1[i];
+            *buffer.get_unchecked_mut(i + 48) = scratch_evens[i + 16] - scratch_odds_n3[i];
+        }
+    }
+    #[inline(always)]
+    unsafe fn process_multi_inplace(&self, buffer: &mut [Complex<T>]) {
+        for chunk in buffer.chunks_mut(self.len()) {
+            self.process_inplace(chunk);
+        }
+    }
+}
+boilerplate_fft_butterfly!(Butterfly64, 64);
+
+
+
 #[cfg(test)]
 mod unit_tests {
 	use super::*;
@@ -1001,8 +1198,21 @@ mod unit_tests {
     test_butterfly_func!(test_butterfly6, Butterfly6, 6);
     test_butterfly_func!(test_butterfly7, Butterfly7, 7);
     test_butterfly_func!(test_butterfly8, Butterfly8, 8);
+    test_butterfly_func!(test_butterfly9, Butterfly9, 9);
+    test_butterfly_func!(test_butterfly11, Butterfly11, 11);
+    test_butterfly_func!(test_butterfly12, Butterfly12, 12);
+    test_butterfly_func!(test_butterfly13, Butterfly13, 13);
+    test_butterfly_func!(test_butterfly15, Butterfly15, 15);
     test_butterfly_func!(test_butterfly16, Butterfly16, 16);
+    test_butterfly_func!(test_butterfly17, Butterfly17, 17);
+    test_butterfly_func!(test_butterfly19, Butterfly19, 19);
+    test_butterfly_func!(test_butterfly23, Butterfly23, 23);
+    test_butterfly_func!(test_butterfly24, Butterfly24, 24);
+    test_butterfly_func!(test_butterfly27, Butterfly27, 27);
+    test_butterfly_func!(test_butterfly29, Butterfly29, 29);
+    test_butterfly_func!(test_butterfly31, Butterfly31, 31);
     test_butterfly_func!(test_butterfly32, Butterfly32, 32);
+    test_butterfly_func!(test_butterfly64, Butterfly64, 64);
     
 
     fn check_butterfly(butterfly: &FFTButterfly<f32>, size: usize, inverse: bool) {
@@ -0,0 +1,1972 @@
+// This file is generated by the gen-butterflies crate. Don't edit it by hand. Instead, run
+// `cargo run -p gen-butterflies > src/algorithm/butterflies_generated.rs` from the root of the repository
+
+use num_complex::Complex;
+
+use common::{FFTnum, verify_length, verify_length_divisible};
+
+use twiddles;
+use ::{Length, IsInverse, FFT};
+use super::FFTButterfly;
+
+
+
+pub struct Butterfly9<T> {
+    twiddles: [Complex<T>; 4],
+    inverse: bool,
+}
+impl<T: FFTnum> Butterfly9<T> {
+    pub fn new(inverse: bool) -> Self {
+        Butterfly9 {
+            twiddles: [
+                twiddles::single_twiddle(1, 3, inverse),
+                twiddles::single_twiddle(1, 9, inverse),
+                twiddles::single_twiddle(2, 9, inverse),
+                twiddles::single_twiddle(4, 9, inverse),
+            ],
+            inverse,
+        }
+    }
+}
+impl<T: FFTnum> FFTButterfly<T> for Butterfly9<T> {
+    #[inline(always)]
+    unsafe fn process_inplace(&self, buffer: &mut [Complex<T>]) {
+        let x0 = *buffer.get_unchecked(0);
+        let x1 = *buffer.get_unchecked(1);
+        let x2 = *buffer.get_unchecked(2);
+        let x3 = *buffer.get_unchecked(3);
+        let x4 = *buffer.get_unchecked(4);
+        let x5 = *buffer.get_unchecked(5);
+        let x6 = *buffer.get_unchecked(6);
+        let x7 = *buffer.get_unchecked(7);
+        let x8 = *buffer.get_unchecked(8);
+        let t0 = x3 + x6;
+        let t1 = x3 - x6;
+        let t2 = x0 + t0;
+        let t3 = x0 + t0 * self.twiddles[0].re;
+        let t4 = t1 * self.twiddles[0].im;
+        let t5 = Complex { re: -t4.im, im: t4.re };
+        let t6 = t3 + t5;
+        let t7 = t3 - t5;
+        let t8 = x4 + x7;
+        let t9 = x4 - x7;
+        let t10 = x1 + t8;
+        let t11 = x1 + t8 * self.twiddles[0].re;
+        let t12 = t9 * self.twiddles[0].im;
+        let t13 = Complex { re: -t12.im, im: t12.re };
+        let t14 = t11 + t13;
+        let t15 = t11 - t13;
+        let t16 = t14 * self.twiddles[1];
+        let t17 = t15 * self.twiddles[2];
+        let t18 = x5 + x8;
+        let t19 = x5 - x8;
+        let t20 = x2 + t18;
+        let t21 = x2 + t18 * self.twiddles[0].re;
+        let t22 = t19 * self.twiddles[0].im;
+        let t23 = Complex { re: -t22.im, im: t22.re };
+        let t24 = t21 + t23;
+        let t25 = t21 - t23;
+        let t26 = t24 * self.twiddles[2];
+        let t27 = t25 * self.twiddles[3];
+        let t28 = t10 + t20;
+        let t29 = t10 - t20;
+        let t30 = t2 + t28;
+        let t31 = t2 + t28 * self.twiddles[0].re;
+        let t32 = t29 * self.twiddles[0].im;
+        let t33 = Complex { re: -t32.im, im: t32.re };
+        let t34 = t31 + t33;
+        let t35 = t31 - t33;
+        let t36 = t16 + t26;
+        let t37 = t16 - t26;
+        let t38 = t6 + t36;
+        let t39 = t6 + t36 * self.twiddles[0].re;
+        let t40 = t37 * self.twiddles[0].im;
+        let t41 = Complex { re: -t40.im, im: t40.re };
+        let t42 = t39 + t41;
+        let t43 = t39 - t41;
+        let t44 = t17 + t27;
+        let t45 = t17 - t27;
+        let t46 = t7 + t44;
+        let t47 = t7 + t44 * self.twiddles[0].re;
+        let t48 = t45 * self.twiddles[0].im;
+        let t49 = Complex { re: -t48.im, im: t48.re };
+        let t50 = t47 + t49;
+        let t51 = t47 - t49;
+        *buffer.get_unchecked_mut(0) = t30;
+        *buffer.get_unchecked_mut(1) = t38;
+        *buffer.get_unchecked_mut(2) = t46;
+        *buffer.get_unchecked_mut(3) = t34;
+        *buffer.get_unchecked_mut(4) = t42;
+        *buffer.get_unchecked_mut(5) = t50;
+        *buffer.get_unchecked_mut(6) = t35;
+        *buffer.get_unchecked_mut(7) = t43;
+        *buffer.get_unchecked_mut(8) = t51;
+    }
+    #[inline(always)]
+    unsafe fn process_multi_inplace(&self, buffer: &mut [Complex<T>]) {
+        for chunk in buffer.chunks_mut(self.len()) {
+            self.process_inplace(chunk);
+        }
+    }
+}
+boilerplate_fft_butterfly!(Butterfly9, 9);
+
+
+
+pub struct Butterfly11<T> {
+    twiddles: [Complex<T>; 5],
+    inverse: bool,
+}
+impl<T: FFTnum> Butterfly11<T> {
+    pub fn new(inverse: bool) -> Self {
+        Butterfly11 {
+            twiddles: [
+                twiddles::single_twiddle(1, 11, inverse),
+                twiddles::single_twiddle(2, 11, inverse),
+                twiddles::single_twiddle(3, 11, inverse),
+                twiddles::single_twiddle(4, 11, inverse),
+                twiddles::single_twiddle(5, 11, inverse),
+            ],
+            inverse,
+        }
+    }
+}
+impl<T: FFTnum> FFTButterfly<T> for Butterfly11<T> {
+    #[inline(always)]
+    unsafe fn process_inplace(&self, buffer: &mut [Complex<T>]) {
+        let x0 = *buffer.get_unchecked(0);
+        let x1 = *buffer.get_unchecked(1);
+        let x2 = *buffer.get_unchecked(2);
+        let x3 = *buffer.get_unchecked(3);
+        let x4 = *buffer.get_unchecked(4);
+        let x5 = *buffer.get_unchecked(5);
+        let x6 = *buffer.get_unchecked(6);
+        let x7 = *buffer.get_unchecked(7);
+        let x8 = *buffer.get_unchecked(8);
+        let x9 = *buffer.get_unchecked(9);
+        let x10 = *buffer.get_unchecked(10);
+        let t0 = x1 + x10;
+        let t1 = x1 - x10;
+        let t2 = x2 + x9;
+        let t3 = x2 - x9;
+        let t4 = x3 + x8;
+        let t5 = x3 - x8;
+        let t6 = x4 + x7;
+        let t7 = x4 - x7;
+        let t8 = x5 + x6;
+        let t9 = x5 - x6;
+        let t10 = x0 + t0 + t2 + t4 + t6 + t8;
+        let t11 = x0 + t0 * self.twiddles[0].re + t2 * self.twiddles[1].re + t4 * self.twiddles[2].re + t6 * self.twiddles[3].re + t8 * self.twiddles[4].re;
+        let t12 = t1 * self.twiddles[0].im + t3 * self.twiddles[1].im + t5 * self.twiddles[2].im + t7 * self.twiddles[3].im + t9 * self.twiddles[4].im;
+        let t13 = Complex { re: -t12.im, im: t12.re };
+        let t14 = t11 + t13;
+        let t15 = t11 - t13;
+        let t16 = x0 + t0 * self.twiddles[1].re + t2 * self.twiddles[3].re + t4 * self.twiddles[4].re + t6 * self.twiddles[2].re + t8 * self.twiddles[0].re;
+        let t17 = t1 * self.twiddles[1].im + t3 * self.twiddles[3].im - t5 * self.twiddles[4].im - t7 * self.twiddles[2].im - t9 * self.twiddles[0].im;
+        let t18 = Complex { re: -t17.im, im: t17.re };
+        let t19 = t16 + t18;
+        let t20 = t16 - t18;
+        let t21 = x0 + t0 * self.twiddles[2].re + t2 * self.twiddles[4].re + t4 * self.twiddles[1].re + t6 * self.twiddles[0].re + t8 * self.twiddles[3].re;
+        let t22 = t1 * self.twiddles[2].im - t3 * self.twiddles[4].im - t5 * self.twiddles[1].im + t7 * self.twiddles[0].im + t9 * self.twiddles[3].im;
+        let t23 = Complex { re: -t22.im, im: t22.re };
+        let t24 = t21 + t23;
+        let t25 = t21 - t23;
+        let t26 = x0 + t0 * self.twiddles[3].re + t2 * self.twiddles[2].re + t4 * self.twiddles[0].re + t6 * self.twiddles[4].re + t8 * self.twiddles[1].re;
+        let t27 = t1 * self.twiddles[3].im - t3 * self.twiddles[2].im + t5 * self.twiddles[0].im + t7 * self.twiddles[4].im - t9 * self.twiddles[1].im;
+        let t28 = Complex { re: -t27.im, im: t27.re };
+        let t29 = t26 + t28;
+        let t30 = t26 - t28;
+        let t31 = x0 + t0 * self.twiddles[4].re + t2 * self.twiddles[0].re + t4 * self.twiddles[3].re + t6 * self.twiddles[1].re + t8 * self.twiddles[2].re;
+        let t32 = t1 * self.twiddles[4].im - t3 * self.twiddles[0].im + t5 * self.twiddles[3].im - t7 * self.twiddles[1].im + t9 * self.twiddles[2].im;
+        let t33 = Complex { re: -t32.im, im: t32.re };
+        let t34 = t31 + t33;
+        let t35 = t31 - t33;
+        *buffer.get_unchecked_mut(0) = t10;
+        *buffer.get_unchecked_mut(1) = t14;
+        *buffer.get_unchecked_mut(2) = t19;
+        *buffer.get_unchecked_mut(3) = t24;
+        *buffer.get_unchecked_mut(4) = t29;
+        *buffer.get_unchecked_mut(5) = t34;
+        *buffer.get_unchecked_mut(6) = t35;
+        *buffer.get_unchecked_mut(7) = t30;
+        *buffer.get_unchecked_mut(8) = t25;
+        *buffer.get_unchecked_mut(9) = t20;
+        *buffer.get_unchecked_mut(10) = t15;
+    }
+    #[inline(always)]
+    unsafe fn process_multi_inplace(&self, buffer: &mut [Complex<T>]) {
+        for chunk in buffer.chunks_mut(self.len()) {
+            self.process_inplace(chunk);
+        }
+    }
+}
+boilerplate_fft_butterfly!(Butterfly11, 11);
+
+
+
+pub struct Butterfly12<T> {
+    twiddles: [Complex<T>; 1],
+    inverse: bool,
+}
+impl<T: FFTnum> Butterfly12<T> {
+    pub fn new(inverse: bool) -> Self {
+        Butterfly12 {
+            twiddles: [
+                twiddles::single_twiddle(1, 3, inverse),
+            ],
+            inverse,
+        }
+    }
+}
+impl<T: FFTnum> FFTButterfly<T> for Butterfly12<T> {
+    #[inline(always)]
+    unsafe fn process_inplace(&self, buffer: &mut [Complex<T>]) {
+        let x0 = *buffer.get_unchecked(0);
+        let x1 = *buffer.get_unchecked(1);
+        let x2 = *buffer.get_unchecked(2);
+        let x3 = *buffer.get_unchecked(3);
+        let x4 = *buffer.get_unchecked(4);
+        let x5 = *buffer.get_unchecked(5);
+        let x6 = *buffer.get_unchecked(6);
+        let x7 = *buffer.get_unchecked(7);
+        let x8 = *buffer.get_unchecked(8);
+        let x9 = *buffer.get_unchecked(9);
+        let x10 = *buffer.get_unchecked(10);
+        let x11 = *buffer.get_unchecked(11);
+        let t0 = x4 + x8;
+        let t1 = x4 - x8;
+        let t2 = x0 + t0;
+        let t3 = x0 + t0 * self.twiddles[0].re;
+        let t4 = t1 * self.twiddles[0].im;
+        let t5 = Complex { re: -t4.im, im: t4.re };
+        let t6 = t3 + t5;
+        let t7 = t3 - t5;
+        let t8 = x7 + x11;
+        let t9 = x7 - x11;
+        let t10 = x3 + t8;
+        let t11 = x3 + t8 * self.twiddles[0].re;
+        let t12 = t9 * self.twiddles[0].im;
+        let t13 = Complex { re: -t12.im, im: t12.re };
+        let t14 = t11 + t13;
+        let t15 = t11 - t13;
+        let t16 = x10 + x2;
+        let t17 = x10 - x2;
+        let t18 = x6 + t16;
+        let t19 = x6 + t16 * self.twiddles[0].re;
+        let t20 = t17 * self.twiddles[0].im;
+        let t21 = Complex { re: -t20.im, im: t20.re };
+        let t22 = t19 + t21;
+        let t23 = t19 - t21;
+        let t24 = x1 + x5;
+        let t25 = x1 - x5;
+        let t26 = x9 + t24;
+        let t27 = x9 + t24 * self.twiddles[0].re;
+        let t28 = t25 * self.twiddles[0].im;
+        let t29 = Complex { re: -t28.im, im: t28.re };
+        let t30 = t27 + t29;
+        let t31 = t27 - t29;
+        let t32 = t2 + t18;
+        let t33 = t2 - t18;
+        let t34 = t10 + t26;
+        let t35 = t10 - t26;
+        let t36 = twiddles::rotate_90(t35, self.inverse);
+        let t37 = t32 + t34;
+        let t38 = t33 + t36;
+        let t39 = t32 - t34;
+        let t40 = t33 - t36;
+        let t41 = t6 + t22;
+        let t42 = t6 - t22;
+        let t43 = t14 + t30;
+        let t44 = t14 - t30;
+        let t45 = twiddles::rotate_90(t44, self.inverse);
+        let t46 = t41 + t43;
+        let t47 = t42 + t45;
+        let t48 = t41 - t43;
+        let t49 = t42 - t45;
+        let t50 = t7 + t23;
+        let t51 = t7 - t23;
+        let t52 = t15 + t31;
+        let t53 = t15 - t31;
+        let t54 = twiddles::rotate_90(t53, self.inverse);
+        let t55 = t50 + t52;
+        let t56 = t51 + t54;
+        let t57 = t50 - t52;
+        let t58 = t51 - t54;
+        *buffer.get_unchecked_mut(0) = t37;
+        *buffer.get_unchecked_mut(1) = t47;
+        *buffer.get_unchecked_mut(2) = t57;
+        *buffer.get_unchecked_mut(3) = t40;
+        *buffer.get_unchecked_mut(4) = t46;
+        *buffer.get_unchecked_mut(5) = t56;
+        *buffer.get_unchecked_mut(6) = t39;
+        *buffer.get_unchecked_mut(7) = t49;
+        *buffer.get_unchecked_mut(8) = t55;
+        *buffer.get_unchecked_mut(9) = t38;
+        *buffer.get_unchecked_mut(10) = t48;
+        *buffer.get_unchecked_mut(11) = t58;
+    }
+    #[inline(always)]
+    unsafe fn process_multi_inplace(&self, buffer: &mut [Complex<T>]) {
+        for chunk in buffer.chunks_mut(self.len()) {
+            self.process_inplace(chunk);
+        }
+    }
+}
+boilerplate_fft_butterfly!(Butterfly12, 12);
+
+
+
+pub struct Butterfly13<T> {
+    twiddles: [Complex<T>; 6],
+    inverse: bool,
+}
+impl<T: FFTnum> Butterfly13<T> {
+    pub fn new(inverse: bool) -> Self {
+        Butterfly13 {
+            twiddles: [
+                twiddles::single_twiddle(1, 13, inverse),
+                twiddles::single_twiddle(2, 13, inverse),
+                twiddles::single_twiddle(3, 13, inverse),
+                twiddles::single_twiddle(4, 13, inverse),
+                twiddles::single_twiddle(5, 13, inverse),
+                twiddles::single_twiddle(6, 13, inverse),
+            ],
+            inverse,
+        }
+    }
+}
+impl<T: FFTnum> FFTButterfly<T> for Butterfly13<T> {
+    #[inline(always)]
+    unsafe fn process_inplace(&self, buffer: &mut [Complex<T>]) {
+        let x0 = *buffer.get_unchecked(0);
+        let x1 = *buffer.get_unchecked(1);
+        let x2 = *buffer.get_unchecked(2);
+        let x3 = *buffer.get_unchecked(3);
+        let x4 = *buffer.get_unchecked(4);
+        let x5 = *buffer.get_unchecked(5);
+        let x6 = *buffer.get_unchecked(6);
+        let x7 = *buffer.get_unchecked(7);
+        let x8 = *buffer.get_unchecked(8);
+        let x9 = *buffer.get_unchecked(9);
+        let x10 = *buffer.get_unchecked(10);
+        let x11 = *buffer.get_unchecked(11);
+        let x12 = *buffer.get_unchecked(12);
+        let t0 = x1 + x12;
+        let t1 = x1 - x12;
+        let t2 = x2 + x11;
+        let t3 = x2 - x11;
+        let t4 = x3 + x10;
+        let t5 = x3 - x10;
+        let t6 = x4 + x9;
+        let t7 = x4 - x9;
+        let t8 = x5 + x8;
+        let t9 = x5 - x8;
+        let t10 = x6 + x7;
+        let t11 = x6 - x7;
+        let t12 = x0 + t0 + t2 + t4 + t6 + t8 + t10;
+        let t13 = x0 + t0 * self.twiddles[0].re + t2 * self.twiddles[1].re + t4 * self.twiddles[2].re + t6 * self.twiddles[3].re + t8 * self.twiddles[4].re + t10 * self.twiddles[5].re;
+        let t14 = t1 * self.twiddles[0].im + t3 * self.twiddles[1].im + t5 * self.twiddles[2].im + t7 * self.twiddles[3].im + t9 * self.twiddles[4].im + t11 * self.twiddles[5].im;
+        let t15 = Complex { re: -t14.im, im: t14.re };
+        let t16 = t13 + t15;
+        let t17 = t13 - t15;
+        let t18 = x0 + t0 * self.twiddles[1].re + t2 * self.twiddles[3].re + t4 * self.twiddles[5].re + t6 * self.twiddles[4].re + t8 * self.twiddles[2].re + t10 * self.twiddles[0].re;
+        let t19 = t1 * self.twiddles[1].im + t3 * self.twiddles[3].im + t5 * self.twiddles[5].im - t7 * self.twiddles[4].im - t9 * self.twiddles[2].im - t11 * self.twiddles[0].im;
+        let t20 = Complex { re: -t19.im, im: t19.re };
+        let t21 = t18 + t20;
+        let t22 = t18 - t20;
+        let t23 = x0 + t0 * self.twiddles[2].re + t2 * self.twiddles[5].re + t4 * self.twiddles[3].re + t6 * self.twiddles[0].re + t8 * self.twiddles[1].re + t10 * self.twiddles[4].re;
+        let t24 = t1 * self.twiddles[2].im + t3 * self.twiddles[5].im - t5 * self.twiddles[3].im - t7 * self.twiddles[0].im + t9 * self.twiddles[1].im + t11 * self.twiddles[4].im;
+        let t25 = Complex { re: -t24.im, im: t24.re };
+        let t26 = t23 + t25;
+        let t27 = t23 - t25;
+        let t28 = x0 + t0 * self.twiddles[3].re + t2 * self.twiddles[4].re + t4 * self.twiddles[0].re + t6 * self.twiddles[2].re + t8 * self.twiddles[5].re + t10 * self.twiddles[1].re;
+        let t29 = t1 * self.twiddles[3].im - t3 * self.twiddles[4].im - t5 * self.twiddles[0].im + t7 * self.twiddles[2].im - t9 * self.twiddles[5].im - t11 * self.twiddles[1].im;
+        let t30 = Complex { re: -t29.im, im: t29.re };
+        let t31 = t28 + t30;
+        let t32 = t28 - t30;
+        let t33 = x0 + t0 * self.twiddles[4].re + t2 * self.twiddles[2].re + t4 * self.twiddles[1].re + t6 * self.twiddles[5].re + t8 * self.twiddles[0].re + t10 * self.twiddles[3].re;
+        let t34 = t1 * self.twiddles[4].im - t3 * self.twiddles[2].im + t5 * self.twiddles[1].im - t7 * self.twiddles[5].im - t9 * self.twiddles[0].im + t11 * self.twiddles[3].im;
+        let t35 = Complex { re: -t34.im, im: t34.re };
+        let t36 = t33 + t35;
+        let t37 = t33 - t35;
+        let t38 = x0 + t0 * self.twiddles[5].re + t2 * self.twiddles[0].re + t4 * self.twiddles[4].re + t6 * self.twiddles[1].re + t8 * self.twiddles[3].re + t10 * self.twiddles[2].re;
+        let t39 = t1 * self.twiddles[5].im - t3 * self.twiddles[0].im + t5 * self.twiddles[4].im - t7 * self.twiddles[1].im + t9 * self.twiddles[3].im - t11 * self.twiddles[2].im;
+        let t40 = Complex { re: -t39.im, im: t39.re };
+        let t41 = t38 + t40;
+        let t42 = t38 - t40;
+        *buffer.get_unchecked_mut(0) = t12;
+        *buffer.get_unchecked_mut(1) = t16;
+        *buffer.get_unchecked_mut(2) = t21;
+        *buffer.get_unchecked_mut(3) = t26;
+        *buffer.get_unchecked_mut(4) = t31;
+        *buffer.get_unchecked_mut(5) = t36;
+        *buffer.get_unchecked_mut(6) = t41;
+        *buffer.get_unchecked_mut(7) = t42;
+        *buffer.get_unchecked_mut(8) = t37;
+        *buffer.get_unchecked_mut(9) = t32;
+        *buffer.get_unchecked_mut(10) = t27;
+        *buffer.get_unchecked_mut(11) = t22;
+        *buffer.get_unchecked_mut(12) = t17;
+    }
+    #[inline(always)]
+    unsafe fn process_multi_inplace(&self, buffer: &mut [Complex<T>]) {
+        for chunk in buffer.chunks_mut(self.len()) {
+            self.process_inplace(chunk);
+        }
+    }
+}
+boilerplate_fft_butterfly!(Butterfly13, 13);
+
+
+
+pub struct Butterfly15<T> {
+    twiddles: [Complex<T>; 3],
+    inverse: bool,
+}
+impl<T: FFTnum> Butterfly15<T> {
+    pub fn new(inverse: bool) -> Self {
+        Butterfly15 {
+            twiddles: [
+                twiddles::single_twiddle(1, 3, inverse),
+                twiddles::single_twiddle(1, 5, inverse),
+                twiddles::single_twiddle(2, 5, inverse),
+            ],
+            inverse,
+        }
+    }
+}
+impl<T: FFTnum> FFTButterfly<T> for Butterfly15<T> {
+    #[inline(always)]
+    unsafe fn process_inplace(&self, buffer: &mut [Complex<T>]) {
+        let x0 = *buffer.get_unchecked(0);
+        let x1 = *buffer.get_unchecked(1);
+        let x2 = *buffer.get_unchecked(2);
+        let x3 = *buffer.get_unchecked(3);
+        let x4 = *buffer.get_unchecked(4);
+        let x5 = *buffer.get_unchecked(5);
+        let x6 = *buffer.get_unchecked(6);
+        let x7 = *buffer.get_unchecked(7);
+        let x8 = *buffer.get_unchecked(8);
+        let x9 = *buffer.get_unchecked(9);
+        let x10 = *buffer.get_unchecked(10);
+        let x11 = *buffer.get_unchecked(11);
+        let x12 = *buffer.get_unchecked(12);
+        let x13 = *buffer.get_unchecked(13);
+        let x14 = *buffer.get_unchecked(14);
+        let t0 = x5 + x10;
+        let t1 = x5 - x10;
+        let t2 = x0 + t0;
+        let t3 = x0 + t0 * self.twiddles[0].re;
+        let t4 = t1 * self.twiddles[0].im;
+        let t5 = Complex { re: -t4.im, im: t4.re };
+        let t6 = t3 + t5;
+        let t7 = t3 - t5;
+        let t8 = x8 + x13;
+        let t9 = x8 - x13;
+        let t10 = x3 + t8;
+        let t11 = x3 + t8 * self.twiddles[0].re;
+        let t12 = t9 * self.twiddles[0].im;
+        let t13 = Complex { re: -t12.im, im: t12.re };
+        let t14 = t11 + t13;
+        let t15 = t11 - t13;
+        let t16 = x11 + x1;
+        let t17 = x11 - x1;
+        let t18 = x6 + t16;
+        let t19 = x6 + t16 * self.twiddles[0].re;
+        let t20 = t17 * self.twiddles[0].im;
+        let t21 = Complex { re: -t20.im, im: t20.re };
+        let t22 = t19 + t21;
+        let t23 = t19 - t21;
+        let t24 = x14 + x4;
+        let t25 = x14 - x4;
+        let t26 = x9 + t24;
+        let t27 = x9 + t24 * self.twiddles[0].re;
+        let t28 = t25 * self.twiddles[0].im;
+        let t29 = Complex { re: -t28.im, im: t28.re };
+        let t30 = t27 + t29;
+        let t31 = t27 - t29;
+        let t32 = x2 + x7;
+        let t33 = x2 - x7;
+        let t34 = x12 + t32;
+        let t35 = x12 + t32 * self.twiddles[0].re;
+        let t36 = t33 * self.twiddles[0].im;
+        let t37 = Complex { re: -t36.im, im: t36.re };
+        let t38 = t35 + t37;
+        let t39 = t35 - t37;
+        let t40 = t10 + t34;
+        let t41 = t10 - t34;
+        let t42 = t18 + t26;
+        let t43 = t18 - t26;
+        let t44 = t2 + t40 + t42;
+        let t45 = t2 + t40 * self.twiddles[1].re + t42 * self.twiddles[2].re;
+        let t46 = t41 * self.twiddles[1].im + t43 * self.twiddles[2].im;
+        let t47 = Complex { re: -t46.im, im: t46.re };
+        let t48 = t45 + t47;
+        let t49 = t45 - t47;
+        let t50 = t2 + t40 * self.twiddles[2].re + t42 * self.twiddles[1].re;
+        let t51 = t41 * self.twiddles[2].im - t43 * self.twiddles[1].im;
+        let t52 = Complex { re: -t51.im, im: t51.re };
+        let t53 = t50 + t52;
+        let t54 = t50 - t52;
+        let t55 = t14 + t38;
+        let t56 = t14 - t38;
+        let t57 = t22 + t30;
+        let t58 = t22 - t30;
+        let t59 = t6 + t55 + t57;
+        let t60 = t6 + t55 * self.twiddles[1].re + t57 * self.twiddles[2].re;
+        let t61 = t56 * self.twiddles[1].im + t58 * self.twiddles[2].im;
+        let t62 = Complex { re: -t61.im, im: t61.re };
+        let t63 = t60 + t62;
+        let t64 = t60 - t62;
+        let t65 = t6 + t55 * self.twiddles[2].re + t57 * self.twiddles[1].re;
+        let t66 = t56 * self.twiddles[2].im - t58 * self.twiddles[1].im;
+        let t67 = Complex { re: -t66.im, im: t66.re };
+        let t68 = t65 + t67;
+        let t69 = t65 - t67;
+        let t70 = t15 + t39;
+        let t71 = t15 - t39;
+        let t72 = t23 + t31;
+        let t73 = t23 - t31;
+        let t74 = t7 + t70 + t72;
+        let t75 = t7 + t70 * self.twiddles[1].re + t72 * self.twiddles[2].re;
+        let t76 = t71 * self.twiddles[1].im + t73 * self.twiddles[2].im;
+        let t77 = Complex { re: -t76.im, im: t76.re };
+        let t78 = t75 + t77;
+        let t79 = t75 - t77;
+        let t80 = t7 + t70 * self.twiddles[2].re + t72 * self.twiddles[1].re;
+        let t81 = t71 * self.twiddles[2].im - t73 * self.twiddles[1].im;
+        let t82 = Complex { re: -t81.im, im: t81.re };
+        let t83 = t80 + t82;
+        let t84 = t80 - t82;
+        *buffer.get_unchecked_mut(0) = t44;
+        *buffer.get_unchecked_mut(1) = t63;
+        *buffer.get_unchecked_mut(2) = t83;
+        *buffer.get_unchecked_mut(3) = t54;
+        *buffer.get_unchecked_mut(4) = t64;
+        *buffer.get_unchecked_mut(5) = t74;
+        *buffer.get_unchecked_mut(6) = t48;
+        *buffer.get_unchecked_mut(7) = t68;
+        *buffer.get_unchecked_mut(8) = t84;
+        *buffer.get_unchecked_mut(9) = t49;
+        *buffer.get_unchecked_mut(10) = t59;
+        *buffer.get_unchecked_mut(11) = t78;
+        *buffer.get_unchecked_mut(12) = t53;
+        *buffer.get_unchecked_mut(13) = t69;
+        *buffer.get_unchecked_mut(14) = t79;
+    }
+    #[inline(always)]
+    unsafe fn process_multi_inplace(&self, buffer: &mut [Complex<T>]) {
+        for chunk in buffer.chunks_mut(self.len()) {
+            self.process_inplace(chunk);
+        }
+    }
+}
+boilerplate_fft_butterfly!(Butterfly15, 15);
+
+
+
+pub struct Butterfly17<T> {
+    twiddles: [Complex<T>; 8],
+    inverse: bool,
+}
+impl<T: FFTnum> Butterfly17<T> {
+    pub fn new(inverse: bool) -> Self {
+        Butterfly17 {
+            twiddles: [
+                twiddles::single_twiddle(1, 17, inverse),
+                twiddles::single_twiddle(2, 17, inverse),
+                twiddles::single_twiddle(3, 17, inverse),
+                twiddles::single_twiddle(4, 17, inverse),
+                twiddles::single_twiddle(5, 17, inverse),
+                twiddles::single_twiddle(6, 17, inverse),
+                twiddles::single_twiddle(7, 17, inverse),
+                twiddles::single_twiddle(8, 17, inverse),
+            ],
+            inverse,
+        }
+    }
+}
+impl<T: FFTnum> FFTButterfly<T> for Butterfly17<T> {
+    #[inline(always)]
+    unsafe fn process_inplace(&self, buffer: &mut [Complex<T>]) {
+        let x0 = *buffer.get_unchecked(0);
+        let x1 = *buffer.get_unchecked(1);
+        let x2 = *buffer.get_unchecked(2);
+        let x3 = *buffer.get_unchecked(3);
+        let x4 = *buffer.get_unchecked(4);
+        let x5 = *buffer.get_unchecked(5);
+        let x6 = *buffer.get_unchecked(6);
+        let x7 = *buffer.get_unchecked(7);
+        let x8 = *buffer.get_unchecked(8);
+        let x9 = *buffer.get_unchecked(9);
+        let x10 = *buffer.get_unchecked(10);
+        let x11 = *buffer.get_unchecked(11);
+        let x12 = *buffer.get_unchecked(12);
+        let x13 = *buffer.get_unchecked(13);
+        let x14 = *buffer.get_unchecked(14);
+        let x15 = *buffer.get_unchecked(15);
+        let x16 = *buffer.get_unchecked(16);
+        let t0 = x1 + x16;
+        let t1 = x1 - x16;
+        let t2 = x2 + x15;
+        let t3 = x2 - x15;
+        let t4 = x3 + x14;
+        let t5 = x3 - x14;
+        let t6 = x4 + x13;
+        let t7 = x4 - x13;
+        let t8 = x5 + x12;
+        let t9 = x5 - x12;
+        let t10 = x6 + x11;
+        let t11 = x6 - x11;
+        let t12 = x7 + x10;
+        let t13 = x7 - x10;
+        let t14 = x8 + x9;
+        let t15 = x8 - x9;
+        let t16 = x0 + t0 + t2 + t4 + t6 + t8 + t10 + t12 + t14;
+        let t17 = x0 + t0 * self.twiddles[0].re + t2 * self.twiddles[1].re + t4 * self.twiddles[2].re + t6 * self.twiddles[3].re + t8 * self.twiddles[4].re + t10 * self.twiddles[5].re + t12 * self.twiddles[6].re + t14 * self.twiddles[7].re;
+        let t18 = t1 * self.twiddles[0].im + t3 * self.twiddles[1].im + t5 * self.twiddles[2].im + t7 * self.twiddles[3].im + t9 * self.twiddles[4].im + t11 * self.twiddles[5].im + t13 * self.twiddles[6].im + t15 * self.twiddles[7].im;
+        let t19 = Complex { re: -t18.im, im: t18.re };
+        let t20 = t17 + t19;
+        let t21 = t17 - t19;
+        let t22 = x0 + t0 * self.twiddles[1].re + t2 * self.twiddles[3].re + t4 * self.twiddles[5].re + t6 * self.twiddles[7].re + t8 * self.twiddles[6].re + t10 * self.twiddles[4].re + t12 * self.twiddles[2].re + t14 * self.twiddles[0].re;
+        let t23 = t1 * self.twiddles[1].im + t3 * self.twiddles[3].im + t5 * self.twiddles[5].im + t7 * self.twiddles[7].im - t9 * self.twiddles[6].im - t11 * self.twiddles[4].im - t13 * self.twiddles[2].im - t15 * self.twiddles[0].im;
+        let t24 = Complex { re: -t23.im, im: t23.re };
+        let t25 = t22 + t24;
+        let t26 = t22 - t24;
+        let t27 = x0 + t0 * self.twiddles[2].re + t2 * self.twiddles[5].re + t4 * self.twiddles[7].re + t6 * self.twiddles[4].re + t8 * self.twiddles[1].re + t10 * self.twiddles[0].re + t12 * self.twiddles[3].re + t14 * self.twiddles[6].re;
+        let t28 = t1 * self.twiddles[2].im + t3 * self.twiddles[5].im - t5 * self.twiddles[7].im - t7 * self.twiddles[4].im - t9 * self.twiddles[1].im + t11 * self.twiddles[0].im + t13 * self.twiddles[3].im + t15 * self.twiddles[6].im;
+        let t29 = Complex { re: -t28.im, im: t28.re };
+        let t30 = t27 + t29;
+        let t31 = t27 - t29;
+        let t32 = x0 + t0 * self.twiddles[3].re + t2 * self.twiddles[7].re + t4 * self.twiddles[4].re + t6 * self.twiddles[0].re + t8 * self.twiddles[2].re + t10 * self.twiddles[6].re + t12 * self.twiddles[5].re + t14 * self.twiddles[1].re;
+        let t33 = t1 * self.twiddles[3].im + t3 * self.twiddles[7].im - t5 * self.twiddles[4].im - t7 * self.twiddles[0].im + t9 * self.twiddles[2].im + t11 * self.twiddles[6].im - t13 * self.twiddles[5].im - t15 * self.twiddles[1].im;
+        let t34 = Complex { re: -t33.im, im: t33.re };
+        let t35 = t32 + t34;
+        let t36 = t32 - t34;
+        let t37 = x0 + t0 * self.twiddles[4].re + t2 * self.twiddles[6].re + t4 * self.twiddles[1].re + t6 * self.twiddles[2].re + t8 * self.twiddles[7].re + t10 * self.twiddles[3].re + t12 * self.twiddles[0].re + t14 * self.twiddles[5].re;
+        let t38 = t1 * self.twiddles[4].im - t3 * self.twiddles[6].im - t5 * self.twiddles[1].im + t7 * self.twiddles[2].im + t9 * self.twiddles[7].im - t11 * self.twiddles[3].im + t13 * self.twiddles[0].im + t15 * self.twiddles[5].im;
+        let t39 = Complex { re: -t38.im, im: t38.re };
+        let t40 = t37 + t39;
+        let t41 = t37 - t39;
+        let t42 = x0 + t0 * self.twiddles[5].re + t2 * self.twiddles[4].re + t4 * self.twiddles[0].re + t6 * self.twiddles[6].re + t8 * self.twiddles[3].re + t10 * self.twiddles[1].re + t12 * self.twiddles[7].re + t14 * self.twiddles[2].re;
+        let t43 = t1 * self.twiddles[5].im - t3 * self.twiddles[4].im + t5 * self.twiddles[0].im + t7 * self.twiddles[6].im - t9 * self.twiddles[3].im + t11 * self.twiddles[1].im + t13 * self.twiddles[7].im - t15 * self.twiddles[2].im;
+        let t44 = Complex { re: -t43.im, im: t43.re };
+        let t45 = t42 + t44;
+        let t46 = t42 - t44;
+        let t47 = x0 + t0 * self.twiddles[6].re + t2 * self.twiddles[2].re + t4 * self.twiddles[3].re + t6 * self.twiddles[5].re + t8 * self.twiddles[0].re + t10 * self.twiddles[7].re + t12 * self.twiddles[1].re + t14 * self.twiddles[4].re;
+        let t48 = t1 * self.twiddles[6].im - t3 * self.twiddles[2].im + t5 * self.twiddles[3].im - t7 * self.twiddles[5].im + t9 * self.twiddles[0].im + t11 * self.twiddles[7].im - t13 * self.twiddles[1].im + t15 * self.twiddles[4].im;
+        let t49 = Complex { re: -t48.im, im: t48.re };
+        let t50 = t47 + t49;
+        let t51 = t47 - t49;
+        let t52 = x0 + t0 * self.twiddles[7].re + t2 * self.twiddles[0].re + t4 * self.twiddles[6].re + t6 * self.twiddles[1].re + t8 * self.twiddles[5].re + t10 * self.twiddles[2].re + t12 * self.twiddles[4].re + t14 * self.twiddles[3].re;
+        let t53 = t1 * self.twiddles[7].im - t3 * self.twiddles[0].im + t5 * self.twiddles[6].im - t7 * self.twiddles[1].im + t9 * self.twiddles[5].im - t11 * self.twiddles[2].im + t13 * self.twiddles[4].im - t15 * self.twiddles[3].im;
+        let t54 = Complex { re: -t53.im, im: t53.re };
+        let t55 = t52 + t54;
+        let t56 = t52 - t54;
+        *buffer.get_unchecked_mut(0) = t16;
+        *buffer.get_unchecked_mut(1) = t20;
+        *buffer.get_unchecked_mut(2) = t25;
+        *buffer.get_unchecked_mut(3) = t30;
+        *buffer.get_unchecked_mut(4) = t35;
+        *buffer.get_unchecked_mut(5) = t40;
+        *buffer.get_unchecked_mut(6) = t45;
+        *buffer.get_unchecked_mut(7) = t50;
+        *buffer.get_unchecked_mut(8) = t55;
+        *buffer.get_unchecked_mut(9) = t56;
+        *buffer.get_unchecked_mut(10) = t51;
+        *buffer.get_unchecked_mut(11) = t46;
+        *buffer.get_unchecked_mut(12) = t41;
+        *buffer.get_unchecked_mut(13) = t36;
+        *buffer.get_unchecked_mut(14) = t31;
+        *buffer.get_unchecked_mut(15) = t26;
+        *buffer.get_unchecked_mut(16) = t21;
+    }
+    #[inline(always)]
+    unsafe fn process_multi_inplace(&self, buffer: &mut [Complex<T>]) {
+        for chunk in buffer.chunks_mut(self.len()) {
+            self.process_inplace(chunk);
+        }
+    }
+}
+boilerplate_fft_butterfly!(Butterfly17, 17);
+
+
+
+pub struct Butterfly19<T> {
+    twiddles: [Complex<T>; 9],
+    inverse: bool,
+}
+impl<T: FFTnum> Butterfly19<T> {
+    pub fn new(inverse: bool) -> Self {
+        Butterfly19 {
+            twiddles: [
+                twiddles::single_twiddle(1, 19, inverse),
+                twiddles::single_twiddle(2, 19, inverse),
+                twiddles::single_twiddle(3, 19, inverse),
+                twiddles::single_twiddle(4, 19, inverse),
+                twiddles::single_twiddle(5, 19, inverse),
+                twiddles::single_twiddle(6, 19, inverse),
+                twiddles::single_twiddle(7, 19, inverse),
+                twiddles::single_twiddle(8, 19, inverse),
+                twiddles::single_twiddle(9, 19, inverse),
+            ],
+            inverse,
+        }
+    }
+}
+impl<T: FFTnum> FFTButterfly<T> for Butterfly19<T> {
+    #[inline(always)]
+    unsafe fn process_inplace(&self, buffer: &mut [Complex<T>]) {
+        let x0 = *buffer.get_unchecked(0);
+        let x1 = *buffer.get_unchecked(1);
+        let x2 = *buffer.get_unchecked(2);
+        let x3 = *buffer.get_unchecked(3);
+        let x4 = *buffer.get_unchecked(4);
+        let x5 = *buffer.get_unchecked(5);
+        let x6 = *buffer.get_unchecked(6);
+        let x7 = *buffer.get_unchecked(7);
+        let x8 = *buffer.get_unchecked(8);
+        let x9 = *buffer.get_unchecked(9);
+        let x10 = *buffer.get_unchecked(10);
+        let x11 = *buffer.get_unchecked(11);
+        let x12 = *buffer.get_unchecked(12);
+        let x13 = *buffer.get_unchecked(13);
+        let x14 = *buffer.get_unchecked(14);
+        let x15 = *buffer.get_unchecked(15);
+        let x16 = *buffer.get_unchecked(16);
+        let x17 = *buffer.get_unchecked(17);
+        let x18 = *buffer.get_unchecked(18);
+        let t0 = x1 + x18;
+        let t1 = x1 - x18;
+        let t2 = x2 + x17;
+        let t3 = x2 - x17;
+        let t4 = x3 + x16;
+        let t5 = x3 - x16;
+        let t6 = x4 + x15;
+        let t7 = x4 - x15;
+        let t8 = x5 + x14;
+        let t9 = x5 - x14;
+        let t10 = x6 + x13;
+        let t11 = x6 - x13;
+        let t12 = x7 + x12;
+        let t13 = x7 - x12;
+        let t14 = x8 + x11;
+        let t15 = x8 - x11;
+        let t16 = x9 + x10;
+        let t17 = x9 - x10;
+        let t18 = x0 + t0 + t2 + t4 + t6 + t8 + t10 + t12 + t14 + t16;
+        let t19 = x0 + t0 * self.twiddles[0].re + t2 * self.twiddles[1].re + t4 * self.twiddles[2].re + t6 * self.twiddles[3].re + t8 * self.twiddles[4].re + t10 * self.twiddles[5].re + t12 * self.twiddles[6].re + t14 * self.twiddles[7].re + t16 * self.twiddles[8].re;
+        let t20 = t1 * self.twiddles[0].im + t3 * self.twiddles[1].im + t5 * self.twiddles[2].im + t7 * self.twiddles[3].im + t9 * self.twiddles[4].im + t11 * self.twiddles[5].im + t13 * self.twiddles[6].im + t15 * self.twiddles[7].im + t17 * self.twiddles[8].im;
+        let t21 = Complex { re: -t20.im, im: t20.re };
+        let t22 = t19 + t21;
+        let t23 = t19 - t21;
+        let t24 = x0 + t0 * self.twiddles[1].re + t2 * self.twiddles[3].re + t4 * self.twiddles[5].re + t6 * self.twiddles[7].re + t8 * self.twiddles[8].re + t10 * self.twiddles[6].re + t12 * self.twiddles[4].re + t14 * self.twiddles[2].re + t16 * self.twiddles[0].re;
+        let t25 = t1 * self.twiddles[1].im + t3 * self.twiddles[3].im + t5 * self.twiddles[5].im + t7 * self.twiddles[7].im - t9 * self.twiddles[8].im - t11 * self.twiddles[6].im - t13 * self.twiddles[4].im - t15 * self.twiddles[2].im - t17 * self.twiddles[0].im;
+        let t26 = Complex { re: -t25.im, im: t25.re };
+        let t27 = t24 + t26;
+        let t28 = t24 - t26;
+        let t29 = x0 + t0 * self.twiddles[2].re + t2 * self.twiddles[5].re + t4 * self.twiddles[8].re + t6 * self.twiddles[6].re + t8 * self.twiddles[3].re + t10 * self.twiddles[0].re + t12 * self.twiddles[1].re + t14 * self.twiddles[4].re + t16 * self.twiddles[7].re;
+        let t30 = t1 * self.twiddles[2].im + t3 * self.twiddles[5].im + t5 * self.twiddles[8].im - t7 * self.twiddles[6].im - t9 * self.twiddles[3].im - t11 * self.twiddles[0].im + t13 * self.twiddles[1].im + t15 * self.twiddles[4].im + t17 * self.twiddles[7].im;
+        let t31 = Complex { re: -t30.im, im: t30.re };
+        let t32 = t29 + t31;
+        let t33 = t29 - t31;
+        let t34 = x0 + t0 * self.twiddles[3].re + t2 * self.twiddles[7].re + t4 * self.twiddles[6].re + t6 * self.twiddles[2].re + t8 * self.twiddles[0].re + t10 * self.twiddles[4].re + t12 * self.twiddles[8].re + t14 * self.twiddles[5].re + t16 * self.twiddles[1].re;
+        let t35 = t1 * self.twiddles[3].im + t3 * self.twiddles[7].im - t5 * self.twiddles[6].im - t7 * self.twiddles[2].im + t9 * self.twiddles[0].im + t11 * self.twiddles[4].im + t13 * self.twiddles[8].im - t15 * self.twiddles[5].im - t17 * self.twiddles[1].im;
+        let t36 = Complex { re: -t35.im, im: t35.re };
+        let t37 = t34 + t36;
+        let t38 = t34 - t36;
+        let t39 = x0 + t0 * self.twiddles[4].re + t2 * self.twiddles[8].re + t4 * self.twiddles[3].re + t6 * self.twiddles[0].re + t8 * self.twiddles[5].re + t10 * self.twiddles[7].re + t12 * self.twiddles[2].re + t14 * self.twiddles[1].re + t16 * self.twiddles[6].re;
+        let t40 = t1 * self.twiddles[4].im - t3 * self.twiddles[8].im - t5 * self.twiddles[3].im + t7 * self.twiddles[0].im + t9 * self.twiddles[5].im - t11 * self.twiddles[7].im - t13 * self.twiddles[2].im + t15 * self.twiddles[1].im + t17 * self.twiddles[6].im;
+        let t41 = Complex { re: -t40.im, im: t40.re };
+        let t42 = t39 + t41;
+        let t43 = t39 - t41;
+        let t44 = x0 + t0 * self.twiddles[5].re + t2 * self.twiddles[6].re + t4 * self.twiddles[0].re + t6 * self.twiddles[4].re + t8 * self.twiddles[7].re + t10 * self.twiddles[1].re + t12 * self.twiddles[3].re + t14 * self.twiddles[8].re + t16 * self.twiddles[2].re;
+        let t45 = t1 * self.twiddles[5].im - t3 * self.twiddles[6].im - t5 * self.twiddles[0].im + t7 * self.twiddles[4].im - t9 * self.twiddles[7].im - t11 * self.twiddles[1].im + t13 * self.twiddles[3].im - t15 * self.twiddles[8].im - t17 * self.twiddles[2].im;
+        let t46 = Complex { re: -t45.im, im: t45.re };
+        let t47 = t44 + t46;
+        let t48 = t44 - t46;
+        let t49 = x0 + t0 * self.twiddles[6].re + t2 * self.twiddles[4].re + t4 * self.twiddles[1].re + t6 * self.twiddles[8].re + t8 * self.twiddles[2].re + t10 * self.twiddles[3].re + t12 * self.twiddles[7].re + t14 * self.twiddles[0].re + t16 * self.twiddles[5].re;
+        let t50 = t1 * self.twiddles[6].im - t3 * self.twiddles[4].im + t5 * self.twiddles[1].im + t7 * self.twiddles[8].im - t9 * self.twiddles[2].im + t11 * self.twiddles[3].im - t13 * self.twiddles[7].im - t15 * self.twiddles[0].im + t17 * self.twiddles[5].im;
+        let t51 = Complex { re: -t50.im, im: t50.re };
+        let t52 = t49 + t51;
+        let t53 = t49 - t51;
+        let t54 = x0 + t0 * self.twiddles[7].re + t2 * self.twiddles[2].re + t4 * self.twiddles[4].re + t6 * self.twiddles[5].re + t8 * self.twiddles[1].re + t10 * self.twiddles[8].re + t12 * self.twiddles[0].re + t14 * self.twiddles[6].re + t16 * self.twiddles[3].re;
+        let t55 = t1 * self.twiddles[7].im - t3 * self.twiddles[2].im + t5 * self.twiddles[4].im - t7 * self.twiddles[5].im + t9 * self.twiddles[1].im - t11 * self.twiddles[8].im - t13 * self.twiddles[0].im + t15 * self.twiddles[6].im - t17 * self.twiddles[3].im;
+        let t56 = Complex { re: -t55.im, im: t55.re };
+        let t57 = t54 + t56;
+        let t58 = t54 - t56;
+        let t59 = x0 + t0 * self.twiddles[8].re + t2 * self.twiddles[0].re + t4 * self.twiddles[7].re + t6 * self.twiddles[1].re + t8 * self.twiddles[6].re + t10 * self.twiddles[2].re + t12 * self.twiddles[5].re + t14 * self.twiddles[3].re + t16 * self.twiddles[4].re;
+        let t60 = t1 * self.twiddles[8].im - t3 * self.twiddles[0].im + t5 * self.twiddles[7].im - t7 * self.twiddles[1].im + t9 * self.twiddles[6].im - t11 * self.twiddles[2].im + t13 * self.twiddles[5].im - t15 * self.twiddles[3].im + t17 * self.twiddles[4].im;
+        let t61 = Complex { re: -t60.im, im: t60.re };
+        let t62 = t59 + t61;
+        let t63 = t59 - t61;
+        *buffer.get_unchecked_mut(0) = t18;
+        *buffer.get_unchecked_mut(1) = t22;
+        *buffer.get_unchecked_mut(2) = t27;
+        *buffer.get_unchecked_mut(3) = t32;
+        *buffer.get_unchecked_mut(4) = t37;
+        *buffer.get_unchecked_mut(5) = t42;
+        *buffer.get_unchecked_mut(6) = t47;
+        *buffer.get_unchecked_mut(7) = t52;
+        *buffer.get_unchecked_mut(8) = t57;
+        *buffer.get_unchecked_mut(9) = t62;
+        *buffer.get_unchecked_mut(10) = t63;
+        *buffer.get_unchecked_mut(11) = t58;
+        *buffer.get_unchecked_mut(12) = t53;
+        *buffer.get_unchecked_mut(13) = t48;
+        *buffer.get_unchecked_mut(14) = t43;
+        *buffer.get_unchecked_mut(15) = t38;
+        *buffer.get_unchecked_mut(16) = t33;
+        *buffer.get_unchecked_mut(17) = t28;
+        *buffer.get_unchecked_mut(18) = t23;
+    }
+    #[inline(always)]
+    unsafe fn process_multi_inplace(&self, buffer: &mut [Complex<T>]) {
+        for chunk in buffer.chunks_mut(self.len()) {
+            self.process_inplace(chunk);
+        }
+    }
+}
+boilerplate_fft_butterfly!(Butterfly19, 19);
+
+
+
+pub struct Butterfly23<T> {
+    twiddles: [Complex<T>; 11],
+    inverse: bool,
+}
+impl<T: FFTnum> Butterfly23<T> {
+    pub fn new(inverse: bool) -> Self {
+        Butterfly23 {
+            twiddles: [
+                twiddles::single_twiddle(1, 23, inverse),
+                twiddles::single_twiddle(2, 23, inverse),
+                twiddles::single_twiddle(3, 23, inverse),
+                twiddles::single_twiddle(4, 23, inverse),
+                twiddles::single_twiddle(5, 23, inverse),
+                twiddles::single_twiddle(6, 23, inverse),
+                twiddles::single_twiddle(7, 23, inverse),
+                twiddles::single_twiddle(8, 23, inverse),
+                twiddles::single_twiddle(9, 23, inverse),
+                twiddles::single_twiddle(10, 23, inverse),
+                twiddles::single_twiddle(11, 23, inverse),
+            ],
+            inverse,
+        }
+    }
+}
+impl<T: FFTnum> FFTButterfly<T> for Butterfly23<T> {
+    #[inline(always)]
+    unsafe fn process_inplace(&self, buffer: &mut [Complex<T>]) {
+        let x0 = *buffer.get_unchecked(0);
+        let x1 = *buffer.get_unchecked(1);
+        let x2 = *buffer.get_unchecked(2);
+        let x3 = *buffer.get_unchecked(3);
+        let x4 = *buffer.get_unchecked(4);
+        let x5 = *buffer.get_unchecked(5);
+        let x6 = *buffer.get_unchecked(6);
+        let x7 = *buffer.get_unchecked(7);
+        let x8 = *buffer.get_unchecked(8);
+        let x9 = *buffer.get_unchecked(9);
+        let x10 = *buffer.get_unchecked(10);
+        let x11 = *buffer.get_unchecked(11);
+        let x12 = *buffer.get_unchecked(12);
+        let x13 = *buffer.get_unchecked(13);
+        let x14 = *buffer.get_unchecked(14);
+        let x15 = *buffer.get_unchecked(15);
+        let x16 = *buffer.get_unchecked(16);
+        let x17 = *buffer.get_unchecked(17);
+        let x18 = *buffer.get_unchecked(18);
+        let x19 = *buffer.get_unchecked(19);
+        let x20 = *buffer.get_unchecked(20);
+        let x21 = *buffer.get_unchecked(21);
+        let x22 = *buffer.get_unchecked(22);
+        let t0 = x1 + x22;
+        let t1 = x1 - x22;
+        let t2 = x2 + x21;
+        let t3 = x2 - x21;
+        let t4 = x3 + x20;
+        let t5 = x3 - x20;
+        let t6 = x4 + x19;
+        let t7 = x4 - x19;
+        let t8 = x5 + x18;
+        let t9 = x5 - x18;
+        let t10 = x6 + x17;
+        let t11 = x6 - x17;
+        let t12 = x7 + x16;
+        let t13 = x7 - x16;
+        let t14 = x8 + x15;
+        let t15 = x8 - x15;
+        let t16 = x9 + x14;
+        let t17 = x9 - x14;
+        let t18 = x10 + x13;
+        let t19 = x10 - x13;
+        let t20 = x11 + x12;
+        let t21 = x11 - x12;
+        let t22 = x0 + t0 + t2 + t4 + t6 + t8 + t10 + t12 + t14 + t16 + t18 + t20;
+        let t23 = x0 + t0 * self.twiddles[0].re + t2 * self.twiddles[1].re + t4 * self.twiddles[2].re + t6 * self.twiddles[3].re + t8 * self.twiddles[4].re + t10 * self.twiddles[5].re + t12 * self.twiddles[6].re + t14 * self.twiddles[7].re + t16 * self.twiddles[8].re + t18 * self.twiddles[9].re + t20 * self.twiddles[10].re;
+        let t24 = t1 * self.twiddles[0].im + t3 * self.twiddles[1].im + t5 * self.twiddles[2].im + t7 * self.twiddles[3].im + t9 * self.twiddles[4].im + t11 * self.twiddles[5].im + t13 * self.twiddles[6].im + t15 * self.twiddles[7].im + t17 * self.twiddles[8].im + t19 * self.twiddles[9].im + t21 * self.twiddles[10].im;
+        let t25 = Complex { re: -t24.im, im: t24.re };
+        let t26 = t23 + t25;
+        let t27 = t23 - t25;
+        let t28 = x0 + t0 * self.twiddles[1].re + t2 * self.twiddles[3].re + t4 * self.twiddles[5].re + t6 * self.twiddles[7].re + t8 * self.twiddles[9].re + t10 * self.twiddles[10].re + t12 * self.twiddles[8].re + t14 * self.twiddles[6].re + t16 * self.twiddles[4].re + t18 * self.twiddles[2].re + t20 * self.twiddles[0].re;
+        let t29 = t1 * self.twiddles[1].im + t3 * self.twiddles[3].im + t5 * self.twiddles[5].im + t7 * self.twiddles[7].im + t9 * self.twiddles[9].im - t11 * self.twiddles[10].im - t13 * self.twiddles[8].im - t15 * self.twiddles[6].im - t17 * self.twiddles[4].im - t19 * self.twiddles[2].im - t21 * self.twiddles[0].im;
+        let t30 = Complex { re: -t29.im, im: t29.re };
+        let t31 = t28 + t30;
+        let t32 = t28 - t30;
+        let t33 = x0 + t0 * self.twiddles[2].re + t2 * self.twiddles[5].re + t4 * self.twiddles[8].re + t6 * self.twiddles[10].re + t8 * self.twiddles[7].re + t10 * self.twiddles[4].re + t12 * self.twiddles[1].re + t14 * self.twiddles[0].re + t16 * self.twiddles[3].re + t18 * self.twiddles[6].re + t20 * self.twiddles[9].re;
+        let t34 = t1 * self.twiddles[2].im + t3 * self.twiddles[5].im + t5 * self.twiddles[8].im - t7 * self.twiddles[10].im - t9 * self.twiddles[7].im - t11 * self.twiddles[4].im - t13 * self.twiddles[1].im + t15 * self.twiddles[0].im + t17 * self.twiddles[3].im + t19 * self.twiddles[6].im + t21 * self.twiddles[9].im;
+        let t35 = Complex { re: -t34.im, im: t34.re };
+        let t36 = t33 + t35;
+        let t37 = t33 - t35;
+        let t38 = x0 + t0 * self.twiddles[3].re + t2 * self.twiddles[7].re + t4 * self.twiddles[10].re + t6 * self.twiddles[6].re + t8 * self.twiddles[2].re + t10 * self.twiddles[0].re + t12 * self.twiddles[4].re + t14 * self.twiddles[8].re + t16 * self.twiddles[9].re + t18 * self.twiddles[5].re + t20 * self.twiddles[1].re;
+        let t39 = t1 * self.twiddles[3].im + t3 * self.twiddles[7].im - t5 * self.twiddles[10].im - t7 * self.twiddles[6].im - t9 * self.twiddles[2].im + t11 * self.twiddles[0].im + t13 * self.twiddles[4].im + t15 * self.twiddles[8].im - t17 * self.twiddles[9].im - t19 * self.twiddles[5].im - t21 * self.twiddles[1].im;
+        let t40 = Complex { re: -t39.im, im: t39.re };
+        let t41 = t38 + t40;
+        let t42 = t38 - t40;
+        let t43 = x0 + t0 * self.twiddles[4].re + t2 * self.twiddles[9].re + t4 * self.twiddles[7].re + t6 * self.twiddles[2].re + t8 * self.twiddles[1].re + t10 * self.twiddles[6].re + t12 * self.twiddles[10].re + t14 * self.twiddles[5].re + t16 * self.twiddles[0].re + t18 * self.twiddles[3].re + t20 * self.twiddles[8].re;
+        let t44 = t1 * self.twiddles[4].im + t3 * self.twiddles[9].im - t5 * self.twiddles[7].im - t7 * self.twiddles[2].im + t9 * self.twiddles[1].im + t11 * self.twiddles[6].im - t13 * self.twiddles[10].im - t15 * self.twiddles[5].im - t17 * self.twiddles[0].im + t19 * self.twiddles[3].im + t21 * self.twiddles[8].im;
+        let t45 = Complex { re: -t44.im, im: t44.re };
+        let t46 = t43 + t45;
+        let t47 = t43 - t45;
+        let t48 = x0 + t0 * self.twiddles[5].re + t2 * self.twiddles[10].re + t4 * self.twiddles[4].re + t6 * self.twiddles[0].re + t8 * self.twiddles[6].re + t10 * self.twiddles[9].re + t12 * self.twiddles[3].re + t14 * self.twiddles[1].re + t16 * self.twiddles[7].re + t18 * self.twiddles[8].re + t20 * self.twiddles[2].re;
+        let t49 = t1 * self.twiddles[5].im - t3 * self.twiddles[10].im - t5 * self.twiddles[4].im + t7 * self.twiddles[0].im + t9 * self.twiddles[6].im - t11 * self.twiddles[9].im - t13 * self.twiddles[3].im + t15 * self.twiddles[1].im + t17 * self.twiddles[7].im - t19 * self.twiddles[8].im - t21 * self.twiddles[2].im;
+        let t50 = Complex { re: -t49.im, im: t49.re };
+        let t51 = t48 + t50;
+        let t52 = t48 - t50;
+        let t53 = x0 + t0 * self.twiddles[6].re + t2 * self.twiddles[8].re + t4 * self.twiddles[1].re + t6 * self.twiddles[4].re + t8 * self.twiddles[10].re + t10 * self.twiddles[3].re + t12 * self.twiddles[2].re + t14 * self.twiddles[9].re + t16 * self.twiddles[5].re + t18 * self.twiddles[0].re + t20 * self.twiddles[7].re;
+        let t54 = t1 * self.twiddles[6].im - t3 * self.twiddles[8].im - t5 * self.twiddles[1].im + t7 * self.twiddles[4].im - t9 * self.twiddles[10].im - t11 * self.twiddles[3].im + t13 * self.twiddles[2].im + t15 * self.twiddles[9].im - t17 * self.twiddles[5].im + t19 * self.twiddles[0].im + t21 * self.twiddles[7].im;
+        let t55 = Complex { re: -t54.im, im: t54.re };
+        let t56 = t53 + t55;
+        let t57 = t53 - t55;
+        let t58 = x0 + t0 * self.twiddles[7].re + t2 * self.twiddles[6].re + t4 * self.twiddles[0].re + t6 * self.twiddles[8].re + t8 * self.twiddles[5].re + t10 * self.twiddles[1].re + t12 * self.twiddles[9].re + t14 * self.twiddles[4].re + t16 * self.twiddles[2].re + t18 * self.twiddles[10].re + t20 * self.twiddles[3].re;
+        let t59 = t1 * self.twiddles[7].im - t3 * self.twiddles[6].im + t5 * self.twiddles[0].im + t7 * self.twiddles[8].im - t9 * self.twiddles[5].im + t11 * self.twiddles[1].im + t13 * self.twiddles[9].im - t15 * self.twiddles[4].im + t17 * self.twiddles[2].im + t19 * self.twiddles[10].im - t21 * self.twiddles[3].im;
+        let t60 = Complex { re: -t59.im, im: t59.re };
+        let t61 = t58 + t60;
+        let t62 = t58 - t60;
+        let t63 = x0 + t0 * self.twiddles[8].re + t2 * self.twiddles[4].re + t4 * self.twiddles[3].re + t6 * self.twiddles[9].re + t8 * self.twiddles[0].re + t10 * self.twiddles[7].re + t12 * self.twiddles[5].re + t14 * self.twiddles[2].re + t16 * self.twiddles[10].re + t18 * self.twiddles[1].re + t20 * self.twiddles[6].re;
+        let t64 = t1 * self.twiddles[8].im - t3 * self.twiddles[4].im + t5 * self.twiddles[3].im - t7 * self.twiddles[9].im - t9 * self.twiddles[0].im + t11 * self.twiddles[7].im - t13 * self.twiddles[5].im + t15 * self.twiddles[2].im - t17 * self.twiddles[10].im - t19 * self.twiddles[1].im + t21 * self.twiddles[6].im;
+        let t65 = Complex { re: -t64.im, im: t64.re };
+        let t66 = t63 + t65;
+        let t67 = t63 - t65;
+        let t68 = x0 + t0 * self.twiddles[9].re + t2 * self.twiddles[2].re + t4 * self.twiddles[6].re + t6 * self.twiddles[5].re + t8 * self.twiddles[3].re + t10 * self.twiddles[8].re + t12 * self.twiddles[0].re + t14 * self.twiddles[10].re + t16 * self.twiddles[1].re + t18 * self.twiddles[7].re + t20 * self.twiddles[4].re;
+        let t69 = t1 * self.twiddles[9].im - t3 * self.twiddles[2].im + t5 * self.twiddles[6].im - t7 * self.twiddles[5].im + t9 * self.twiddles[3].im - t11 * self.twiddles[8].im + t13 * self.twiddles[0].im + t15 * self.twiddles[10].im - t17 * self.twiddles[1].im + t19 * self.twiddles[7].im - t21 * self.twiddles[4].im;
+        let t70 = Complex { re: -t69.im, im: t69.re };
+        let t71 = t68 + t70;
+        let t72 = t68 - t70;
+        let t73 = x0 + t0 * self.twiddles[10].re + t2 * self.twiddles[0].re + t4 * self.twiddles[9].re + t6 * self.twiddles[1].re + t8 * self.twiddles[8].re + t10 * self.twiddles[2].re + t12 * self.twiddles[7].re + t14 * self.twiddles[3].re + t16 * self.twiddles[6].re + t18 * self.twiddles[4].re + t20 * self.twiddles[5].re;
+        let t74 = t1 * self.twiddles[10].im - t3 * self.twiddles[0].im + t5 * self.twiddles[9].im - t7 * self.twiddles[1].im + t9 * self.twiddles[8].im - t11 * self.twiddles[2].im + t13 * self.twiddles[7].im - t15 * self.twiddles[3].im + t17 * self.twiddles[6].im - t19 * self.twiddles[4].im + t21 * self.twiddles[5].im;
+        let t75 = Complex { re: -t74.im, im: t74.re };
+        let t76 = t73 + t75;
+        let t77 = t73 - t75;
+        *buffer.get_unchecked_mut(0) = t22;
+        *buffer.get_unchecked_mut(1) = t26;
+        *buffer.get_unchecked_mut(2) = t31;
+        *buffer.get_unchecked_mut(3) = t36;
+        *buffer.get_unchecked_mut(4) = t41;
+        *buffer.get_unchecked_mut(5) = t46;
+        *buffer.get_unchecked_mut(6) = t51;
+        *buffer.get_unchecked_mut(7) = t56;
+        *buffer.get_unchecked_mut(8) = t61;
+        *buffer.get_unchecked_mut(9) = t66;
+        *buffer.get_unchecked_mut(10) = t71;
+        *buffer.get_unchecked_mut(11) = t76;
+        *buffer.get_unchecked_mut(12) = t77;
+        *buffer.get_unchecked_mut(13) = t72;
+        *buffer.get_unchecked_mut(14) = t67;
+        *buffer.get_unchecked_mut(15) = t62;
+        *buffer.get_unchecked_mut(16) = t57;
+        *buffer.get_unchecked_mut(17) = t52;
+        *buffer.get_unchecked_mut(18) = t47;
+        *buffer.get_unchecked_mut(19) = t42;
+        *buffer.get_unchecked_mut(20) = t37;
+        *buffer.get_unchecked_mut(21) = t32;
+        *buffer.get_unchecked_mut(22) = t27;
+    }
+    #[inline(always)]
+    unsafe fn process_multi_inplace(&self, buffer: &mut [Complex<T>]) {
+        for chunk in buffer.chunks_mut(self.len()) {
+            self.process_inplace(chunk);
+        }
+    }
+}
+boilerplate_fft_butterfly!(Butterfly23, 23);
+
+
+
+pub struct Butterfly24<T> {
+    twiddles: [Complex<T>; 3],
+    inverse: bool,
+}
+impl<T: FFTnum> Butterfly24<T> {
+    pub fn new(inverse: bool) -> Self {
+        Butterfly24 {
+            twiddles: [
+                twiddles::single_twiddle(1, 3, inverse),
+                twiddles::single_twiddle(1, 8, inverse),
+                twiddles::single_twiddle(3, 8, inverse),
+            ],
+            inverse,
+        }
+    }
+}
+impl<T: FFTnum> FFTButterfly<T> for Butterfly24<T> {
+    #[inline(always)]
+    unsafe fn process_inplace(&self, buffer: &mut [Complex<T>]) {
+        let x0 = *buffer.get_unchecked(0);
+        let x1 = *buffer.get_unchecked(1);
+        let x2 = *buffer.get_unchecked(2);
+        let x3 = *buffer.get_unchecked(3);
+        let x4 = *buffer.get_unchecked(4);
+        let x5 = *buffer.get_unchecked(5);
+        let x6 = *buffer.get_unchecked(6);
+        let x7 = *buffer.get_unchecked(7);
+        let x8 = *buffer.get_unchecked(8);
+        let x9 = *buffer.get_unchecked(9);
+        let x10 = *buffer.get_unchecked(10);
+        let x11 = *buffer.get_unchecked(11);
+        let x12 = *buffer.get_unchecked(12);
+        let x13 = *buffer.get_unchecked(13);
+        let x14 = *buffer.get_unchecked(14);
+        let x15 = *buffer.get_unchecked(15);
+        let x16 = *buffer.get_unchecked(16);
+        let x17 = *buffer.get_unchecked(17);
+        let x18 = *buffer.get_unchecked(18);
+        let x19 = *buffer.get_unchecked(19);
+        let x20 = *buffer.get_unchecked(20);
+        let x21 = *buffer.get_unchecked(21);
+        let x22 = *buffer.get_unchecked(22);
+        let x23 = *buffer.get_unchecked(23);
+        let t0 = x8 + x16;
+        let t1 = x8 - x16;
+        let t2 = x0 + t0;
+        let t3 = x0 + t0 * self.twiddles[0].re;
+        let t4 = t1 * self.twiddles[0].im;
+        let t5 = Complex { re: -t4.im, im: t4.re };
+        let t6 = t3 + t5;
+        let t7 = t3 - t5;
+        let t8 = x11 + x19;
+        let t9 = x11 - x19;
+        let t10 = x3 + t8;
+        let t11 = x3 + t8 * self.twiddles[0].re;
+        let t12 = t9 * self.twiddles[0].im;
+        let t13 = Complex { re: -t12.im, im: t12.re };
+        let t14 = t11 + t13;
+        let t15 = t11 - t13;
+        let t16 = x14 + x22;
+        let t17 = x14 - x22;
+        let t18 = x6 + t16;
+        let t19 = x6 + t16 * self.twiddles[0].re;
+        let t20 = t17 * self.twiddles[0].im;
+        let t21 = Complex { re: -t20.im, im: t20.re };
+        let t22 = t19 + t21;
+        let t23 = t19 - t21;
+        let t24 = x17 + x1;
+        let t25 = x17 - x1;
+        let t26 = x9 + t24;
+        let t27 = x9 + t24 * self.twiddles[0].re;
+        let t28 = t25 * self.twiddles[0].im;
+        let t29 = Complex { re: -t28.im, im: t28.re };
+        let t30 = t27 + t29;
+        let t31 = t27 - t29;
+        let t32 = x20 + x4;
+        let t33 = x20 - x4;
+        let t34 = x12 + t32;
+        let t35 = x12 + t32 * self.twiddles[0].re;
+        let t36 = t33 * self.twiddles[0].im;
+        let t37 = Complex { re: -t36.im, im: t36.re };
+        let t38 = t35 + t37;
+        let t39 = t35 - t37;
+        let t40 = x23 + x7;
+        let t41 = x23 - x7;
+        let t42 = x15 + t40;
+        let t43 = x15 + t40 * self.twiddles[0].re;
+        let t44 = t41 * self.twiddles[0].im;
+        let t45 = Complex { re: -t44.im, im: t44.re };
+        let t46 = t43 + t45;
+        let t47 = t43 - t45;
+        let t48 = x2 + x10;
+        let t49 = x2 - x10;
+        let t50 = x18 + t48;
+        let t51 = x18 + t48 * self.twiddles[0].re;
+        let t52 = t49 * self.twiddles[0].im;
+        let t53 = Complex { re: -t52.im, im: t52.re };
+        let t54 = t51 + t53;
+        let t55 = t51 - t53;
+        let t56 = x5 + x13;
+        let t57 = x5 - x13;
+        let t58 = x21 + t56;
+        let t59 = x21 + t56 * self.twiddles[0].re;
+        let t60 = t57 * self.twiddles[0].im;
+        let t61 = Complex { re: -t60.im, im: t60.re };
+        let t62 = t59 + t61;
+        let t63 = t59 - t61;
+        let t64 = t2 + t34;
+        let t65 = t2 - t34;
+        let t66 = t10 + t42;
+        let t67 = t10 - t42;
+        let t68 = t67 * self.twiddles[1];
+        let t69 = t18 + t50;
+        let t70 = t18 - t50;
+        let t71 = twiddles::rotate_90(t70, self.inverse);
+        let t72 = t26 + t58;
+        let t73 = t26 - t58;
+        let t74 = t73 * self.twiddles[2];
+        let t75 = t64 + t69;
+        let t76 = t64 - t69;
+        let t77 = t66 + t72;
+        let t78 = t66 - t72;
+        let t79 = twiddles::rotate_90(t78, self.inverse);
+        let t80 = t75 + t77;
+        let t81 = t76 + t79;
+        let t82 = t75 - t77;
+        let t83 = t76 - t79;
+        let t84 = t65 + t71;
+        let t85 = t65 - t71;
+        let t86 = t68 + t74;
+        let t87 = t68 - t74;
+        let t88 = twiddles::rotate_90(t87, self.inverse);
+        let t89 = t84 + t86;
+        let t90 = t85 + t88;
+        let t91 = t84 - t86;
+        let t92 = t85 - t88;
+        let t93 = t6 + t38;
+        let t94 = t6 - t38;
+        let t95 = t14 + t46;
+        let t96 = t14 - t46;
+        let t97 = t96 * self.twiddles[1];
+        let t98 = t22 + t54;
+        let t99 = t22 - t54;
+        let t100 = twiddles::rotate_90(t99, self.inverse);
+        let t101 = t30 + t62;
+        let t102 = t30 - t62;
+        let t103 = t102 * self.twiddles[2];
+        let t104 = t93 + t98;
+        let t105 = t93 - t98;
+        let t106 = t95 + t101;
+        let t107 = t95 - t101;
+        let t108 = twiddles::rotate_90(t107, self.inverse);
+        let t109 = t104 + t106;
+        let t110 = t105 + t108;
+        let t111 = t104 - t106;
+        let t112 = t105 - t108;
+        let t113 = t94 + t100;
+        let t114 = t94 - t100;
+        let t115 = t97 + t103;
+        let t116 = t97 - t103;
+        let t117 = twiddles::rotate_90(t116, self.inverse);
+        let t118 = t113 + t115;
+        let t119 = t114 + t117;
+        let t120 = t113 - t115;
+        let t121 = t114 - t117;
+        let t122 = t7 + t39;
+        let t123 = t7 - t39;
+        let t124 = t15 + t47;
+        let t125 = t15 - t47;
+        let t126 = t125 * self.twiddles[1];
+        let t127 = t23 + t55;
+        let t128 = t23 - t55;
+        let t129 = twiddles::rotate_90(t128, self.inverse);
+        let t130 = t31 + t63;
+        let t131 = t31 - t63;
+        let t132 = t131 * self.twiddles[2];
+        let t133 = t122 + t127;
+        let t134 = t122 - t127;
+        let t135 = t124 + t130;
+        let t136 = t124 - t130;
+        let t137 = twiddles::rotate_90(t136, self.inverse);
+        let t138 = t133 + t135;
+        let t139 = t134 + t137;
+        let t140 = t133 - t135;
+        let t141 = t134 - t137;
+        let t142 = t123 + t129;
+        let t143 = t123 - t129;
+        let t144 = t126 + t132;
+        let t145 = t126 - t132;
+        let t146 = twiddles::rotate_90(t145, self.inverse);
+        let t147 = t142 + t144;
+        let t148 = t143 + t146;
+        let t149 = t142 - t144;
+        let t150 = t143 - t146;
+        *buffer.get_unchecked_mut(0) = t80;
+        *buffer.get_unchecked_mut(1) = t118;
+        *buffer.get_unchecked_mut(2) = t139;
+        *buffer.get_unchecked_mut(3) = t90;
+        *buffer.get_unchecked_mut(4) = t111;
+        *buffer.get_unchecked_mut(5) = t149;
+        *buffer.get_unchecked_mut(6) = t83;
+        *buffer.get_unchecked_mut(7) = t121;
+        *buffer.get_unchecked_mut(8) = t138;
+        *buffer.get_unchecked_mut(9) = t89;
+        *buffer.get_unchecked_mut(10) = t110;
+        *buffer.get_unchecked_mut(11) = t148;
+        *buffer.get_unchecked_mut(12) = t82;
+        *buffer.get_unchecked_mut(13) = t120;
+        *buffer.get_unchecked_mut(14) = t141;
+        *buffer.get_unchecked_mut(15) = t92;
+        *buffer.get_unchecked_mut(16) = t109;
+        *buffer.get_unchecked_mut(17) = t147;
+        *buffer.get_unchecked_mut(18) = t81;
+        *buffer.get_unchecked_mut(19) = t119;
+        *buffer.get_unchecked_mut(20) = t140;
+        *buffer.get_unchecked_mut(21) = t91;
+        *buffer.get_unchecked_mut(22) = t112;
+        *buffer.get_unchecked_mut(23) = t150;
+    }
+    #[inline(always)]
+    unsafe fn process_multi_inplace(&self, buffer: &mut [Complex<T>]) {
+        for chunk in buffer.chunks_mut(self.len()) {
+            self.process_inplace(chunk);
+        }
+    }
+}
+boilerplate_fft_butterfly!(Butterfly24, 24);
+
+
+
+pub struct Butterfly27<T> {
+    twiddles: [Complex<T>; 13],
+    inverse: bool,
+}
+impl<T: FFTnum> Butterfly27<T> {
+    pub fn new(inverse: bool) -> Self {
+        Butterfly27 {
+            twiddles: [
+                twiddles::single_twiddle(1, 3, inverse),
+                twiddles::single_twiddle(1, 9, inverse),
+                twiddles::single_twiddle(2, 9, inverse),
+                twiddles::single_twiddle(4, 9, inverse),
+                twiddles::single_twiddle(1, 27, inverse),
+                twiddles::single_twiddle(2, 27, inverse),
+                twiddles::single_twiddle(4, 27, inverse),
+                twiddles::single_twiddle(5, 27, inverse),
+                twiddles::single_twiddle(7, 27, inverse),
+                twiddles::single_twiddle(8, 27, inverse),
+                twiddles::single_twiddle(10, 27, inverse),
+                twiddles::single_twiddle(14, 27, inverse),
+                twiddles::single_twiddle(16, 27, inverse),
+            ],
+            inverse,
+        }
+    }
+}
+impl<T: FFTnum> FFTButterfly<T> for Butterfly27<T> {
+    #[inline(always)]
+    unsafe fn process_inplace(&self, buffer: &mut [Complex<T>]) {
+        let x0 = *buffer.get_unchecked(0);
+        let x1 = *buffer.get_unchecked(1);
+        let x2 = *buffer.get_unchecked(2);
+        let x3 = *buffer.get_unchecked(3);
+        let x4 = *buffer.get_unchecked(4);
+        let x5 = *buffer.get_unchecked(5);
+        let x6 = *buffer.get_unchecked(6);
+        let x7 = *buffer.get_unchecked(7);
+        let x8 = *buffer.get_unchecked(8);
+        let x9 = *buffer.get_unchecked(9);
+        let x10 = *buffer.get_unchecked(10);
+        let x11 = *buffer.get_unchecked(11);
+        let x12 = *buffer.get_unchecked(12);
+        let x13 = *buffer.get_unchecked(13);
+        let x14 = *buffer.get_unchecked(14);
+        let x15 = *buffer.get_unchecked(15);
+        let x16 = *buffer.get_unchecked(16);
+        let x17 = *buffer.get_unchecked(17);
+        let x18 = *buffer.get_unchecked(18);
+        let x19 = *buffer.get_unchecked(19);
+        let x20 = *buffer.get_unchecked(20);
+        let x21 = *buffer.get_unchecked(21);
+        let x22 = *buffer.get_unchecked(22);
+        let x23 = *buffer.get_unchecked(23);
+        let x24 = *buffer.get_unchecked(24);
+        let x25 = *buffer.get_unchecked(25);
+        let x26 = *buffer.get_unchecked(26);
+        let t0 = x9 + x18;
+        let t1 = x9 - x18;
+        let t2 = x0 + t0;
+        let t3 = x0 + t0 * self.twiddles[0].re;
+        let t4 = t1 * self.twiddles[0].im;
+        let t5 = Complex { re: -t4.im, im: t4.re };
+        let t6 = t3 + t5;
+        let t7 = t3 - t5;
+        let t8 = x12 + x21;
+        let t9 = x12 - x21;
+        let t10 = x3 + t8;
+        let t11 = x3 + t8 * self.twiddles[0].re;
+        let t12 = t9 * self.twiddles[0].im;
+        let t13 = Complex { re: -t12.im, im: t12.re };
+        let t14 = t11 + t13;
+        let t15 = t11 - t13;
+        let t16 = t14 * self.twiddles[1];
+        let t17 = t15 * self.twiddles[2];
+        let t18 = x15 + x24;
+        let t19 = x15 - x24;
+        let t20 = x6 + t18;
+        let t21 = x6 + t18 * self.twiddles[0].re;
+        let t22 = t19 * self.twiddles[0].im;
+        let t23 = Complex { re: -t22.im, im: t22.re };
+        let t24 = t21 + t23;
+        let t25 = t21 - t23;
+        let t26 = t24 * self.twiddles[2];
+        let t27 = t25 * self.twiddles[3];
+        let t28 = t10 + t20;
+        let t29 = t10 - t20;
+        let t30 = t2 + t28;
+        let t31 = t2 + t28 * self.twiddles[0].re;
+        let t32 = t29 * self.twiddles[0].im;
+        let t33 = Complex { re: -t32.im, im: t32.re };
+        let t34 = t31 + t33;
+        let t35 = t31 - t33;
+        let t36 = t16 + t26;
+        let t37 = t16 - t26;
+        let t38 = t6 + t36;
+        let t39 = t6 + t36 * self.twiddles[0].re;
+        let t40 = t37 * self.twiddles[0].im;
+        let t41 = Complex { re: -t40.im, im: t40.re };
+        let t42 = t39 + t41;
+        let t43 = t39 - t41;
+        let t44 = t17 + t27;
+        let t45 = t17 - t27;
+        let t46 = t7 + t44;
+        let t47 = t7 + t44 * self.twiddles[0].re;
+        let t48 = t45 * self.twiddles[0].im;
+        let t49 = Complex { re: -t48.im, im: t48.re };
+        let t50 = t47 + t49;
+        let t51 = t47 - t49;
+        let t52 = x10 + x19;
+        let t53 = x10 - x19;
+        let t54 = x1 + t52;
+        let t55 = x1 + t52 * self.twiddles[0].re;
+        let t56 = t53 * self.twiddles[0].im;
+        let t57 = Complex { re: -t56.im, im: t56.re };
+        let t58 = t55 + t57;
+        let t59 = t55 - t57;
+        let t60 = x13 + x22;
+        let t61 = x13 - x22;
+        let t62 = x4 + t60;
+        let t63 = x4 + t60 * self.twiddles[0].re;
+        let t64 = t61 * self.twiddles[0].im;
+        let t65 = Complex { re: -t64.im, im: t64.re };
+        let t66 = t63 + t65;
+        let t67 = t63 - t65;
+        let t68 = t66 * self.twiddles[1];
+        let t69 = t67 * self.twiddles[2];
+        let t70 = x16 + x25;
+        let t71 = x16 - x25;
+        let t72 = x7 + t70;
+        let t73 = x7 + t70 * self.twiddles[0].re;
+        let t74 = t71 * self.twiddles[0].im;
+        let t75 = Complex { re: -t74.im, im: t74.re };
+        let t76 = t73 + t75;
+        let t77 = t73 - t75;
+        let t78 = t76 * self.twiddles[2];
+        let t79 = t77 * self.twiddles[3];
+        let t80 = t62 + t72;
+        let t81 = t62 - t72;
+        let t82 = t54 + t80;
+        let t83 = t54 + t80 * self.twiddles[0].re;
+        let t84 = t81 * self.twiddles[0].im;
+        let t85 = Complex { re: -t84.im, im: t84.re };
+        let t86 = t83 + t85;
+        let t87 = t83 - t85;
+        let t88 = t68 + t78;
+        let t89 = t68 - t78;
+        let t90 = t58 + t88;
+        let t91 = t58 + t88 * self.twiddles[0].re;
+        let t92 = t89 * self.twiddles[0].im;
+        let t93 = Complex { re: -t92.im, im: t92.re };
+        let t94 = t91 + t93;
+        let t95 = t91 - t93;
+        let t96 = t69 + t79;
+        let t97 = t69 - t79;
+        let t98 = t59 + t96;
+        let t99 = t59 + t96 * self.twiddles[0].re;
+        let t100 = t97 * self.twiddles[0].im;
+        let t101 = Complex { re: -t100.im, im: t100.re };
+        let t102 = t99 + t101;
+        let t103 = t99 - t101;
+        let t104 = t90 * self.twiddles[4];
+        let t105 = t98 * self.twiddles[5];
+        let t106 = t86 * self.twiddles[1];
+        let t107 = t94 * self.twiddles[6];
+        let t108 = t102 * self.twiddles[7];
+        let t109 = t87 * self.twiddles[2];
+        let t110 = t95 * self.twiddles[8];
+        let t111 = t103 * self.twiddles[9];
+        let t112 = x11 + x20;
+        let t113 = x11 - x20;
+        let t114 = x2 + t112;
+        let t115 = x2 + t112 * self.twiddles[0].re;
+        let t116 = t113 * self.twiddles[0].im;
+        let t117 = Complex { re: -t116.im, im: t116.re };
+        let t118 = t115 + t117;
+        let t119 = t115 - t117;
+        let t120 = x14 + x23;
+        let t121 = x14 - x23;
+        let t122 = x5 + t120;
+        let t123 = x5 + t120 * self.twiddles[0].re;
+        let t124 = t121 * self.twiddles[0].im;
+        let t125 = Complex { re: -t124.im, im: t124.re };
+        let t126 = t123 + t125;
+        let t127 = t123 - t125;
+        let t128 = t126 * self.twiddles[1];
+        let t129 = t127 * self.twiddles[2];
+        let t130 = x17 + x26;
+        let t131 = x17 - x26;
+        let t132 = x8 + t130;
+        let t133 = x8 + t130 * self.twiddles[0].re;
+        let t134 = t131 * self.twiddles[0].im;
+        let t135 = Complex { re: -t134.im, im: t134.re };
+        let t136 = t133 + t135;
+        let t137 = t133 - t135;
+        let t138 = t136 * self.twiddles[2];
+        let t139 = t137 * self.twiddles[3];
+        let t140 = t122 + t132;
+        let t141 = t122 - t132;
+        let t142 = t114 + t140;
+        let t143 = t114 + t140 * self.twiddles[0].re;
+        let t144 = t141 * self.twiddles[0].im;
+        let t145 = Complex { re: -t144.im, im: t144.re };
+        let t146 = t143 + t145;
+        let t147 = t143 - t145;
+        let t148 = t128 + t138;
+        let t149 = t128 - t138;
+        let t150 = t118 + t148;
+        let t151 = t118 + t148 * self.twiddles[0].re;
+        let t152 = t149 * self.twiddles[0].im;
+        let t153 = Complex { re: -t152.im, im: t152.re };
+        let t154 = t151 + t153;
+        let t155 = t151 - t153;
+        let t156 = t129 + t139;
+        let t157 = t129 - t139;
+        let t158 = t119 + t156;
+        let t159 = t119 + t156 * self.twiddles[0].re;
+        let t160 = t157 * self.twiddles[0].im;
+        let t161 = Complex { re: -t160.im, im: t160.re };
+        let t162 = t159 + t161;
+        let t163 = t159 - t161;
+        let t164 = t150 * self.twiddles[5];
+        let t165 = t158 * self.twiddles[6];
+        let t166 = t146 * self.twiddles[2];
+        let t167 = t154 * self.twiddles[9];
+        let t168 = t162 * self.twiddles[10];
+        let t169 = t147 * self.twiddles[3];
+        let t170 = t155 * self.twiddles[11];
+        let t171 = t163 * self.twiddles[12];
+        let t172 = t82 + t142;
+        let t173 = t82 - t142;
+        let t174 = t30 + t172;
+        let t175 = t30 + t172 * self.twiddles[0].re;
+        let t176 = t173 * self.twiddles[0].im;
+        let t177 = Complex { re: -t176.im, im: t176.re };
+        let t178 = t175 + t177;
+        let t179 = t175 - t177;
+        let t180 = t104 + t164;
+        let t181 = t104 - t164;
+        let t182 = t38 + t180;
+        let t183 = t38 + t180 * self.twiddles[0].re;
+        let t184 = t181 * self.twiddles[0].im;
+        let t185 = Complex { re: -t184.im, im: t184.re };
+        let t186 = t183 + t185;
+        let t187 = t183 - t185;
+        let t188 = t105 + t165;
+        let t189 = t105 - t165;
+        let t190 = t46 + t188;
+        let t191 = t46 + t188 * self.twiddles[0].re;
+        let t192 = t189 * self.twiddles[0].im;
+        let t193 = Complex { re: -t192.im, im: t192.re };
+        let t194 = t191 + t193;
+        let t195 = t191 - t193;
+        let t196 = t106 + t166;
+        let t197 = t106 - t166;
+        let t198 = t34 + t196;
+        let t199 = t34 + t196 * self.twiddles[0].re;
+        let t200 = t197 * self.twiddles[0].im;
+        let t201 = Complex { re: -t200.im, im: t200.re };
+        let t202 = t199 + t201;
+        let t203 = t199 - t201;
+        let t204 = t107 + t167;
+        let t205 = t107 - t167;
+        let t206 = t42 + t204;
+        let t207 = t42 + t204 * self.twiddles[0].re;
+        let t208 = t205 * self.twiddles[0].im;
+        let t209 = Complex { re: -t208.im, im: t208.re };
+        let t210 = t207 + t209;
+        let t211 = t207 - t209;
+        let t212 = t108 + t168;
+        let t213 = t108 - t168;
+        let t214 = t50 + t212;
+        let t215 = t50 + t212 * self.twiddles[0].re;
+        let t216 = t213 * self.twiddles[0].im;
+        let t217 = Complex { re: -t216.im, im: t216.re };
+        let t218 = t215 + t217;
+        let t219 = t215 - t217;
+        let t220 = t109 + t169;
+        let t221 = t109 - t169;
+        let t222 = t35 + t220;
+        let t223 = t35 + t220 * self.twiddles[0].re;
+        let t224 = t221 * self.twiddles[0].im;
+        let t225 = Complex { re: -t224.im, im: t224.re };
+        let t226 = t223 + t225;
+        let t227 = t223 - t225;
+        let t228 = t110 + t170;
+        let t229 = t110 - t170;
+        let t230 = t43 + t228;
+        let t231 = t43 + t228 * self.twiddles[0].re;
+        let t232 = t229 * self.twiddles[0].im;
+        let t233 = Complex { re: -t232.im, im: t232.re };
+        let t234 = t231 + t233;
+        let t235 = t231 - t233;
+        let t236 = t111 + t171;
+        let t237 = t111 - t171;
+        let t238 = t51 + t236;
+        let t239 = t51 + t236 * self.twiddles[0].re;
+        let t240 = t237 * self.twiddles[0].im;
+        let t241 = Complex { re: -t240.im, im: t240.re };
+        let t242 = t239 + t241;
+        let t243 = t239 - t241;
+        *buffer.get_unchecked_mut(0) = t174;
+        *buffer.get_unchecked_mut(1) = t182;
+        *buffer.get_unchecked_mut(2) = t190;
+        *buffer.get_unchecked_mut(3) = t198;
+        *buffer.get_unchecked_mut(4) = t206;
+        *buffer.get_unchecked_mut(5) = t214;
+        *buffer.get_unchecked_mut(6) = t222;
+        *buffer.get_unchecked_mut(7) = t230;
+        *buffer.get_unchecked_mut(8) = t238;
+        *buffer.get_unchecked_mut(9) = t178;
+        *buffer.get_unchecked_mut(10) = t186;
+        *buffer.get_unchecked_mut(11) = t194;
+        *buffer.get_unchecked_mut(12) = t202;
+        *buffer.get_unchecked_mut(13) = t210;
+        *buffer.get_unchecked_mut(14) = t218;
+        *buffer.get_unchecked_mut(15) = t226;
+        *buffer.get_unchecked_mut(16) = t234;
+        *buffer.get_unchecked_mut(17) = t242;
+        *buffer.get_unchecked_mut(18) = t179;
+        *buffer.get_unchecked_mut(19) = t187;
+        *buffer.get_unchecked_mut(20) = t195;
+        *buffer.get_unchecked_mut(21) = t203;
+        *buffer.get_unchecked_mut(22) = t211;
+        *buffer.get_unchecked_mut(23) = t219;
+        *buffer.get_unchecked_mut(24) = t227;
+        *buffer.get_unchecked_mut(25) = t235;
+        *buffer.get_unchecked_mut(26) = t243;
+    }
+    #[inline(always)]
+    unsafe fn process_multi_inplace(&self, buffer: &mut [Complex<T>]) {
+        for chunk in buffer.chunks_mut(self.len()) {
+            self.process_inplace(chunk);
+        }
+    }
+}
+boilerplate_fft_butterfly!(Butterfly27, 27);
+
+
+
+pub struct Butterfly29<T> {
+    twiddles: [Complex<T>; 14],
+    inverse: bool,
+}
+impl<T: FFTnum> Butterfly29<T> {
+    pub fn new(inverse: bool) -> Self {
+        Butterfly29 {
+            twiddles: [
+                twiddles::single_twiddle(1, 29, inverse),
+                twiddles::single_twiddle(2, 29, inverse),
+                twiddles::single_twiddle(3, 29, inverse),
+                twiddles::single_twiddle(4, 29, inverse),
+                twiddles::single_twiddle(5, 29, inverse),
+                twiddles::single_twiddle(6, 29, inverse),
+                twiddles::single_twiddle(7, 29, inverse),
+                twiddles::single_twiddle(8, 29, inverse),
+                twiddles::single_twiddle(9, 29, inverse),
+                twiddles::single_twiddle(10, 29, inverse),
+                twiddles::single_twiddle(11, 29, inverse),
+                twiddles::single_twiddle(12, 29, inverse),
+                twiddles::single_twiddle(13, 29, inverse),
+                twiddles::single_twiddle(14, 29, inverse),
+            ],
+            inverse,
+        }
+    }
+}
+impl<T: FFTnum> FFTButterfly<T> for Butterfly29<T> {
+    #[inline(always)]
+    unsafe fn process_inplace(&self, buffer: &mut [Complex<T>]) {
+        let x0 = *buffer.get_unchecked(0);
+        let x1 = *buffer.get_unchecked(1);
+        let x2 = *buffer.get_unchecked(2);
+        let x3 = *buffer.get_unchecked(3);
+        let x4 = *buffer.get_unchecked(4);
+        let x5 = *buffer.get_unchecked(5);
+        let x6 = *buffer.get_unchecked(6);
+        let x7 = *buffer.get_unchecked(7);
+        let x8 = *buffer.get_unchecked(8);
+        let x9 = *buffer.get_unchecked(9);
+        let x10 = *buffer.get_unchecked(10);
+        let x11 = *buffer.get_unchecked(11);
+        let x12 = *buffer.get_unchecked(12);
+        let x13 = *buffer.get_unchecked(13);
+        let x14 = *buffer.get_unchecked(14);
+        let x15 = *buffer.get_unchecked(15);
+        let x16 = *buffer.get_unchecked(16);
+        let x17 = *buffer.get_unchecked(17);
+        let x18 = *buffer.get_unchecked(18);
+        let x19 = *buffer.get_unchecked(19);
+        let x20 = *buffer.get_unchecked(20);
+        let x21 = *buffer.get_unchecked(21);
+        let x22 = *buffer.get_unchecked(22);
+        let x23 = *buffer.get_unchecked(23);
+        let x24 = *buffer.get_unchecked(24);
+        let x25 = *buffer.get_unchecked(25);
+        let x26 = *buffer.get_unchecked(26);
+        let x27 = *buffer.get_unchecked(27);
+        let x28 = *buffer.get_unchecked(28);
+        let t0 = x1 + x28;
+        let t1 = x1 - x28;
+        let t2 = x2 + x27;
+        let t3 = x2 - x27;
+        let t4 = x3 + x26;
+        let t5 = x3 - x26;
+        let t6 = x4 + x25;
+        let t7 = x4 - x25;
+        let t8 = x5 + x24;
+        let t9 = x5 - x24;
+        let t10 = x6 + x23;
+        let t11 = x6 - x23;
+        let t12 = x7 + x22;
+        let t13 = x7 - x22;
+        let t14 = x8 + x21;
+        let t15 = x8 - x21;
+        let t16 = x9 + x20;
+        let t17 = x9 - x20;
+        let t18 = x10 + x19;
+        let t19 = x10 - x19;
+        let t20 = x11 + x18;
+        let t21 = x11 - x18;
+        let t22 = x12 + x17;
+        let t23 = x12 - x17;
+        let t24 = x13 + x16;
+        let t25 = x13 - x16;
+        let t26 = x14 + x15;
+        let t27 = x14 - x15;
+        let t28 = x0 + t0 + t2 + t4 + t6 + t8 + t10 + t12 + t14 + t16 + t18 + t20 + t22 + t24 + t26;
+        let t29 = x0 + t0 * self.twiddles[0].re + t2 * self.twiddles[1].re + t4 * self.twiddles[2].re + t6 * self.twiddles[3].re + t8 * self.twiddles[4].re + t10 * self.twiddles[5].re + t12 * self.twiddles[6].re + t14 * self.twiddles[7].re + t16 * self.twiddles[8].re + t18 * self.twiddles[9].re + t20 * self.twiddles[10].re + t22 * self.twiddles[11].re + t24 * self.twiddles[12].re + t26 * self.twiddles[13].re;
+        let t30 = t1 * self.twiddles[0].im + t3 * self.twiddles[1].im + t5 * self.twiddles[2].im + t7 * self.twiddles[3].im + t9 * self.twiddles[4].im + t11 * self.twiddles[5].im + t13 * self.twiddles[6].im + t15 * self.twiddles[7].im + t17 * self.twiddles[8].im + t19 * self.twiddles[9].im + t21 * self.twiddles[10].im + t23 * self.twiddles[11].im + t25 * self.twiddles[12].im + t27 * self.twiddles[13].im;
+        let t31 = Complex { re: -t30.im, im: t30.re };
+        let t32 = t29 + t31;
+        let t33 = t29 - t31;
+        let t34 = x0 + t0 * self.twiddles[1].re + t2 * self.twiddles[3].re + t4 * self.twiddles[5].re + t6 * self.twiddles[7].re + t8 * self.twiddles[9].re + t10 * self.twiddles[11].re + t12 * self.twiddles[13].re + t14 * self.twiddles[12].re + t16 * self.twiddles[10].re + t18 * self.twiddles[8].re + t20 * self.twiddles[6].re + t22 * self.twiddles[4].re + t24 * self.twiddles[2].re + t26 * self.twiddles[0].re;
+        let t35 = t1 * self.twiddles[1].im + t3 * self.twiddles[3].im + t5 * self.twiddles[5].im + t7 * self.twiddles[7].im + t9 * self.twiddles[9].im + t11 * self.twiddles[11].im + t13 * self.twiddles[13].im - t15 * self.twiddles[12].im - t17 * self.twiddles[10].im - t19 * self.twiddles[8].im - t21 * self.twiddles[6].im - t23 * self.twiddles[4].im - t25 * self.twiddles[2].im - t27 * self.twiddles[0].im;
+        let t36 = Complex { re: -t35.im, im: t35.re };
+        let t37 = t34 + t36;
+        let t38 = t34 - t36;
+        let t39 = x0 + t0 * self.twiddles[2].re + t2 * self.twiddles[5].re + t4 * self.twiddles[8].re + t6 * self.twiddles[11].re + t8 * self.twiddles[13].re + t10 * self.twiddles[10].re + t12 * self.twiddles[7].re + t14 * self.twiddles[4].re + t16 * self.twiddles[1].re + t18 * self.twiddles[0].re + t20 * self.twiddles[3].re + t22 * self.twiddles[6].re + t24 * self.twiddles[9].re + t26 * self.twiddles[12].re;
+        let t40 = t1 * self.twiddles[2].im + t3 * self.twiddles[5].im + t5 * self.twiddles[8].im + t7 * self.twiddles[11].im - t9 * self.twiddles[13].im - t11 * self.twiddles[10].im - t13 * self.twiddles[7].im - t15 * self.twiddles[4].im - t17 * self.twiddles[1].im + t19 * self.twiddles[0].im + t21 * self.twiddles[3].im + t23 * self.twiddles[6].im + t25 * self.twiddles[9].im + t27 * self.twiddles[12].im;
+        let t41 = Complex { re: -t40.im, im: t40.re };
+        let t42 = t39 + t41;
+        let t43 = t39 - t41;
+        let t44 = x0 + t0 * self.twiddles[3].re + t2 * self.twiddles[7].re + t4 * self.twiddles[11].re + t6 * self.twiddles[12].re + t8 * self.twiddles[8].re + t10 * self.twiddles[4].re + t12 * self.twiddles[0].re + t14 * self.twiddles[2].re + t16 * self.twiddles[6].re + t18 * self.twiddles[10].re + t20 * self.twiddles[13].re + t22 * self.twiddles[9].re + t24 * self.twiddles[5].re + t26 * self.twiddles[1].re;
+        let t45 = t1 * self.twiddles[3].im + t3 * self.twiddles[7].im + t5 * self.twiddles[11].im - t7 * self.twiddles[12].im - t9 * self.twiddles[8].im - t11 * self.twiddles[4].im - t13 * self.twiddles[0].im + t15 * self.twiddles[2].im + t17 * self.twiddles[6].im + t19 * self.twiddles[10].im - t21 * self.twiddles[13].im - t23 * self.twiddles[9].im - t25 * self.twiddles[5].im - t27 * self.twiddles[1].im;
+        let t46 = Complex { re: -t45.im, im: t45.re };
+        let t47 = t44 + t46;
+        let t48 = t44 - t46;
+        let t49 = x0 + t0 * self.twiddles[4].re + t2 * self.twiddles[9].re + t4 * self.twiddles[13].re + t6 * self.twiddles[8].re + t8 * self.twiddles[3].re + t10 * self.twiddles[0].re + t12 * self.twiddles[5].re + t14 * self.twiddles[10].re + t16 * self.twiddles[12].re + t18 * self.twiddles[7].re + t20 * self.twiddles[2].re + t22 * self.twiddles[1].re + t24 * self.twiddles[6].re + t26 * self.twiddles[11].re;
+        let t50 = t1 * self.twiddles[4].im + t3 * self.twiddles[9].im - t5 * self.twiddles[13].im - t7 * self.twiddles[8].im - t9 * self.twiddles[3].im + t11 * self.twiddles[0].im + t13 * self.twiddles[5].im + t15 * self.twiddles[10].im - t17 * self.twiddles[12].im - t19 * self.twiddles[7].im - t21 * self.twiddles[2].im + t23 * self.twiddles[1].im + t25 * self.twiddles[6].im + t27 * self.twiddles[11].im;
+        let t51 = Complex { re: -t50.im, im: t50.re };
+        let t52 = t49 + t51;
+        let t53 = t49 - t51;
+        let t54 = x0 + t0 * self.twiddles[5].re + t2 * self.twiddles[11].re + t4 * self.twiddles[10].re + t6 * self.twiddles[4].re + t8 * self.twiddles[0].re + t10 * self.twiddles[6].re + t12 * self.twiddles[12].re + t14 * self.twiddles[9].re + t16 * self.twiddles[3].re + t18 * self.twiddles[1].re + t20 * self.twiddles[7].re + t22 * self.twiddles[13].re + t24 * self.twiddles[8].re + t26 * self.twiddles[2].re;
+        let t55 = t1 * self.twiddles[5].im + t3 * self.twiddles[11].im - t5 * self.twiddles[10].im - t7 * self.twiddles[4].im + t9 * self.twiddles[0].im + t11 * self.twiddles[6].im + t13 * self.twiddles[12].im - t15 * self.twiddles[9].im - t17 * self.twiddles[3].im + t19 * self.twiddles[1].im + t21 * self.twiddles[7].im + t23 * self.twiddles[13].im - t25 * self.twiddles[8].im - t27 * self.twiddles[2].im;
+        let t56 = Complex { re: -t55.im, im: t55.re };
+        let t57 = t54 + t56;
+        let t58 = t54 - t56;
+        let t59 = x0 + t0 * self.twiddles[6].re + t2 * self.twiddles[13].re + t4 * self.twiddles[7].re + t6 * self.twiddles[0].re + t8 * self.twiddles[5].re + t10 * self.twiddles[12].re + t12 * self.twiddles[8].re + t14 * self.twiddles[1].re + t16 * self.twiddles[4].re + t18 * self.twiddles[11].re + t20 * self.twiddles[9].re + t22 * self.twiddles[2].re + t24 * self.twiddles[3].re + t26 * self.twiddles[10].re;
+        let t60 = t1 * self.twiddles[6].im + t3 * self.twiddles[13].im - t5 * self.twiddles[7].im - t7 * self.twiddles[0].im + t9 * self.twiddles[5].im + t11 * self.twiddles[12].im - t13 * self.twiddles[8].im - t15 * self.twiddles[1].im + t17 * self.twiddles[4].im + t19 * self.twiddles[11].im - t21 * self.twiddles[9].im - t23 * self.twiddles[2].im + t25 * self.twiddles[3].im + t27 * self.twiddles[10].im;
+        let t61 = Complex { re: -t60.im, im: t60.re };
+        let t62 = t59 + t61;
+        let t63 = t59 - t61;
+        let t64 = x0 + t0 * self.twiddles[7].re + t2 * self.twiddles[12].re + t4 * self.twiddles[4].re + t6 * self.twiddles[2].re + t8 * self.twiddles[10].re + t10 * self.twiddles[9].re + t12 * self.twiddles[1].re + t14 * self.twiddles[5].re + t16 * self.twiddles[13].re + t18 * self.twiddles[6].re + t20 * self.twiddles[0].re + t22 * self.twiddles[8].re + t24 * self.twiddles[11].re + t26 * self.twiddles[3].re;
+        let t65 = t1 * self.twiddles[7].im - t3 * self.twiddles[12].im - t5 * self.twiddles[4].im + t7 * self.twiddles[2].im + t9 * self.twiddles[10].im - t11 * self.twiddles[9].im - t13 * self.twiddles[1].im + t15 * self.twiddles[5].im + t17 * self.twiddles[13].im - t19 * self.twiddles[6].im + t21 * self.twiddles[0].im + t23 * self.twiddles[8].im - t25 * self.twiddles[11].im - t27 * self.twiddles[3].im;
+        let t66 = Complex { re: -t65.im, im: t65.re };
+        let t67 = t64 + t66;
+        let t68 = t64 - t66;
+        let t69 = x0 + t0 * self.twiddles[8].re + t2 * self.twiddles[10].re + t4 * self.twiddles[1].re + t6 * self.twiddles[6].re + t8 * self.twiddles[12].re + t10 * self.twiddles[3].re + t12 * self.twiddles[4].re + t14 * self.twiddles[13].re + t16 * self.twiddles[5].re + t18 * self.twiddles[2].re + t20 * self.twiddles[11].re + t22 * self.twiddles[7].re + t24 * self.twiddles[0].re + t26 * self.twiddles[9].re;
+        let t70 = t1 * self.twiddles[8].im - t3 * self.twiddles[10].im - t5 * self.twiddles[1].im + t7 * self.twiddles[6].im - t9 * self.twiddles[12].im - t11 * self.twiddles[3].im + t13 * self.twiddles[4].im + t15 * self.twiddles[13].im - t17 * self.twiddles[5].im + t19 * self.twiddles[2].im + t21 * self.twiddles[11].im - t23 * self.twiddles[7].im + t25 * self.twiddles[0].im + t27 * self.twiddles[9].im;
+        let t71 = Complex { re: -t70.im, im: t70.re };
+        let t72 = t69 + t71;
+        let t73 = t69 - t71;
+        let t74 = x0 + t0 * self.twiddles[9].re + t2 * self.twiddles[8].re + t4 * self.twiddles[0].re + t6 * self.twiddles[10].re + t8 * self.twiddles[7].re + t10 * self.twiddles[1].re + t12 * self.twiddles[11].re + t14 * self.twiddles[6].re + t16 * self.twiddles[2].re + t18 * self.twiddles[12].re + t20 * self.twiddles[5].re + t22 * self.twiddles[3].re + t24 * self.twiddles[13].re + t26 * self.twiddles[4].re;
+        let t75 = t1 * self.twiddles[9].im - t3 * self.twiddles[8].im + t5 * self.twiddles[0].im + t7 * self.twiddles[10].im - t9 * self.twiddles[7].im + t11 * self.twiddles[1].im + t13 * self.twiddles[11].im - t15 * self.twiddles[6].im + t17 * self.twiddles[2].im + t19 * self.twiddles[12].im - t21 * self.twiddles[5].im + t23 * self.twiddles[3].im + t25 * self.twiddles[13].im - t27 * self.twiddles[4].im;
+        let t76 = Complex { re: -t75.im, im: t75.re };
+        let t77 = t74 + t76;
+        let t78 = t74 - t76;
+        let t79 = x0 + t0 * self.twiddles[10].re + t2 * self.twiddles[6].re + t4 * self.twiddles[3].re + t6 * self.twiddles[13].re + t8 * self.twiddles[2].re + t10 * self.twiddles[7].re + t12 * self.twiddles[9].re + t14 * self.twiddles[0].re + t16 * self.twiddles[11].re + t18 * self.twiddles[5].re + t20 * self.twiddles[4].re + t22 * self.twiddles[12].re + t24 * self.twiddles[1].re + t26 * self.twiddles[8].re;
+        let t80 = t1 * self.twiddles[10].im - t3 * self.twiddles[6].im + t5 * self.twiddles[3].im - t7 * self.twiddles[13].im - t9 * self.twiddles[2].im + t11 * self.twiddles[7].im - t13 * self.twiddles[9].im + t15 * self.twiddles[0].im + t17 * self.twiddles[11].im - t19 * self.twiddles[5].im + t21 * self.twiddles[4].im - t23 * self.twiddles[12].im - t25 * self.twiddles[1].im + t27 * self.twiddles[8].im;
+        let t81 = Complex { re: -t80.im, im: t80.re };
+        let t82 = t79 + t81;
+        let t83 = t79 - t81;
+        let t84 = x0 + t0 * self.twiddles[11].re + t2 * self.twiddles[4].re + t4 * self.twiddles[6].re + t6 * self.twiddles[9].re + t8 * self.twiddles[1].re + t10 * self.twiddles[13].re + t12 * self.twiddles[2].re + t14 * self.twiddles[8].re + t16 * self.twiddles[7].re + t18 * self.twiddles[3].re + t20 * self.twiddles[12].re + t22 * self.twiddles[0].re + t24 * self.twiddles[10].re + t26 * self.twiddles[5].re;
+        let t85 = t1 * self.twiddles[11].im - t3 * self.twiddles[4].im + t5 * self.twiddles[6].im - t7 * self.twiddles[9].im + t9 * self.twiddles[1].im + t11 * self.twiddles[13].im - t13 * self.twiddles[2].im + t15 * self.twiddles[8].im - t17 * self.twiddles[7].im + t19 * self.twiddles[3].im - t21 * self.twiddles[12].im - t23 * self.twiddles[0].im + t25 * self.twiddles[10].im - t27 * self.twiddles[5].im;
+        let t86 = Complex { re: -t85.im, im: t85.re };
+        let t87 = t84 + t86;
+        let t88 = t84 - t86;
+        let t89 = x0 + t0 * self.twiddles[12].re + t2 * self.twiddles[2].re + t4 * self.twiddles[9].re + t6 * self.twiddles[5].re + t8 * self.twiddles[6].re + t10 * self.twiddles[8].re + t12 * self.twiddles[3].re + t14 * self.twiddles[11].re + t16 * self.twiddles[0].re + t18 * self.twiddles[13].re + t20 * self.twiddles[1].re + t22 * self.twiddles[10].re + t24 * self.twiddles[4].re + t26 * self.twiddles[7].re;
+        let t90 = t1 * self.twiddles[12].im - t3 * self.twiddles[2].im + t5 * self.twiddles[9].im - t7 * self.twiddles[5].im + t9 * self.twiddles[6].im - t11 * self.twiddles[8].im + t13 * self.twiddles[3].im - t15 * self.twiddles[11].im + t17 * self.twiddles[0].im + t19 * self.twiddles[13].im - t21 * self.twiddles[1].im + t23 * self.twiddles[10].im - t25 * self.twiddles[4].im + t27 * self.twiddles[7].im;
+        let t91 = Complex { re: -t90.im, im: t90.re };
+        let t92 = t89 + t91;
+        let t93 = t89 - t91;
+        let t94 = x0 + t0 * self.twiddles[13].re + t2 * self.twiddles[0].re + t4 * self.twiddles[12].re + t6 * self.twiddles[1].re + t8 * self.twiddles[11].re + t10 * self.twiddles[2].re + t12 * self.twiddles[10].re + t14 * self.twiddles[3].re + t16 * self.twiddles[9].re + t18 * self.twiddles[4].re + t20 * self.twiddles[8].re + t22 * self.twiddles[5].re + t24 * self.twiddles[7].re + t26 * self.twiddles[6].re;
+        let t95 = t1 * self.twiddles[13].im - t3 * self.twiddles[0].im + t5 * self.twiddles[12].im - t7 * self.twiddles[1].im + t9 * self.twiddles[11].im - t11 * self.twiddles[2].im + t13 * self.twiddles[10].im - t15 * self.twiddles[3].im + t17 * self.twiddles[9].im - t19 * self.twiddles[4].im + t21 * self.twiddles[8].im - t23 * self.twiddles[5].im + t25 * self.twiddles[7].im - t27 * self.twiddles[6].im;
+        let t96 = Complex { re: -t95.im, im: t95.re };
+        let t97 = t94 + t96;
+        let t98 = t94 - t96;
+        *buffer.get_unchecked_mut(0) = t28;
+        *buffer.get_unchecked_mut(1) = t32;
+        *buffer.get_unchecked_mut(2) = t37;
+        *buffer.get_unchecked_mut(3) = t42;
+        *buffer.get_unchecked_mut(4) = t47;
+        *buffer.get_unchecked_mut(5) = t52;
+        *buffer.get_unchecked_mut(6) = t57;
+        *buffer.get_unchecked_mut(7) = t62;
+        *buffer.get_unchecked_mut(8) = t67;
+        *buffer.get_unchecked_mut(9) = t72;
+        *buffer.get_unchecked_mut(10) = t77;
+        *buffer.get_unchecked_mut(11) = t82;
+        *buffer.get_unchecked_mut(12) = t87;
+        *buffer.get_unchecked_mut(13) = t92;
+        *buffer.get_unchecked_mut(14) = t97;
+        *buffer.get_unchecked_mut(15) = t98;
+        *buffer.get_unchecked_mut(16) = t93;
+        *buffer.get_unchecked_mut(17) = t88;
+        *buffer.get_unchecked_mut(18) = t83;
+        *buffer.get_unchecked_mut(19) = t78;
+        *buffer.get_unchecked_mut(20) = t73;
+        *buffer.get_unchecked_mut(21) = t68;
+        *buffer.get_unchecked_mut(22) = t63;
+        *buffer.get_unchecked_mut(23) = t58;
+        *buffer.get_unchecked_mut(24) = t53;
+        *buffer.get_unchecked_mut(25) = t48;
+        *buffer.get_unchecked_mut(26) = t43;
+        *buffer.get_unchecked_mut(27) = t38;
+        *buffer.get_unchecked_mut(28) = t33;
+    }
+    #[inline(always)]
+    unsafe fn process_multi_inplace(&self, buffer: &mut [Complex<T>]) {
+        for chunk in buffer.chunks_mut(self.len()) {
+            self.process_inplace(chunk);
+        }
+    }
+}
+boilerplate_fft_butterfly!(Butterfly29, 29);
+
+
+
+pub struct Butterfly31<T> {
+    twiddles: [Complex<T>; 15],
+    inverse: bool,
+}
+impl<T: FFTnum> Butterfly31<T> {
+    pub fn new(inverse: bool) -> Self {
+        Butterfly31 {
+            twiddles: [
+                twiddles::single_twiddle(1, 31, inverse),
+                twiddles::single_twiddle(2, 31, inverse),
+                twiddles::single_twiddle(3, 31, inverse),
+                twiddles::single_twiddle(4, 31, inverse),
+                twiddles::single_twiddle(5, 31, inverse),
+                twiddles::single_twiddle(6, 31, inverse),
+                twiddles::single_twiddle(7, 31, inverse),
+                twiddles::single_twiddle(8, 31, inverse),
+                twiddles::single_twiddle(9, 31, inverse),
+                twiddles::single_twiddle(10, 31, inverse),
+                twiddles::single_twiddle(11, 31, inverse),
+                twiddles::single_twiddle(12, 31, inverse),
+                twiddles::single_twiddle(13, 31, inverse),
+                twiddles::single_twiddle(14, 31, inverse),
+                twiddles::single_twiddle(15, 31, inverse),
+            ],
+            inverse,
+        }
+    }
+}
+impl<T: FFTnum> FFTButterfly<T> for Butterfly31<T> {
+    #[inline(always)]
+    unsafe fn process_inplace(&self, buffer: &mut [Complex<T>]) {
+        let x0 = *buffer.get_unchecked(0);
+        let x1 = *buffer.get_unchecked(1);
+        let x2 = *buffer.get_unchecked(2);
+        let x3 = *buffer.get_unchecked(3);
+        let x4 = *buffer.get_unchecked(4);
+        let x5 = *buffer.get_unchecked(5);
+        let x6 = *buffer.get_unchecked(6);
+        let x7 = *buffer.get_unchecked(7);
+        let x8 = *buffer.get_unchecked(8);
+        let x9 = *buffer.get_unchecked(9);
+        let x10 = *buffer.get_unchecked(10);
+        let x11 = *buffer.get_unchecked(11);
+        let x12 = *buffer.get_unchecked(12);
+        let x13 = *buffer.get_unchecked(13);
+        let x14 = *buffer.get_unchecked(14);
+        let x15 = *buffer.get_unchecked(15);
+        let x16 = *buffer.get_unchecked(16);
+        let x17 = *buffer.get_unchecked(17);
+        let x18 = *buffer.get_unchecked(18);
+        let x19 = *buffer.get_unchecked(19);
+        let x20 = *buffer.get_unchecked(20);
+        let x21 = *buffer.get_unchecked(21);
+        let x22 = *buffer.get_unchecked(22);
+        let x23 = *buffer.get_unchecked(23);
+        let x24 = *buffer.get_unchecked(24);
+        let x25 = *buffer.get_unchecked(25);
+        let x26 = *buffer.get_unchecked(26);
+        let x27 = *buffer.get_unchecked(27);
+        let x28 = *buffer.get_unchecked(28);
+        let x29 = *buffer.get_unchecked(29);
+        let x30 = *buffer.get_unchecked(30);
+        let t0 = x1 + x30;
+        let t1 = x1 - x30;
+        let t2 = x2 + x29;
+        let t3 = x2 - x29;
+        let t4 = x3 + x28;
+        let t5 = x3 - x28;
+        let t6 = x4 + x27;
+        let t7 = x4 - x27;
+        let t8 = x5 + x26;
+        let t9 = x5 - x26;
+        let t10 = x6 + x25;
+        let t11 = x6 - x25;
+        let t12 = x7 + x24;
+        let t13 = x7 - x24;
+        let t14 = x8 + x23;
+        let t15 = x8 - x23;
+        let t16 = x9 + x22;
+        let t17 = x9 - x22;
+        let t18 = x10 + x21;
+        let t19 = x10 - x21;
+        let t20 = x11 + x20;
+        let t21 = x11 - x20;
+        let t22 = x12 + x19;
+        let t23 = x12 - x19;
+        let t24 = x13 + x18;
+        let t25 = x13 - x18;
+        let t26 = x14 + x17;
+        let t27 = x14 - x17;
+        let t28 = x15 + x16;
+        let t29 = x15 - x16;
+        let t30 = x0 + t0 + t2 + t4 + t6 + t8 + t10 + t12 + t14 + t16 + t18 + t20 + t22 + t24 + t26 + t28;
+        let t31 = x0 + t0 * self.twiddles[0].re + t2 * self.twiddles[1].re + t4 * self.twiddles[2].re + t6 * self.twiddles[3].re + t8 * self.twiddles[4].re + t10 * self.twiddles[5].re + t12 * self.twiddles[6].re + t14 * self.twiddles[7].re + t16 * self.twiddles[8].re + t18 * self.twiddles[9].re + t20 * self.twiddles[10].re + t22 * self.twiddles[11].re + t24 * self.twiddles[12].re + t26 * self.twiddles[13].re + t28 * self.twiddles[14].re;
+        let t32 = t1 * self.twiddles[0].im + t3 * self.twiddles[1].im + t5 * self.twiddles[2].im + t7 * self.twiddles[3].im + t9 * self.twiddles[4].im + t11 * self.twiddles[5].im + t13 * self.twiddles[6].im + t15 * self.twiddles[7].im + t17 * self.twiddles[8].im + t19 * self.twiddles[9].im + t21 * self.twiddles[10].im + t23 * self.twiddles[11].im + t25 * self.twiddles[12].im + t27 * self.twiddles[13].im + t29 * self.twiddles[14].im;
+        let t33 = Complex { re: -t32.im, im: t32.re };
+        let t34 = t31 + t33;
+        let t35 = t31 - t33;
+        let t36 = x0 + t0 * self.twiddles[1].re + t2 * self.twiddles[3].re + t4 * self.twiddles[5].re + t6 * self.twiddles[7].re + t8 * self.twiddles[9].re + t10 * self.twiddles[11].re + t12 * self.twiddles[13].re + t14 * self.twiddles[14].re + t16 * self.twiddles[12].re + t18 * self.twiddles[10].re + t20 * self.twiddles[8].re + t22 * self.twiddles[6].re + t24 * self.twiddles[4].re + t26 * self.twiddles[2].re + t28 * self.twiddles[0].re;
+        let t37 = t1 * self.twiddles[1].im + t3 * self.twiddles[3].im + t5 * self.twiddles[5].im + t7 * self.twiddles[7].im + t9 * self.twiddles[9].im + t11 * self.twiddles[11].im + t13 * self.twiddles[13].im - t15 * self.twiddles[14].im - t17 * self.twiddles[12].im - t19 * self.twiddles[10].im - t21 * self.twiddles[8].im - t23 * self.twiddles[6].im - t25 * self.twiddles[4].im - t27 * self.twiddles[2].im - t29 * self.twiddles[0].im;
+        let t38 = Complex { re: -t37.im, im: t37.re };
+        let t39 = t36 + t38;
+        let t40 = t36 - t38;
+        let t41 = x0 + t0 * self.twiddles[2].re + t2 * self.twiddles[5].re + t4 * self.twiddles[8].re + t6 * self.twiddles[11].re + t8 * self.twiddles[14].re + t10 * self.twiddles[12].re + t12 * self.twiddles[9].re + t14 * self.twiddles[6].re + t16 * self.twiddles[3].re + t18 * self.twiddles[0].re + t20 * self.twiddles[1].re + t22 * self.twiddles[4].re + t24 * self.twiddles[7].re + t26 * self.twiddles[10].re + t28 * self.twiddles[13].re;
+        let t42 = t1 * self.twiddles[2].im + t3 * self.twiddles[5].im + t5 * self.twiddles[8].im + t7 * self.twiddles[11].im + t9 * self.twiddles[14].im - t11 * self.twiddles[12].im - t13 * self.twiddles[9].im - t15 * self.twiddles[6].im - t17 * self.twiddles[3].im - t19 * self.twiddles[0].im + t21 * self.twiddles[1].im + t23 * self.twiddles[4].im + t25 * self.twiddles[7].im + t27 * self.twiddles[10].im + t29 * self.twiddles[13].im;
+        let t43 = Complex { re: -t42.im, im: t42.re };
+        let t44 = t41 + t43;
+        let t45 = t41 - t43;
+        let t46 = x0 + t0 * self.twiddles[3].re + t2 * self.twiddles[7].re + t4 * self.twiddles[11].re + t6 * self.twiddles[14].re + t8 * self.twiddles[10].re + t10 * self.twiddles[6].re + t12 * self.twiddles[2].re + t14 * self.twiddles[0].re + t16 * self.twiddles[4].re + t18 * self.twiddles[8].re + t20 * self.twiddles[12].re + t22 * self.twiddles[13].re + t24 * self.twiddles[9].re + t26 * self.twiddles[5].re + t28 * self.twiddles[1].re;
+        let t47 = t1 * self.twiddles[3].im + t3 * self.twiddles[7].im + t5 * self.twiddles[11].im - t7 * self.twiddles[14].im - t9 * self.twiddles[10].im - t11 * self.twiddles[6].im - t13 * self.twiddles[2].im + t15 * self.twiddles[0].im + t17 * self.twiddles[4].im + t19 * self.twiddles[8].im + t21 * self.twiddles[12].im - t23 * self.twiddles[13].im - t25 * self.twiddles[9].im - t27 * self.twiddles[5].im - t29 * self.twiddles[1].im;
+        let t48 = Complex { re: -t47.im, im: t47.re };
+        let t49 = t46 + t48;
+        let t50 = t46 - t48;
+        let t51 = x0 + t0 * self.twiddles[4].re + t2 * self.twiddles[9].re + t4 * self.twiddles[14].re + t6 * self.twiddles[10].re + t8 * self.twiddles[5].re + t10 * self.twiddles[0].re + t12 * self.twiddles[3].re + t14 * self.twiddles[8].re + t16 * self.twiddles[13].re + t18 * self.twiddles[11].re + t20 * self.twiddles[6].re + t22 * self.twiddles[1].re + t24 * self.twiddles[2].re + t26 * self.twiddles[7].re + t28 * self.twiddles[12].re;
+        let t52 = t1 * self.twiddles[4].im + t3 * self.twiddles[9].im + t5 * self.twiddles[14].im - t7 * self.twiddles[10].im - t9 * self.twiddles[5].im - t11 * self.twiddles[0].im + t13 * self.twiddles[3].im + t15 * self.twiddles[8].im + t17 * self.twiddles[13].im - t19 * self.twiddles[11].im - t21 * self.twiddles[6].im - t23 * self.twiddles[1].im + t25 * self.twiddles[2].im + t27 * self.twiddles[7].im + t29 * self.twiddles[12].im;
+        let t53 = Complex { re: -t52.im, im: t52.re };
+        let t54 = t51 + t53;
+        let t55 = t51 - t53;
+        let t56 = x0 + t0 * self.twiddles[5].re + t2 * self.twiddles[11].re + t4 * self.twiddles[12].re + t6 * self.twiddles[6].re + t8 * self.twiddles[0].re + t10 * self.twiddles[4].re + t12 * self.twiddles[10].re + t14 * self.twiddles[13].re + t16 * self.twiddles[7].re + t18 * self.twiddles[1].re + t20 * self.twiddles[3].re + t22 * self.twiddles[9].re + t24 * self.twiddles[14].re + t26 * self.twiddles[8].re + t28 * self.twiddles[2].re;
+        let t57 = t1 * self.twiddles[5].im + t3 * self.twiddles[11].im - t5 * self.twiddles[12].im - t7 * self.twiddles[6].im - t9 * self.twiddles[0].im + t11 * self.twiddles[4].im + t13 * self.twiddles[10].im - t15 * self.twiddles[13].im - t17 * self.twiddles[7].im - t19 * self.twiddles[1].im + t21 * self.twiddles[3].im + t23 * self.twiddles[9].im - t25 * self.twiddles[14].im - t27 * self.twiddles[8].im - t29 * self.twiddles[2].im;
+        let t58 = Complex { re: -t57.im, im: t57.re };
+        let t59 = t56 + t58;
+        let t60 = t56 - t58;
+        let t61 = x0 + t0 * self.twiddles[6].re + t2 * self.twiddles[13].re + t4 * self.twiddles[9].re + t6 * self.twiddles[2].re + t8 * self.twiddles[3].re + t10 * self.twiddles[10].re + t12 * self.twiddles[12].re + t14 * self.twiddles[5].re + t16 * self.twiddles[0].re + t18 * self.twiddles[7].re + t20 * self.twiddles[14].re + t22 * self.twiddles[8].re + t24 * self.twiddles[1].re + t26 * self.twiddles[4].re + t28 * self.twiddles[11].re;
+        let t62 = t1 * self.twiddles[6].im + t3 * self.twiddles[13].im - t5 * self.twiddles[9].im - t7 * self.twiddles[2].im + t9 * self.twiddles[3].im + t11 * self.twiddles[10].im - t13 * self.twiddles[12].im - t15 * self.twiddles[5].im + t17 * self.twiddles[0].im + t19 * self.twiddles[7].im + t21 * self.twiddles[14].im - t23 * self.twiddles[8].im - t25 * self.twiddles[1].im + t27 * self.twiddles[4].im + t29 * self.twiddles[11].im;
+        let t63 = Complex { re: -t62.im, im: t62.re };
+        let t64 = t61 + t63;
+        let t65 = t61 - t63;
+        let t66 = x0 + t0 * self.twiddles[7].re + t2 * self.twiddles[14].re + t4 * self.twiddles[6].re + t6 * self.twiddles[0].re + t8 * self.twiddles[8].re + t10 * self.twiddles[13].re + t12 * self.twiddles[5].re + t14 * self.twiddles[1].re + t16 * self.twiddles[9].re + t18 * self.twiddles[12].re + t20 * self.twiddles[4].re + t22 * self.twiddles[2].re + t24 * self.twiddles[10].re + t26 * self.twiddles[11].re + t28 * self.twiddles[3].re;
+        let t67 = t1 * self.twiddles[7].im - t3 * self.twiddles[14].im - t5 * self.twiddles[6].im + t7 * self.twiddles[0].im + t9 * self.twiddles[8].im - t11 * self.twiddles[13].im - t13 * self.twiddles[5].im + t15 * self.twiddles[1].im + t17 * self.twiddles[9].im - t19 * self.twiddles[12].im - t21 * self.twiddles[4].im + t23 * self.twiddles[2].im + t25 * self.twiddles[10].im - t27 * self.twiddles[11].im - t29 * self.twiddles[3].im;
+        let t68 = Complex { re: -t67.im, im: t67.re };
+        let t69 = t66 + t68;
+        let t70 = t66 - t68;
+        let t71 = x0 + t0 * self.twiddles[8].re + t2 * self.twiddles[12].re + t4 * self.twiddles[3].re + t6 * self.twiddles[4].re + t8 * self.twiddles[13].re + t10 * self.twiddles[7].re + t12 * self.twiddles[0].re + t14 * self.twiddles[9].re + t16 * self.twiddles[11].re + t18 * self.twiddles[2].re + t20 * self.twiddles[5].re + t22 * self.twiddles[14].re + t24 * self.twiddles[6].re + t26 * self.twiddles[1].re + t28 * self.twiddles[10].re;
+        let t72 = t1 * self.twiddles[8].im - t3 * self.twiddles[12].im - t5 * self.twiddles[3].im + t7 * self.twiddles[4].im + t9 * self.twiddles[13].im - t11 * self.twiddles[7].im + t13 * self.twiddles[0].im + t15 * self.twiddles[9].im - t17 * self.twiddles[11].im - t19 * self.twiddles[2].im + t21 * self.twiddles[5].im + t23 * self.twiddles[14].im - t25 * self.twiddles[6].im + t27 * self.twiddles[1].im + t29 * self.twiddles[10].im;
+        let t73 = Complex { re: -t72.im, im: t72.re };
+        let t74 = t71 + t73;
+        let t75 = t71 - t73;
+        let t76 = x0 + t0 * self.twiddles[9].re + t2 * self.twiddles[10].re + t4 * self.twiddles[0].re + t6 * self.twiddles[8].re + t8 * self.twiddles[11].re + t10 * self.twiddles[1].re + t12 * self.twiddles[7].re + t14 * self.twiddles[12].re + t16 * self.twiddles[2].re + t18 * self.twiddles[6].re + t20 * self.twiddles[13].re + t22 * self.twiddles[3].re + t24 * self.twiddles[5].re + t26 * self.twiddles[14].re + t28 * self.twiddles[4].re;
+        let t77 = t1 * self.twiddles[9].im - t3 * self.twiddles[10].im - t5 * self.twiddles[0].im + t7 * self.twiddles[8].im - t9 * self.twiddles[11].im - t11 * self.twiddles[1].im + t13 * self.twiddles[7].im - t15 * self.twiddles[12].im - t17 * self.twiddles[2].im + t19 * self.twiddles[6].im - t21 * self.twiddles[13].im - t23 * self.twiddles[3].im + t25 * self.twiddles[5].im - t27 * self.twiddles[14].im - t29 * self.twiddles[4].im;
+        let t78 = Complex { re: -t77.im, im: t77.re };
+        let t79 = t76 + t78;
+        let t80 = t76 - t78;
+        let t81 = x0 + t0 * self.twiddles[10].re + t2 * self.twiddles[8].re + t4 * self.twiddles[1].re + t6 * self.twiddles[12].re + t8 * self.twiddles[6].re + t10 * self.twiddles[3].re + t12 * self.twiddles[14].re + t14 * self.twiddles[4].re + t16 * self.twiddles[5].re + t18 * self.twiddles[13].re + t20 * self.twiddles[2].re + t22 * self.twiddles[7].re + t24 * self.twiddles[11].re + t26 * self.twiddles[0].re + t28 * self.twiddles[9].re;
+        let t82 = t1 * self.twiddles[10].im - t3 * self.twiddles[8].im + t5 * self.twiddles[1].im + t7 * self.twiddles[12].im - t9 * self.twiddles[6].im + t11 * self.twiddles[3].im + t13 * self.twiddles[14].im - t15 * self.twiddles[4].im + t17 * self.twiddles[5].im - t19 * self.twiddles[13].im - t21 * self.twiddles[2].im + t23 * self.twiddles[7].im - t25 * self.twiddles[11].im - t27 * self.twiddles[0].im + t29 * self.twiddles[9].im;
+        let t83 = Complex { re: -t82.im, im: t82.re };
+        let t84 = t81 + t83;
+        let t85 = t81 - t83;
+        let t86 = x0 + t0 * self.twiddles[11].re + t2 * self.twiddles[6].re + t4 * self.twiddles[4].re + t6 * self.twiddles[13].re + t8 * self.twiddles[1].re + t10 * self.twiddles[9].re + t12 * self.twiddles[8].re + t14 * self.twiddles[2].re + t16 * self.twiddles[14].re + t18 * self.twiddles[3].re + t20 * self.twiddles[7].re + t22 * self.twiddles[10].re + t24 * self.twiddles[0].re + t26 * self.twiddles[12].re + t28 * self.twiddles[5].re;
+        let t87 = t1 * self.twiddles[11].im - t3 * self.twiddles[6].im + t5 * self.twiddles[4].im - t7 * self.twiddles[13].im - t9 * self.twiddles[1].im + t11 * self.twiddles[9].im - t13 * self.twiddles[8].im + t15 * self.twiddles[2].im + t17 * self.twiddles[14].im - t19 * self.twiddles[3].im + t21 * self.twiddles[7].im - t23 * self.twiddles[10].im + t25 * self.twiddles[0].im + t27 * self.twiddles[12].im - t29 * self.twiddles[5].im;
+        let t88 = Complex { re: -t87.im, im: t87.re };
+        let t89 = t86 + t88;
+        let t90 = t86 - t88;
+        let t91 = x0 + t0 * self.twiddles[12].re + t2 * self.twiddles[4].re + t4 * self.twiddles[7].re + t6 * self.twiddles[9].re + t8 * self.twiddles[2].re + t10 * self.twiddles[14].re + t12 * self.twiddles[1].re + t14 * self.twiddles[10].re + t16 * self.twiddles[6].re + t18 * self.twiddles[5].re + t20 * self.twiddles[11].re + t22 * self.twiddles[0].re + t24 * self.twiddles[13].re + t26 * self.twiddles[3].re + t28 * self.twiddles[8].re;
+        let t92 = t1 * self.twiddles[12].im - t3 * self.twiddles[4].im + t5 * self.twiddles[7].im - t7 * self.twiddles[9].im + t9 * self.twiddles[2].im - t11 * self.twiddles[14].im - t13 * self.twiddles[1].im + t15 * self.twiddles[10].im - t17 * self.twiddles[6].im + t19 * self.twiddles[5].im - t21 * self.twiddles[11].im + t23 * self.twiddles[0].im + t25 * self.twiddles[13].im - t27 * self.twiddles[3].im + t29 * self.twiddles[8].im;
+        let t93 = Complex { re: -t92.im, im: t92.re };
+        let t94 = t91 + t93;
+        let t95 = t91 - t93;
+        let t96 = x0 + t0 * self.twiddles[13].re + t2 * self.twiddles[2].re + t4 * self.twiddles[10].re + t6 * self.twiddles[5].re + t8 * self.twiddles[7].re + t10 * self.twiddles[8].re + t12 * self.twiddles[4].re + t14 * self.twiddles[11].re + t16 * self.twiddles[1].re + t18 * self.twiddles[14].re + t20 * self.twiddles[0].re + t22 * self.twiddles[12].re + t24 * self.twiddles[3].re + t26 * self.twiddles[9].re + t28 * self.twiddles[6].re;
+        let t97 = t1 * self.twiddles[13].im - t3 * self.twiddles[2].im + t5 * self.twiddles[10].im - t7 * self.twiddles[5].im + t9 * self.twiddles[7].im - t11 * self.twiddles[8].im + t13 * self.twiddles[4].im - t15 * self.twiddles[11].im + t17 * self.twiddles[1].im - t19 * self.twiddles[14].im - t21 * self.twiddles[0].im + t23 * self.twiddles[12].im - t25 * self.twiddles[3].im + t27 * self.twiddles[9].im - t29 * self.twiddles[6].im;
+        let t98 = Complex { re: -t97.im, im: t97.re };
+        let t99 = t96 + t98;
+        let t100 = t96 - t98;
+        let t101 = x0 + t0 * self.twiddles[14].re + t2 * self.twiddles[0].re + t4 * self.twiddles[13].re + t6 * self.twiddles[1].re + t8 * self.twiddles[12].re + t10 * self.twiddles[2].re + t12 * self.twiddles[11].re + t14 * self.twiddles[3].re + t16 * self.twiddles[10].re + t18 * self.twiddles[4].re + t20 * self.twiddles[9].re + t22 * self.twiddles[5].re + t24 * self.twiddles[8].re + t26 * self.twiddles[6].re + t28 * self.twiddles[7].re;
+        let t102 = t1 * self.twiddles[14].im - t3 * self.twiddles[0].im + t5 * self.twiddles[13].im - t7 * self.twiddles[1].im + t9 * self.twiddles[12].im - t11 * self.twiddles[2].im + t13 * self.twiddles[11].im - t15 * self.twiddles[3].im + t17 * self.twiddles[10].im - t19 * self.twiddles[4].im + t21 * self.twiddles[9].im - t23 * self.twiddles[5].im + t25 * self.twiddles[8].im - t27 * self.twiddles[6].im + t29 * self.twiddles[7].im;
+        let t103 = Complex { re: -t102.im, im: t102.re };
+        let t104 = t101 + t103;
+        let t105 = t101 - t103;
+        *buffer.get_unchecked_mut(0) = t30;
+        *buffer.get_unchecked_mut(1) = t34;
+        *buffer.get_unchecked_mut(2) = t39;
+        *buffer.get_unchecked_mut(3) = t44;
+        *buffer.get_unchecked_mut(4) = t49;
+        *buffer.get_unchecked_mut(5) = t54;
+        *buffer.get_unchecked_mut(6) = t59;
+        *buffer.get_unchecked_mut(7) = t64;
+        *buffer.get_unchecked_mut(8) = t69;
+        *buffer.get_unchecked_mut(9) = t74;
+        *buffer.get_unchecked_mut(10) = t79;
+        *buffer.get_unchecked_mut(11) = t84;
+        *buffer.get_unchecked_mut(12) = t89;
+        *buffer.get_unchecked_mut(13) = t94;
+        *buffer.get_unchecked_mut(14) = t99;
+        *buffer.get_unchecked_mut(15) = t104;
+        *buffer.get_unchecked_mut(16) = t105;
+        *buffer.get_unchecked_mut(17) = t100;
+        *buffer.get_unchecked_mut(18) = t95;
+        *buffer.get_unchecked_mut(19) = t90;
+        *buffer.get_unchecked_mut(20) = t85;
+        *buffer.get_unchecked_mut(21) = t80;
+        *buffer.get_unchecked_mut(22) = t75;
+        *buffer.get_unchecked_mut(23) = t70;
+        *buffer.get_unchecked_mut(24) = t65;
+        *buffer.get_unchecked_mut(25) = t60;
+        *buffer.get_unchecked_mut(26) = t55;
+        *buffer.get_unchecked_mut(27) = t50;
+        *buffer.get_unchecked_mut(28) = t45;
+        *buffer.get_unchecked_mut(29) = t40;
+        *buffer.get_unchecked_mut(30) = t35;
+    }
+    #[inline(always)]
+    unsafe fn process_multi_inplace(&self, buffer: &mut [Complex<T>]) {
+        for chunk in buffer.chunks_mut(self.len()) {
+            self.process_inplace(chunk);
+        }
+    }
+}
+boilerplate_fft_butterfly!(Butterfly31, 31);
@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 use num_complex::Complex;
 use num_traits::Zero;
 
@@ -90,17 +92,19 @@ impl<T> IsInverse for DFT<T> {
 #[cfg(test)]
 mod unit_tests {
     use super::*;
-    use std::f32;
+    use core::f32;
+    use alloc::vec::Vec;
     use test_utils::{random_signal, compare_vectors};
     use num_complex::Complex;
     use num_traits::Zero;
+    use FftError;
 
     fn dft(signal: &[Complex<f32>], spectrum: &mut [Complex<f32>]) {
         for (k, spec_bin) in spectrum.iter_mut().enumerate() {
             let mut sum = Zero::zero();
             for (i, &x) in signal.iter().enumerate() {
                 let angle = -1f32 * (i * k) as f32 * 2f32 * f32::consts::PI / signal.len() as f32;
-                let twiddle = Complex::from_polar(&1f32, &angle);
+                let twiddle = Complex::new(angle.cos(), angle.sin());
 
                 sum = sum + twiddle * x;
             }
@@ -108,6 +112,54 @@ mod unit_tests {
         }
     }
 
+    #[test]
+    fn test_try_process() {
+        let dft_instance = DFT::<f32>::new(10, false);
+
+        let mut input = vec![Zero::zero(); 10];
+        let mut output = vec![Zero::zero(); 10];
+        assert_eq!(dft_instance.try_process(&mut input, &mut output), Ok(()));
+
+        let mut short_input = vec![Zero::zero(); 9];
+        assert_eq!(dft_instance.try_process(&mut short_input, &mut output), Err(FftError::InputLength { expected: 10, actual: 9 }));
+        let mut long_output = vec![Zero::zero(); 11];
+        assert_eq!(dft_instance.try_process(&mut input, &mut long_output), Err(FftError::OutputLength { expected: 10, actual: 11 }));
+
+        let mut multi_input = vec![Zero::zero(); 30];
+        let mut multi_output = vec![Zero::zero(); 30];
+        assert_eq!(dft_instance.try_process_multi(&mut multi_input, &mut multi_output), Ok(()));
+        assert_eq!(dft_instance.try_process_multi(&mut multi_input[..25], &mut multi_output[..25]),
+            Err(FftError::NotMultiple { fft_len: 10, actual: 25 }));
+        assert_eq!(dft_instance.try_process_multi(&mut multi_input[..20], &mut multi_output),
+            Err(FftError::MismatchedBuffers { input_len: 20, output_len: 30 }));
+    }
+
+    #[test]
+    fn test_try_process_zero_length() {
+        let dft_instance = DFT::<f32>::new(0, false);
+
+        let mut input = vec![Zero::zero(); 3];
+        let mut output = vec![Zero::zero(); 3];
+        assert_eq!(dft_instance.try_process(&mut input[..0], &mut output[..0]), Ok(()));
+        assert_eq!(dft_instance.try_process_multi(&mut input[..0], &mut output[..0]), Ok(()));
+
+        // no buffer length other than zero is a multiple of zero
+        assert_eq!(dft_instance.try_process_multi(&mut input, &mut output),
+            Err(FftError::NotMultiple { fft_len: 0, actual: 3 }));
+        assert_eq!(dft_instance.try_process_multi(&mut input[..0], &mut output),
+            Err(FftError::MismatchedBuffers { input_len: 0, output_len: 3 }));
+    }
+
+    #[test]
+    #[should_panic(expected = "Input is the wrong length. Expected 10, got 9")]
+    fn test_process_wrong_length() {
+        let dft_instance = DFT::new(10, false);
+
+        let mut input: Vec<Complex<f32>> = vec![Zero::zero(); 9];
+        let mut output = vec![Zero::zero(); 10];
+        dft_instance.process(&mut input, &mut output);
+    }
+
     #[test]
     fn test_matches_dft() {
         let n = 4;
@@ -0,0 +1,401 @@
+use core::mem;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use alloc::boxed::Box;
+
+use num_complex::Complex;
+
+use common::{FFTnum, verify_length, verify_length_divisible};
+
+use algorithm::Radix4;
+use algorithm::butterflies::*;
+use algorithm::radix_n::radix_n_twiddles;
+use plan::{FFTplanner, PlannedAlgorithm, radix_n_radices};
+use math_utils;
+use ::{Length, IsInverse, FFT};
+
+/// The largest copy of the buffer that [`FixedFFT::process_inplace`](struct.FixedFFT.html#method.process_inplace) makes
+/// on the stack. Anything larger is allocated on the heap instead
+const MAX_STACK_SCRATCH_BYTES: usize = 4096;
+
+/// FFT of a size known at compile time, which processes arrays instead of slices
+///
+/// The algorithm is chosen from `N` when the instance is created, the same way the
+/// [`FFTplanner`](../struct.FFTplanner.html) would choose it. When that's a butterfly, [`Radix4`](struct.Radix4.html),
+/// or [`RadixN`](struct.RadixN.html), the algorithm is stored by value instead of behind an `Arc`, so calling it
+/// doesn't need a virtual call, and RadixN's butterflies are called without a virtual call for every stage. Other
+/// algorithms are stored as the planner returns them, and so are all algorithms for types the planner computes in f32,
+/// like `half::f16`. Since the arrays passed to [`process`](#method.process) always have the right size, the butterflies
+/// stored by value are called without length checks.
+///
+/// This type is only available with the `const-generics` feature, which requires rustc 1.51 or newer.
+///
+/// ~~~
+/// // Computes a forward FFT of size 256
+/// use rustfft::algorithm::FixedFFT;
+/// use rustfft::num_complex::Complex;
+/// use rustfft::num_traits::Zero;
+///
+/// let mut input:  [Complex<f32>; 256] = [Zero::zero(); 256];
+/// let mut output: [Complex<f32>; 256] = [Zero::zero(); 256];
+///
+/// let fft = FixedFFT::<f32, 256>::new(false);
+/// fft.process(&mut input, &mut output);
+/// ~~~
+pub struct FixedFFT<T, const N: usize> {
+    algorithm: FixedAlgorithm<T>,
+    inverse: bool,
+}
+
+enum FixedAlgorithm<T> {
+    // sizes 0 and 1, whose FFT is a copy
+    Copy,
+
+    Butterfly(FixedButterfly<T>),
+    Radix4(Radix4<T>),
+
+    // the RadixN algorithm, with the twiddle factors for every stage after the first stored one stage after another
+    RadixN { butterflies: Box<[FixedButterfly<T>]>, twiddles: Box<[Complex<T>]> },
+
+    // every other algorithm the planner can choose
+    Planned(Arc<FFT<T>>),
+}
+
+impl<T: FFTnum, const N: usize> FixedFFT<T, N> {
+    /// Creates a FFT instance which will process arrays of size `N`
+    pub fn new(inverse: bool) -> Self {
+        Self::with_planner(&mut FFTplanner::new(inverse))
+    }
+
+    /// Creates a FFT instance which will process arrays of size `N`, using the algorithm `planner` would choose
+    ///
+    /// This uses the planner's cost model and parallelism, and its direction: The instance is an inverse FFT if the
+    /// planner plans inverse FFTs. Algorithms stored as the planner returns them share the planner's inner FFTs.
+    pub fn with_planner(planner: &mut FFTplanner<T>) -> Self {
+        let inverse = planner.is_inverse();
+        let algorithm = if N <= 1 {
+            FixedAlgorithm::Copy
+        } else if let Some(fft) = planner.plan_widened(N) {
+            // computing in T directly would round every intermediate value to T's precision
+            FixedAlgorithm::Planned(fft)
+        } else {
+            match planner.planned_algorithm(N) {
+                PlannedAlgorithm::Butterfly => FixedAlgorithm::Butterfly(FixedButterfly::new(N, inverse)),
+                PlannedAlgorithm::Radix4 => FixedAlgorithm::Radix4(Radix4::new(N, inverse)),
+                PlannedAlgorithm::RadixN => {
+                    let radices = radix_n_radices(&math_utils::prime_factors(N)).unwrap();
+                    let butterflies: Vec<FixedButterfly<T>> = radices.iter().map(|&radix| FixedButterfly::new(radix, inverse)).collect();
+                    FixedAlgorithm::RadixN {
+                        butterflies: butterflies.into_boxed_slice(),
+                        twiddles: radix_n_twiddles(&radices, inverse).into_boxed_slice(),
+                    }
+                }
+                _ => FixedAlgorithm::Planned(planner.plan_fft(N)),
+            }
+        };
+
+        FixedFFT {
+            algorithm,
+            inverse,
+        }
+    }
+
+    /// Computes a FFT of `input` and places the result in `output`
+    ///
+    /// Like [`FFT::process`](../trait.FFT.html#tymethod.process), the output is not normalized, and the contents of
+    /// `input` should be considered garbage after calling
+    pub fn process(&self, input: &mut [Complex<T>; N], output: &mut [Complex<T>; N]) {
+        if let FixedAlgorithm::Butterfly(ref butterfly) = self.algorithm {
+            *output = *input;
+            unsafe { butterfly.process_inplace(output) };
+        } else {
+            unsafe { self.perform_fft(input, output) };
+        }
+    }
+
+    /// Computes a FFT of `buffer` in place
+    ///
+    /// Unless `N` is a butterfly size, this needs a copy of `buffer` as scratch space. If the copy is at most 4 KiB,
+    /// it's made on the stack, so small FFTs don't allocate. Larger copies are
+    /// allocated on the heap on every call, so that a large `N` can't overflow the stack. To avoid that allocation, use
+    /// [`process`](#method.process) with an output array of your own.
+    pub fn process_inplace(&self, buffer: &mut [Complex<T>; N]) {
+        if let FixedAlgorithm::Butterfly(ref butterfly) = self.algorithm {
+            unsafe { butterfly.process_inplace(buffer) };
+        } else if N * mem::size_of::<Complex<T>>() <= MAX_STACK_SCRATCH_BYTES {
+            self.process_inplace_stack(buffer);
+        } else {
+            let mut scratch = buffer.to_vec();
+            unsafe { self.perform_fft(&mut scratch, buffer) };
+        }
+    }
+
+    // kept out of process_inplace, so that when N is too large for a stack copy, process_inplace's stack frame doesn't
+    // reserve space for one anyway
+    #[inline(never)]
+    fn process_inplace_stack(&self, buffer: &mut [Complex<T>; N]) {
+        let mut input = *buffer;
+        unsafe { self.perform_fft(&mut input, buffer) };
+    }
+
+    // `input` and `output` must both have length N
+    unsafe fn perform_fft(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) {
+        match self.algorithm {
+            FixedAlgorithm::Copy => output.copy_from_slice(input),
+            FixedAlgorithm::Butterfly(ref butterfly) => {
+                output.copy_from_slice(input);
+                butterfly.process_inplace(output);
+            }
+            FixedAlgorithm::Radix4(ref fft) => fft.process(input, output),
+            FixedAlgorithm::RadixN { ref butterflies, ref twiddles } => {
+                // reorder the input and apply the first stage's butterflies
+                prepare_fixed(input, output, 1, butterflies);
+
+                let mut sub_len = butterflies[0].len();
+                let mut stage_twiddles: &[Complex<T>] = twiddles;
+                for butterfly in &butterflies[1..] {
+                    let radix = butterfly.len();
+                    assert!(stage_twiddles.len() >= sub_len * (radix - 1));
+                    butterfly.process_stage_inplace(output, stage_twiddles, sub_len);
+
+                    //skip past all the twiddle factors used in this stage
+                    stage_twiddles = &stage_twiddles[sub_len * (radix - 1)..];
+                    sub_len *= radix;
+                }
+            }
+            FixedAlgorithm::Planned(ref fft) => fft.process(input, output),
+        }
+    }
+}
+
+/// Same as the RadixN algorithm's digit-reversal pass, for butterflies stored by value
+fn prepare_fixed<T: FFTnum>(signal: &[Complex<T>], spectrum: &mut [Complex<T>], stride: usize, butterflies: &[FixedButterfly<T>]) {
+    let (last_butterfly, inner_butterflies) = butterflies.split_last().unwrap();
+    let radix = last_butterfly.len();
+
+    if inner_butterflies.is_empty() {
+        assert!(spectrum.len() == radix && signal.len() > (radix - 1) * stride);
+        unsafe {
+            for i in 0..radix {
+                *spectrum.get_unchecked_mut(i) = *signal.get_unchecked(i * stride);
+            }
+            last_butterfly.process_inplace(spectrum);
+        }
+    } else {
+        let sub_len = spectrum.len() / radix;
+        for (j, sub_spectrum) in spectrum.chunks_mut(sub_len).enumerate() {
+            prepare_fixed(&signal[j * stride..], sub_spectrum, stride * radix, inner_butterflies);
+        }
+    }
+}
+
+// every butterfly, stored by value. matching on the variant is much cheaper than a virtual call, and lets each
+// butterfly's process_inplace be inlined into process_stage_inplace
+macro_rules! fixed_butterflies {
+    ($($len:expr => $butterfly:ident $(<$t:ident>)*),*) => (
+        enum FixedButterfly<T> {
+            $($butterfly($butterfly $(<$t>)*),)*
+        }
+        impl<T: FFTnum> FixedButterfly<T> {
+            fn new(len: usize, inverse: bool) -> Self {
+                match len {
+                    $($len => FixedButterfly::$butterfly(<$butterfly $(<$t>)*>::new(inverse)),)*
+                    _ => panic!("Invalid butterfly size: {}", len),
+                }
+            }
+
+            #[inline]
+            fn len(&self) -> usize {
+                match *self {
+                    $(FixedButterfly::$butterfly(_) => $len,)*
+                }
+            }
+
+            #[inline]
+            unsafe fn process_inplace(&self, buffer: &mut [Complex<T>]) {
+                match *self {
+                    $(FixedButterfly::$butterfly(ref butterfly) => butterfly.process_inplace(buffer),)*
+                }
+            }
+
+            #[inline]
+            unsafe fn process_stage_inplace(&self, buffer: &mut [Complex<T>], twiddles: &[Complex<T>], stride: usize) {
+                match *self {
+                    $(FixedButterfly::$butterfly(ref butterfly) => butterfly.process_stage_inplace(buffer, twiddles, stride),)*
+                }
+            }
+        }
+    )
+}
+with_butterflies!(fixed_butterflies);
+
+impl<T: FFTnum, const N: usize> FFT<T> for FixedFFT<T, N> {
+    fn process(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) {
+        verify_length(input, output, N);
+
+        unsafe { self.perform_fft(input, output) };
+    }
+    fn process_multi(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) {
+        verify_length_divisible(input, output, N);
+        if N == 0 {
+            // both buffers are empty, and there are no chunks to split them into
+            return;
+        }
+
+        for (in_chunk, out_chunk) in input.chunks_mut(N).zip(output.chunks_mut(N)) {
+            unsafe { self.perform_fft(in_chunk, out_chunk) };
+        }
+    }
+}
+impl<T, const N: usize> Length for FixedFFT<T, N> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        N
+    }
+}
+impl<T, const N: usize> IsInverse for FixedFFT<T, N> {
+    #[inline(always)]
+    fn is_inverse(&self) -> bool {
+        self.inverse
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use num_traits::Zero;
+    use test_utils::{check_fft_algorithm, compare_vectors, random_signal};
+    use CostModel;
+
+    #[test]
+    fn test_fixed_fft() {
+        test_fixed_fft_with_length::<1>();
+        test_fixed_fft_with_length::<2>();
+        test_fixed_fft_with_length::<7>();
+        test_fixed_fft_with_length::<31>();
+        test_fixed_fft_with_length::<64>();
+        test_fixed_fft_with_length::<128>();
+        test_fixed_fft_with_length::<1024>();
+        test_fixed_fft_with_length::<441>();
+        test_fixed_fft_with_length::<1029>();
+        test_fixed_fft_with_length::<1225>();
+
+        // the planner doesn't use any of the algorithms stored by value for these
+        test_fixed_fft_with_length::<37>();
+        test_fixed_fft_with_length::<48>();
+        test_fixed_fft_with_length::<1000>();
+    }
+
+    #[test]
+    fn test_fixed_fft_algorithms() {
+        assert!(matches!(FixedFFT::<f32, 16>::new(false).algorithm, FixedAlgorithm::Butterfly(_)));
+        assert!(matches!(FixedFFT::<f32, 256>::new(false).algorithm, FixedAlgorithm::Radix4(_)));
+        assert!(matches!(FixedFFT::<f32, 441>::new(false).algorithm, FixedAlgorithm::Planned(_)));
+        assert!(matches!(FixedFFT::<f32, 37>::new(false).algorithm, FixedAlgorithm::Planned(_)));
+        assert!(matches!(FixedFFT::<f32, 48>::new(false).algorithm, FixedAlgorithm::Planned(_)));
+        assert!(matches!(FixedFFT::<f32, 1000>::new(false).algorithm, FixedAlgorithm::Planned(_)));
+    }
+
+    #[test]
+    fn test_fixed_fft_empty() {
+        // a FFT of size 0 has no chunks to split a batch into, but its only valid batch is empty
+        let fft = FixedFFT::<f32, 0>::new(false);
+        fft.process_multi(&mut [], &mut []);
+        fft.process(&mut [], &mut []);
+    }
+
+    #[test]
+    fn test_fixed_fft_arrays() {
+        let fft = FixedFFT::<f32, 96>::new(false);
+        let expected_fft = FFTplanner::new(false).plan_fft(96);
+
+        let signal = random_signal(96);
+        let mut expected = vec![Zero::zero(); 96];
+        expected_fft.process(&mut signal.clone(), &mut expected);
+
+        let mut input = [Zero::zero(); 96];
+        input.copy_from_slice(&signal);
+        let mut output = [Zero::zero(); 96];
+        fft.process(&mut input, &mut output);
+        assert!(compare_vectors(&output, &expected));
+
+        let mut buffer = [Zero::zero(); 96];
+        buffer.copy_from_slice(&signal);
+        fft.process_inplace(&mut buffer);
+        assert!(compare_vectors(&buffer, &expected));
+
+        // this buffer is too large to copy onto the stack, so the scratch space is allocated instead
+        let fft = FixedFFT::<f32, 1000>::new(true);
+        assert!(1000 * mem::size_of::<Complex<f32>>() > MAX_STACK_SCRATCH_BYTES);
+
+        let signal = random_signal(1000);
+        let mut expected = vec![Zero::zero(); 1000];
+        FFTplanner::new(true).plan_fft(1000).process(&mut signal.clone(), &mut expected);
+
+        let mut buffer = [Zero::zero(); 1000];
+        buffer.copy_from_slice(&signal);
+        fft.process_inplace(&mut buffer);
+        assert!(compare_vectors(&buffer, &expected));
+
+        // the empty FFT shouldn't do anything
+        let fft = FixedFFT::<f32, 0>::new(false);
+        fft.process_inplace(&mut []);
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn test_fixed_fft_half() {
+        use half::f16;
+
+        // every size should be computed in f32, like the planner does, even ones stored by value for f32
+        let fft = FixedFFT::<f16, 16>::new(false);
+        assert!(matches!(fft.algorithm, FixedAlgorithm::Planned(_)));
+
+        let signal: Vec<Complex<f16>> = random_signal(16).iter()
+            .map(|value| Complex::new(f16::from_f32(value.re), f16::from_f32(value.im)))
+            .collect();
+        let mut expected = vec![Zero::zero(); 16];
+        FFTplanner::new(false).plan_fft(16).process(&mut signal.clone(), &mut expected);
+
+        let mut input = [Zero::zero(); 16];
+        input.copy_from_slice(&signal);
+        let mut output = [Zero::zero(); 16];
+        fft.process(&mut input, &mut output);
+        assert_eq!(&output[..], &expected[..]);
+    }
+
+    #[test]
+    fn test_fixed_fft_with_planner() {
+        // the default cost model never chooses RadixN, so make it cheap enough to be chosen
+        struct PreferRadixN;
+        impl CostModel for PreferRadixN {
+            fn radix_n(&self, len: usize, _num_stages: usize) -> f64 {
+                0.1 * len as f64
+            }
+        }
+
+        for &inverse in &[false, true] {
+            let mut planner = FFTplanner::new(inverse);
+            planner.set_cost_model(Arc::new(PreferRadixN));
+
+            let fft = FixedFFT::<f32, 441>::with_planner(&mut planner);
+            assert!(matches!(fft.algorithm, FixedAlgorithm::RadixN { .. }));
+            check_fft_algorithm(&fft, 441, inverse);
+
+            let fft = FixedFFT::<f32, 1029>::with_planner(&mut planner);
+            assert!(matches!(fft.algorithm, FixedAlgorithm::RadixN { .. }));
+            check_fft_algorithm(&fft, 1029, inverse);
+
+            let fft = FixedFFT::<f32, 1225>::with_planner(&mut planner);
+            assert!(matches!(fft.algorithm, FixedAlgorithm::RadixN { .. }));
+            check_fft_algorithm(&fft, 1225, inverse);
+        }
+    }
+
+    fn test_fixed_fft_with_length<const N: usize>() {
+        for &inverse in &[false, true] {
+            let fft = FixedFFT::<f32, N>::new(inverse);
+            check_fft_algorithm(&fft, N, inverse);
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use core::cmp;
+use core::mem;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use alloc::boxed::Box;
+
+use num_complex::Complex;
+use num_traits::Zero;
+
+use common::{FFTnum, verify_length, verify_length_divisible, check_product_len};
+
+use ::{Length, IsInverse, FFT, FftError};
+use parallel::Parallelism;
+use twiddles;
+
+/// The number of bytes of column data to process at a time. Chosen to fit comfortably in a typical L2 cache, along
+/// with the inner FFT's own data
+const BLOCK_BYTES: usize = 1 << 17;
+
+/// The fewest columns to process at a time, so that every row we read from contains at least a full cache line
+const MIN_BLOCK_WIDTH: usize = 8;
+
+/// Implementation of the four-step FFT algorithm, for FFTs too large to fit in the CPU cache
+///
+/// Like [`MixedRadix`](struct.MixedRadix.html), this algorithm factors a size n FFT into n1 * n2, and computes it
+/// with inner FFTs of size n1 and n2. The difference is in how it moves data: MixedRadix makes six passes over the
+/// whole buffer, each of which misses the cache once the buffer is large enough. This algorithm instead copies a block
+/// of columns that fits in the cache into a scratch buffer, then computes that block's FFTs and applies its twiddle
+/// factors before writing it back, so that the first half of the algorithm only makes one pass over memory.
+///
+/// For FFTs that already fit in the cache, MixedRadix is faster. The planner chooses between them automatically. With
+/// the default cost model, it uses this algorithm for most sizes larger than 2^18, including every power of two from
+/// 2^19 up, since Radix4 and Stockham make several passes over the whole buffer, while this algorithm's inner FFTs
+/// each fit in the cache.
+///
+/// Each block of columns is copied into a scratch buffer with room for two blocks, about 256KiB unless the columns are
+/// very tall. Neither the input nor the output has room for it while the columns are being processed, so it's allocated
+/// on every call to `process`, and the default cost model includes that allocation in its estimate. `process_multi`
+/// only allocates it once for all of its FFTs.
+///
+/// ~~~
+/// // Computes a forward FFT of size 2^20, using the four-step algorithm with inner FFTs chosen by hand
+/// use rustfft::algorithm::FourStep;
+/// use rustfft::{FFT, FFTplanner};
+/// use rustfft::num_complex::Complex;
+/// use rustfft::num_traits::Zero;
+///
+/// let mut input:  Vec<Complex<f32>> = vec![Zero::zero(); 1 << 20];
+/// let mut output: Vec<Complex<f32>> = vec![Zero::zero(); 1 << 20];
+///
+/// let mut planner = FFTplanner::new(false);
+/// let inner_fft_n1 = planner.plan_fft(1024);
+/// let inner_fft_n2 = planner.plan_fft(1024);
+///
+/// let fft = FourStep::new(inner_fft_n1, inner_fft_n2);
+/// fft.process(&mut input, &mut output);
+/// ~~~
+pub struct FourStep<T> {
+    width: usize,
+    width_size_fft: Arc<FFT<T>>,
+
+    height: usize,
+    height_size_fft: Arc<FFT<T>>,
+
+    block_width: usize,
+
+    // twiddle(i) is computed as coarse_twiddles[i >> fine_bits] * fine_twiddles[i & fine_mask]. both tables are about
+    // sqrt(len) long, so unlike a single table of size len, they stay in the cache while we apply them
+    coarse_twiddles: Box<[Complex<T>]>,
+    fine_twiddles: Box<[Complex<T>]>,
+    fine_bits: u32,
+
+    inverse: bool,
+
+    parallelism: Parallelism,
+}
+
+impl<T: FFTnum> FourStep<T> {
+    /// Creates a FFT instance which will process inputs/outputs of size `width_fft.len() * height_fft.len()`
+    ///
+    /// Both FFTs must have the same direction
+    pub fn new(width_fft: Arc<FFT<T>>, height_fft: Arc<FFT<T>>) -> Self {
+        Self::try_new(width_fft, height_fft).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Same as [`new`](#method.new), except that invalid inputs return an [`FftError`](../enum.FftError.html) instead
+    /// of panicking
+    pub fn try_new(width_fft: Arc<FFT<T>>, height_fft: Arc<FFT<T>>) -> Result<Self, FftError> {
+        if width_fft.is_inverse() != height_fft.is_inverse() {
+            return Err(FftError::MismatchedDirection {
+                width_inverse: width_fft.is_inverse(),
+                height_inverse: height_fft.is_inverse(),
+            });
+        }
+
+        let inverse = width_fft.is_inverse();
+
+        let width = width_fft.len();
+        let height = height_fft.len();
+        if width == 0 || height == 0 {
+            return Err(FftError::ZeroLength);
+        }
+
+        let len = check_product_len(width, height)?;
+
+        // process as many columns at a time as will fit in the block, as long as each row we read is a full cache line
+        let block_len = BLOCK_BYTES / mem::size_of::<Complex<T>>();
+        let block_width = cmp::min(cmp::max(block_len / height, MIN_BLOCK_WIDTH), width);
+
+        let fine_bits = (64 - (len as u64).leading_zeros()) / 2;
+        let fine_len = 1 << fine_bits;
+        let coarse_len = (len + fine_len - 1) >> fine_bits;
+        let fine_twiddles: Vec<Complex<T>> = (0..fine_len).map(|i| twiddles::single_twiddle(i, len, inverse)).collect();
+        let coarse_twiddles: Vec<Complex<T>> = (0..coarse_len).map(|i| twiddles::single_twiddle(i << fine_bits, len, inverse)).collect();
+
+        Ok(FourStep {
+            width,
+            width_size_fft: width_fft,
+
+            height,
+            height_size_fft: height_fft,
+
+            block_width,
+
+            coarse_twiddles: coarse_twiddles.into_boxed_slice(),
+            fine_twiddles: fine_twiddles.into_boxed_slice(),
+            fine_bits,
+
+            inverse,
+
+            parallelism: Parallelism::default(),
+        })
+    }
+
+    /// Sets how this FFT instance splits its size-`width` FFTs and final transpose across threads
+    ///
+    /// Without the `rayon` feature, all work is done on the calling thread regardless of this setting
+    pub fn with_parallelism(mut self, parallelism: Parallelism) -> Self {
+        self.parallelism = parallelism;
+        self
+    }
+
+    fn perform_fft(&self, input: &mut [Complex<T>], output: &mut [Complex<T>], scratch: &mut [Complex<T>]) {
+        let (block_input, block_output) = scratch.split_at_mut(self.block_width * self.height);
+
+        // STEPS 1-3, one block of columns at a time: copy the columns into the scratch buffer, perform FFTs of size
+        // `height` on each column, then apply twiddle factors while copying each column into a row of `output`
+        let mut x_start = 0;
+        while x_start < self.width {
+            let block_width = cmp::min(self.block_width, self.width - x_start);
+            let block_input = &mut block_input[..block_width * self.height];
+            let block_output = &mut block_output[..block_width * self.height];
+
+            for (y, row) in input.chunks(self.width).enumerate() {
+                for (column, &element) in row[x_start..x_start + block_width].iter().enumerate() {
+                    block_input[column * self.height + y] = element;
+                }
+            }
+
+            self.height_size_fft.process_multi(block_input, block_output);
+
+            for (k, row) in output.chunks_mut(self.width).enumerate() {
+                for (column, element) in row[x_start..x_start + block_width].iter_mut().enumerate() {
+                    let x = x_start + column;
+                    *element = block_output[column * self.height + k] * self.twiddle(x * k);
+                }
+            }
+
+            x_start += block_width;
+        }
+
+        // STEP 4: perform FFTs of size `width`
+        self.parallelism.process_multi(&*self.width_size_fft, output, input);
+
+        // STEP 5: transpose
+        self.parallelism.transpose(input, output, self.width, self.height);
+    }
+
+    #[inline(always)]
+    fn twiddle(&self, index: usize) -> Complex<T> {
+        let fine_mask = (1 << self.fine_bits) - 1;
+        self.coarse_twiddles[index >> self.fine_bits] * self.fine_twiddles[index & fine_mask]
+    }
+
+    /// Allocates the scratch space needed by `perform_fft`: the input and output of the column FFTs for one block
+    fn make_scratch(&self) -> Vec<Complex<T>> {
+        vec![Zero::zero(); 2 * self.block_width * self.height]
+    }
+}
+impl<T: FFTnum> FFT<T> for FourStep<T> {
+    fn process(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) {
+        verify_length(input, output, self.len());
+
+        let mut scratch = self.make_scratch();
+        self.perform_fft(input, output, &mut scratch);
+    }
+    fn process_multi(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) {
+        verify_length_divisible(input, output, self.len());
+
+        let mut scratch = self.make_scratch();
+        for (in_chunk, out_chunk) in input.chunks_mut(self.len()).zip(output.chunks_mut(self.len())) {
+            self.perform_fft(in_chunk, out_chunk, &mut scratch);
+        }
+    }
+}
+impl<T> Length for FourStep<T> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.width * self.height
+    }
+}
+impl<T> IsInverse for FourStep<T> {
+    #[inline(always)]
+    fn is_inverse(&self) -> bool {
+        self.inverse
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use alloc::sync::Arc;
+    use test_utils::check_fft_algorithm;
+    use algorithm::DFT;
+
+    #[test]
+    fn test_four_step() {
+        for width in 1..7 {
+            for height in 1..7 {
+                // use a small block width, so that we test partial blocks
+                for &block_width in &[1, 2, 4] {
+                    test_four_step_with_lengths(width, height, block_width, false);
+                    test_four_step_with_lengths(width, height, block_width, true);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_four_step_block_width() {
+        let fft = FourStep::new(Arc::new(DFT::<f32>::new(4096, false)), Arc::new(DFT::new(64, false)));
+        assert_eq!(fft.block_width, 256);
+
+        // the block should always contain at least one cache line per row, but never more columns than there are
+        let fft = FourStep::new(Arc::new(DFT::<f64>::new(16, false)), Arc::new(DFT::new(1 << 14, false)));
+        assert_eq!(fft.block_width, MIN_BLOCK_WIDTH);
+        let fft = FourStep::new(Arc::new(DFT::<f64>::new(3, false)), Arc::new(DFT::new(5, false)));
+        assert_eq!(fft.block_width, 3);
+    }
+
+    fn test_four_step_with_lengths(width: usize, height: usize, block_width: usize, inverse: bool) {
+        let width_fft = Arc::new(DFT::new(width, inverse)) as Arc<FFT<f32>>;
+        let height_fft = Arc::new(DFT::new(height, inverse)) as Arc<FFT<f32>>;
+
+        let mut fft = FourStep::new(width_fft, height_fft);
+        fft.block_width = cmp::min(block_width, width);
+
+        check_fft_algorithm(&fft, width * height, inverse);
+    }
+}
@@ -1,15 +1,17 @@
-use std::sync::Arc;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use alloc::boxed::Box;
 
 use num_complex::Complex;
 use strength_reduce::StrengthReducedUsize;
-use transpose;
 
-use common::{FFTnum, verify_length, verify_length_divisible};
+use common::{FFTnum, verify_length, verify_length_divisible, check_product_len};
 
 use math_utils;
 use array_utils;
+use parallel::Parallelism;
 
-use ::{Length, IsInverse, FFT};
+use ::{Length, IsInverse, FFT, FftError};
 use algorithm::butterflies::FFTButterfly;
 
 /// Implementation of the [Good-Thomas Algorithm (AKA Prime Factor Algorithm)](https://en.wikipedia.org/wiki/Prime-factor_FFT_algorithm)
@@ -52,29 +54,42 @@ pub struct GoodThomasAlgorithm<T> {
 
     len: StrengthReducedUsize,
     inverse: bool,
+
+    parallelism: Parallelism,
 }
 
 impl<T: FFTnum> GoodThomasAlgorithm<T> {
     /// Creates a FFT instance which will process inputs/outputs of size `width_fft.len() * height_fft.len()`
     ///
-    /// GCD(width_fft.len(), height_fft.len()) must be equal to 1
+    /// GCD(width_fft.len(), height_fft.len()) must be equal to 1, and both FFTs must have the same direction
     pub fn new(width_fft: Arc<FFT<T>>, height_fft: Arc<FFT<T>>) -> Self {
-        assert_eq!(
-            width_fft.is_inverse(), height_fft.is_inverse(), 
-            "width_fft and height_fft must both be inverse, or neither. got width inverse={}, height inverse={}",
-            width_fft.is_inverse(), height_fft.is_inverse());
+        Self::try_new(width_fft, height_fft).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Same as [`new`](#method.new), except that invalid inputs return an [`FftError`](../enum.FftError.html) instead
+    /// of panicking
+    pub fn try_new(width_fft: Arc<FFT<T>>, height_fft: Arc<FFT<T>>) -> Result<Self, FftError> {
+        if width_fft.is_inverse() != height_fft.is_inverse() {
+            return Err(FftError::MismatchedDirection {
+                width_inverse: width_fft.is_inverse(),
+                height_inverse: height_fft.is_inverse(),
+            });
+        }
 
         let width = width_fft.len();
         let height = height_fft.len();
+        if width == 0 || height == 0 {
+            return Err(FftError::ZeroLength);
+        }
+        let len = check_product_len(width, height)?;
         let is_inverse = width_fft.is_inverse();
 
         // compute the nultiplicative inverse of width mod height and vice versa
         let (gcd, mut width_inverse, mut height_inverse) =
             math_utils::extended_euclidean_algorithm(width as i64, height as i64);
-        assert!(gcd == 1,
-                "Invalid input width and height to Good-Thomas Algorithm: ({},{}): Inputs must be coprime",
-                width,
-                height);
+        if gcd != 1 {
+            return Err(FftError::NotCoprime { width, height });
+        }
 
         // width_inverse or height_inverse might be negative, make it positive
         if width_inverse < 0 {
@@ -84,7 +99,7 @@ impl<T: FFTnum> GoodThomasAlgorithm<T> {
             height_inverse += width as i64;
         }
 
-        Self {
+        Ok(Self {
             width: width,
             width_size_fft: width_fft,
 
@@ -94,9 +109,19 @@ impl<T: FFTnum> GoodThomasAlgorithm<T> {
             input_x_stride: height_inverse as usize * height,
             input_y_stride: width_inverse as usize * width,
 
-            len: StrengthReducedUsize::new(width * height),
+            len: StrengthReducedUsize::new(len),
             inverse: is_inverse,
-        }
+
+            parallelism: Parallelism::default(),
+        })
+    }
+
+    /// Sets how this FFT instance splits its inner FFTs and transposes across threads
+    ///
+    /// Without the `rayon` feature, all work is done on the calling thread regardless of this setting
+    pub fn with_parallelism(mut self, parallelism: Parallelism) -> Self {
+        self.parallelism = parallelism;
+        self
     }
 
     fn perform_fft(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) {
@@ -110,13 +135,13 @@ impl<T: FFTnum> GoodThomasAlgorithm<T> {
         }
 
         // run FFTs of size `width`
-        self.width_size_fft.process_multi(output, input);
+        self.parallelism.process_multi(&*self.width_size_fft, output, input);
 
         // transpose
-        transpose::transpose(input, output, self.width, self.height);
+        self.parallelism.transpose(input, output, self.width, self.height);
 
         // run FFTs of size 'height'
-        self.height_size_fft.process_multi(output, input);
+        self.parallelism.process_multi(&*self.height_size_fft, output, input);
 
         // copy to the output, using our output redordering mapping
         for (x, row) in input.chunks(self.height).enumerate() {
@@ -138,9 +163,7 @@ impl<T: FFTnum> FFT<T> for GoodThomasAlgorithm<T> {
     fn process_multi(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) {
         verify_length_divisible(input, output, self.len());
 
-        for (in_chunk, out_chunk) in input.chunks_mut(self.len()).zip(output.chunks_mut(self.len())) {
-            self.perform_fft(in_chunk, out_chunk);
-        }
+        self.parallelism.for_each_chunk(input, output, self.len(), |in_chunk, out_chunk| self.perform_fft(in_chunk, out_chunk));
     }
 }
 impl<T> Length for GoodThomasAlgorithm<T> {
@@ -203,45 +226,29 @@ pub struct GoodThomasAlgorithmDoubleButterfly<T> {
 impl<T: FFTnum> GoodThomasAlgorithmDoubleButterfly<T> {
     /// Creates a FFT instance which will process inputs/outputs of size `width_fft.len() * height_fft.len()`
     ///
-    /// GCD(n1.len(), n2.len()) must be equal to 1
+    /// GCD(width_fft.len(), height_fft.len()) must be equal to 1, and both FFTs must have the same direction
     pub fn new(width_fft: Arc<FFTButterfly<T>>, height_fft: Arc<FFTButterfly<T>>) -> Self {
-        assert_eq!(
-            width_fft.is_inverse(), height_fft.is_inverse(), 
-            "n1_fft and height_fft must both be inverse, or neither. got width inverse={}, height inverse={}",
-            width_fft.is_inverse(), height_fft.is_inverse());
+        Self::try_new(width_fft, height_fft).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Same as [`new`](#method.new), except that invalid inputs return an [`FftError`](../enum.FftError.html) instead
+    /// of panicking
+    pub fn try_new(width_fft: Arc<FFTButterfly<T>>, height_fft: Arc<FFTButterfly<T>>) -> Result<Self, FftError> {
+        if width_fft.is_inverse() != height_fft.is_inverse() {
+            return Err(FftError::MismatchedDirection {
+                width_inverse: width_fft.is_inverse(),
+                height_inverse: height_fft.is_inverse(),
+            });
+        }
 
         let width = width_fft.len();
         let height = height_fft.len();
-        let len = width * height;
-
-        // compute the nultiplicative inverse of n1 mod height and vice versa
-        let (gcd, mut width_inverse, mut height_inverse) =
-            math_utils::extended_euclidean_algorithm(width as i64, height as i64);
-        assert!(gcd == 1,
-                "Invalid input n1 and height to Good-Thomas Algorithm: ({},{}): Inputs must be coprime",
-                width,
-                height);
-
-        // width_inverse or height_inverse might be negative, make it positive
-        if width_inverse < 0 {
-            width_inverse += height as i64;
-        }
-        if height_inverse < 0 {
-            height_inverse += width as i64;
+        if width == 0 || height == 0 {
+            return Err(FftError::ZeroLength);
         }
+        let input_output_map = good_thomas_map(width, height)?;
 
-        // NOTE: we are precomputing the input and output reordering indexes, because benchmarking shows that it's 10-20% faster
-        // If we wanted to optimize for memory use or setup time instead of multiple-FFT speed, we could compute these on the fly in the perform_fft() method
-        let input_iter = (0..len)
-                .map(|i| (i % width, i / width))
-                .map(|(x, y)| (x * height + y * width) % len);
-        let output_iter = (0..len)
-                .map(|i| (i % height, i / height))
-                .map(|(y, x)| (x * height * height_inverse as usize + y * width * width_inverse as usize) % len);
-
-        let input_output_map: Vec<usize> = input_iter.chain(output_iter).collect();
-
-        GoodThomasAlgorithmDoubleButterfly {
+        Ok(GoodThomasAlgorithmDoubleButterfly {
             inverse: width_fft.is_inverse(),
 
             width: width,
@@ -251,7 +258,7 @@ impl<T: FFTnum> GoodThomasAlgorithmDoubleButterfly<T> {
             height_size_fft: height_fft,
             
             input_output_map: input_output_map.into_boxed_slice(),
-        }
+        })
     }
 
     unsafe fn perform_fft(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) {
@@ -279,6 +286,39 @@ impl<T: FFTnum> GoodThomasAlgorithmDoubleButterfly<T> {
     }
 }
 
+/// Computes the input and output reordering of the Good-Thomas Algorithm for a `width` by `height` FFT: The first
+/// `width * height` entries are the input index of each element before the width FFTs, and the rest are the output
+/// index of each element after the height FFTs
+pub(crate) fn good_thomas_map(width: usize, height: usize) -> Result<Vec<usize>, FftError> {
+    let len = check_product_len(width, height)?;
+
+    // compute the nultiplicative inverse of n1 mod height and vice versa
+    let (gcd, mut width_inverse, mut height_inverse) =
+        math_utils::extended_euclidean_algorithm(width as i64, height as i64);
+    if gcd != 1 {
+        return Err(FftError::NotCoprime { width, height });
+    }
+
+    // width_inverse or height_inverse might be negative, make it positive
+    if width_inverse < 0 {
+        width_inverse += height as i64;
+    }
+    if height_inverse < 0 {
+        height_inverse += width as i64;
+    }
+
+    // NOTE: we are precomputing the input and output reordering indexes, because benchmarking shows that it's 10-20% faster
+    // If we wanted to optimize for memory use or setup time instead of multiple-FFT speed, we could compute these on the fly in the perform_fft() method
+    let input_iter = (0..len)
+            .map(|i| (i % width, i / width))
+            .map(|(x, y)| (x * height + y * width) % len);
+    let output_iter = (0..len)
+            .map(|i| (i % height, i / height))
+            .map(|(y, x)| (x * height * height_inverse as usize + y * width * width_inverse as usize) % len);
+
+    Ok(input_iter.chain(output_iter).collect())
+}
+
 impl<T: FFTnum> FFT<T> for GoodThomasAlgorithmDoubleButterfly<T> {
     fn process(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) {
         verify_length(input, output, self.len());
@@ -310,7 +350,7 @@ impl<T> IsInverse for GoodThomasAlgorithmDoubleButterfly<T> {
 #[cfg(test)]
 mod unit_tests {
     use super::*;
-    use std::sync::Arc;
+    use alloc::sync::Arc;
     use test_utils::{check_fft_algorithm, make_butterfly};
     use algorithm::DFT;
     use num_integer::gcd;
@@ -340,6 +380,26 @@ mod unit_tests {
         }
     }
 
+    #[test]
+    fn test_good_thomas_try_new() {
+        let width_fft = Arc::new(DFT::new(4, false)) as Arc<FFT<f32>>;
+        let height_fft = Arc::new(DFT::new(6, false)) as Arc<FFT<f32>>;
+        assert_eq!(GoodThomasAlgorithm::try_new(width_fft, height_fft).err(), Some(FftError::NotCoprime { width: 4, height: 6 }));
+
+        let width_fft = Arc::new(DFT::new(4, false)) as Arc<FFT<f32>>;
+        let height_fft = Arc::new(DFT::new(5, true)) as Arc<FFT<f32>>;
+        assert_eq!(GoodThomasAlgorithm::try_new(width_fft, height_fft).err(),
+            Some(FftError::MismatchedDirection { width_inverse: false, height_inverse: true }));
+
+        let width_fft = Arc::new(DFT::new(0, false)) as Arc<FFT<f32>>;
+        let height_fft = Arc::new(DFT::new(1, false)) as Arc<FFT<f32>>;
+        assert_eq!(GoodThomasAlgorithm::try_new(width_fft, height_fft).err(), Some(FftError::ZeroLength));
+
+        let width_fft = make_butterfly(3, false);
+        let height_fft = make_butterfly(6, false);
+        assert_eq!(GoodThomasAlgorithmDoubleButterfly::try_new(width_fft, height_fft).err(), Some(FftError::NotCoprime { width: 3, height: 6 }));
+    }
+
     fn test_good_thomas_with_lengths(width: usize, height: usize, inverse: bool) {
         let width_fft = Arc::new(DFT::new(width, inverse)) as Arc<FFT<f32>>;
         let height_fft = Arc::new(DFT::new(height, inverse)) as Arc<FFT<f32>>;
@@ -1,12 +1,14 @@
-use std::sync::Arc;
+use alloc::sync::Arc;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
 
 use num_complex::Complex;
-use transpose;
 
-use common::{FFTnum, verify_length, verify_length_divisible};
+use common::{FFTnum, verify_length, verify_length_divisible, check_product_len};
 
-use ::{Length, IsInverse, FFT};
+use ::{Length, IsInverse, FFT, FftError};
 use algorithm::butterflies::FFTButterfly;
+use parallel::Parallelism;
 use array_utils;
 use twiddles;
 
@@ -45,40 +47,60 @@ pub struct MixedRadix<T> {
 
     twiddles: Box<[Complex<T>]>,
     inverse: bool,
+
+    parallelism: Parallelism,
 }
 
 impl<T: FFTnum> MixedRadix<T> {
     /// Creates a FFT instance which will process inputs/outputs of size `width_fft.len() * height_fft.len()`
+    ///
+    /// Both FFTs must have the same direction
     pub fn new(width_fft: Arc<FFT<T>>, height_fft: Arc<FFT<T>>) -> Self {
-        assert_eq!(
-            width_fft.is_inverse(), height_fft.is_inverse(), 
-            "width_fft and height_fft must both be inverse, or neither. got width inverse={}, height inverse={}",
-            width_fft.is_inverse(), height_fft.is_inverse());
+        Self::try_new(width_fft, height_fft).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Same as [`new`](#method.new), except that invalid inputs return an [`FftError`](../enum.FftError.html) instead
+    /// of panicking
+    pub fn try_new(width_fft: Arc<FFT<T>>, height_fft: Arc<FFT<T>>) -> Result<Self, FftError> {
+        if width_fft.is_inverse() != height_fft.is_inverse() {
+            return Err(FftError::MismatchedDirection {
+                width_inverse: width_fft.is_inverse(),
+                height_inverse: height_fft.is_inverse(),
+            });
+        }
 
         let inverse = width_fft.is_inverse();
 
         let width = width_fft.len();
         let height = height_fft.len();
+        if width == 0 || height == 0 {
+            return Err(FftError::ZeroLength);
+        }
 
-        let len = width * height;
+        check_product_len(width, height)?;
 
-        let mut twiddles = Vec::with_capacity(len);
-        for x in 0..width {
-            for y in 0..height {
-                twiddles.push(twiddles::single_twiddle(x * y, len, inverse));
-            }
-        }
+        let twiddles = make_twiddles(width, height, inverse);
 
-        MixedRadix {
+        Ok(MixedRadix {
             width: width,
             width_size_fft: width_fft,
 
             height: height,
             height_size_fft: height_fft,
 
-            twiddles: twiddles.into_boxed_slice(),
+            twiddles: twiddles,
             inverse: inverse,
-        }
+
+            parallelism: Parallelism::default(),
+        })
+    }
+
+    /// Sets how this FFT instance splits its inner FFTs and transposes across threads
+    ///
+    /// Without the `rayon` feature, all work is done on the calling thread regardless of this setting
+    pub fn with_parallelism(mut self, parallelism: Parallelism) -> Self {
+        self.parallelism = parallelism;
+        self
     }
 
 
@@ -86,24 +108,23 @@ impl<T: FFTnum> MixedRadix<T> {
         // SIX STEP FFT:
 
         // STEP 1: transpose
-        transpose::transpose(input, output, self.width, self.height);
+        self.parallelism.transpose(input, output, self.width, self.height);
 
         // STEP 2: perform FFTs of size `height`
-        self.height_size_fft.process_multi(output, input);
+        self.parallelism.process_multi(&*self.height_size_fft, output, input);
 
         // STEP 3: Apply twiddle factors
-        for (element, &twiddle) in input.iter_mut().zip(self.twiddles.iter()) {
-            *element = *element * twiddle;
-        }
+        let (twiddles, height) = (&self.twiddles, self.height);
+        self.parallelism.for_each_row(input, height, |x, row| apply_twiddles(row, &twiddles[x * height..(x + 1) * height]));
 
         // STEP 4: transpose again
-        transpose::transpose(input, output, self.height, self.width);
+        self.parallelism.transpose(input, output, self.height, self.width);
 
         // STEP 5: perform FFTs of size `width`
-        self.width_size_fft.process_multi(output, input);
+        self.parallelism.process_multi(&*self.width_size_fft, output, input);
 
         // STEP 6: transpose again
-        transpose::transpose(input, output, self.width, self.height);
+        self.parallelism.transpose(input, output, self.width, self.height);
     }
 }
 impl<T: FFTnum> FFT<T> for MixedRadix<T> {
@@ -115,15 +136,13 @@ impl<T: FFTnum> FFT<T> for MixedRadix<T> {
     fn process_multi(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) {
         verify_length_divisible(input, output, self.len());
 
-        for (in_chunk, out_chunk) in input.chunks_mut(self.len()).zip(output.chunks_mut(self.len())) {
-            self.perform_fft(in_chunk, out_chunk);
-        }
+        self.parallelism.for_each_chunk(input, output, self.len(), |in_chunk, out_chunk| self.perform_fft(in_chunk, out_chunk));
     }
 }
 impl<T> Length for MixedRadix<T> {
     #[inline(always)]
     fn len(&self) -> usize {
-        self.twiddles.len()
+        self.width * self.height
     }
 }
 impl<T> IsInverse for MixedRadix<T> {
@@ -175,36 +194,44 @@ pub struct MixedRadixDoubleButterfly<T> {
 
 impl<T: FFTnum> MixedRadixDoubleButterfly<T> {
     /// Creates a FFT instance which will process inputs/outputs of size `width_fft.len() * height_fft.len()`
+    ///
+    /// Both FFTs must have the same direction
     pub fn new(width_fft: Arc<FFTButterfly<T>>, height_fft: Arc<FFTButterfly<T>>) -> Self {
-        assert_eq!(
-            width_fft.is_inverse(), height_fft.is_inverse(), 
-            "width_fft and height_fft must both be inverse, or neither. got width inverse={}, height inverse={}",
-            width_fft.is_inverse(), height_fft.is_inverse());
+        Self::try_new(width_fft, height_fft).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Same as [`new`](#method.new), except that invalid inputs return an [`FftError`](../enum.FftError.html) instead
+    /// of panicking
+    pub fn try_new(width_fft: Arc<FFTButterfly<T>>, height_fft: Arc<FFTButterfly<T>>) -> Result<Self, FftError> {
+        if width_fft.is_inverse() != height_fft.is_inverse() {
+            return Err(FftError::MismatchedDirection {
+                width_inverse: width_fft.is_inverse(),
+                height_inverse: height_fft.is_inverse(),
+            });
+        }
 
         let inverse = width_fft.is_inverse();
 
         let width = width_fft.len();
         let height = height_fft.len();
+        if width == 0 || height == 0 {
+            return Err(FftError::ZeroLength);
+        }
 
-        let len = width * height;
+        check_product_len(width, height)?;
 
-        let mut twiddles = Vec::with_capacity(len);
-        for x in 0..width {
-            for y in 0..height {
-                twiddles.push(twiddles::single_twiddle(x * y, len, inverse));
-            }
-        }
+        let twiddles = make_twiddles(width, height, inverse);
 
-        MixedRadixDoubleButterfly {
+        Ok(MixedRadixDoubleButterfly {
             width: width,
             width_size_fft: width_fft,
 
             height: height,
             height_size_fft: height_fft,
 
-            twiddles: twiddles.into_boxed_slice(),
+            twiddles: twiddles,
             inverse: inverse
-        }
+        })
     }
 
 
@@ -218,9 +245,7 @@ impl<T: FFTnum> MixedRadixDoubleButterfly<T> {
         self.height_size_fft.process_multi_inplace(output);
 
         // STEP 3: Apply twiddle factors
-        for (element, &twiddle) in output.iter_mut().zip(self.twiddles.iter()) {
-            *element = *element * twiddle;
-        }
+        apply_twiddles(output, &self.twiddles);
 
         // STEP 4: transpose again
         array_utils::transpose_small(self.height, self.width, output, input);
@@ -250,7 +275,7 @@ impl<T: FFTnum> FFT<T> for MixedRadixDoubleButterfly<T> {
 impl<T> Length for MixedRadixDoubleButterfly<T> {
     #[inline(always)]
     fn len(&self) -> usize {
-        self.twiddles.len()
+        self.width * self.height
     }
 }
 impl<T> IsInverse for MixedRadixDoubleButterfly<T> {
@@ -261,12 +286,33 @@ impl<T> IsInverse for MixedRadixDoubleButterfly<T> {
 }
 
 
+/// Returns twiddle(x * y, width * height) for every x and y, with y varying fastest
+///
+/// Step 3 applies these in order on every call, so they're stored contiguously rather than computed with a stride of x
+pub(crate) fn make_twiddles<T: FFTnum>(width: usize, height: usize, inverse: bool) -> Box<[Complex<T>]> {
+    let len = width * height;
+    let mut twiddles = Vec::with_capacity(len);
+    for x in 0..width {
+        for y in 0..height {
+            twiddles.push(twiddles::single_twiddle(x * y, len, inverse));
+        }
+    }
+    twiddles.into_boxed_slice()
+}
+
+/// Multiplies each element of `buffer` by the corresponding element of `twiddles`
+#[inline(always)]
+fn apply_twiddles<T: FFTnum>(buffer: &mut [Complex<T>], twiddles: &[Complex<T>]) {
+    for (element, &twiddle) in buffer.iter_mut().zip(twiddles.iter()) {
+        *element = *element * twiddle;
+    }
+}
 
 
 #[cfg(test)]
 mod unit_tests {
     use super::*;
-    use std::sync::Arc;
+    use alloc::sync::Arc;
     use test_utils::{check_fft_algorithm, make_butterfly};
     use algorithm::DFT;
 
@@ -290,8 +336,22 @@ mod unit_tests {
         }
     }
 
-
-
+    #[test]
+    fn test_mixed_radix_try_new() {
+        let width_fft = Arc::new(DFT::new(4, false)) as Arc<FFT<f32>>;
+        let height_fft = Arc::new(DFT::new(5, true)) as Arc<FFT<f32>>;
+        assert_eq!(MixedRadix::try_new(width_fft, height_fft).err(),
+            Some(FftError::MismatchedDirection { width_inverse: false, height_inverse: true }));
+
+        let width_fft = Arc::new(DFT::new(3, false)) as Arc<FFT<f32>>;
+        let height_fft = Arc::new(DFT::new(0, false)) as Arc<FFT<f32>>;
+        assert_eq!(MixedRadix::try_new(width_fft, height_fft).err(), Some(FftError::ZeroLength));
+
+        let width_fft = make_butterfly(3, false);
+        let height_fft = make_butterfly(4, true);
+        assert_eq!(MixedRadixDoubleButterfly::try_new(width_fft, height_fft).err(),
+            Some(FftError::MismatchedDirection { width_inverse: false, height_inverse: true }));
+    }
 
     fn test_mixed_radix_with_lengths(width: usize, height: usize, inverse: bool) {
         let width_fft = Arc::new(DFT::new(width, inverse)) as Arc<FFT<f32>>;
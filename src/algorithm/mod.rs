@@ -1,14 +1,39 @@
+/// Hardcoded size-specfic FFT algorithms
+// declared first, so that the other algorithms can choose butterflies by size with its macros
+#[macro_use]
+pub mod butterflies;
+
 mod good_thomas_algorithm;
 mod mixed_radix;
+mod four_step;
 mod raders_algorithm;
+mod prime_power;
 mod radix4;
+mod radix_n;
+mod split_radix;
+mod stockham;
 mod dft;
-
-/// Hardcoded size-specfic FFT algorithms
-pub mod butterflies;
+#[cfg(feature = "const-generics")]
+mod fixed;
+#[cfg(feature = "half")]
+mod widened;
 
 pub use self::mixed_radix::{MixedRadix, MixedRadixDoubleButterfly};
+pub use self::four_step::FourStep;
 pub use self::raders_algorithm::RadersAlgorithm;
+pub use self::prime_power::PrimePower;
 pub use self::radix4::Radix4;
+pub use self::radix_n::RadixN;
+pub use self::split_radix::SplitRadix;
+pub use self::stockham::Stockham;
 pub use self::good_thomas_algorithm::{GoodThomasAlgorithm, GoodThomasAlgorithmDoubleButterfly};
 pub use self::dft::DFT;
+#[cfg(feature = "const-generics")]
+pub use self::fixed::FixedFFT;
+#[cfg(feature = "half")]
+pub use self::widened::Widened;
+
+// the fixed-point FFT computes the same steps as these algorithms, with its own scaling between them
+pub(crate) use self::mixed_radix::make_twiddles;
+pub(crate) use self::good_thomas_algorithm::good_thomas_map;
+pub(crate) use self::raders_algorithm::raders_kernel;
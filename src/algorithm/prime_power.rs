@@ -0,0 +1,263 @@
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use alloc::boxed::Box;
+
+use num_complex::Complex;
+
+use common::{FFTnum, verify_length, verify_length_divisible, check_product_len};
+
+use ::{Length, IsInverse, FFT, FftError};
+use parallel::Parallelism;
+use twiddles;
+
+/// FFT algorithm for sizes that are a power of a single number, usually a prime
+///
+/// This algorithm computes a FFT of size `p^k` in k stages, using the same inner FFT of size p for every stage. Each
+/// stage gathers its inputs into contiguous order while multiplying them by twiddle factors, then computes every
+/// inner FFT of the stage with a single call to `process_multi`. Unlike nesting [`MixedRadix`](struct.MixedRadix.html)
+/// k times, this only needs one pass over the data per stage, plus a digit-reversal pass at the start and a transpose
+/// at the end. The inner FFT can be any algorithm, including [`RadersAlgorithm`](struct.RadersAlgorithm.html) for
+/// primes without a butterfly.
+///
+/// ~~~
+/// // Computes a forward FFT of size 44521 = 211^2
+/// use rustfft::algorithm::PrimePower;
+/// use rustfft::{FFT, FFTplanner};
+/// use rustfft::num_complex::Complex;
+/// use rustfft::num_traits::Zero;
+///
+/// let mut input:  Vec<Complex<f32>> = vec![Zero::zero(); 44521];
+/// let mut output: Vec<Complex<f32>> = vec![Zero::zero(); 44521];
+///
+/// let mut planner = FFTplanner::new(false);
+/// let inner_fft = planner.plan_fft(211);
+///
+/// let fft = PrimePower::new(inner_fft, 2);
+/// fft.process(&mut input, &mut output);
+/// ~~~
+pub struct PrimePower<T> {
+    inner_fft: Arc<FFT<T>>,
+    exponent: usize,
+
+    // for each stage after the first, the twiddle factors for each of its inner FFTs, stored one FFT after another
+    twiddles: Box<[Complex<T>]>,
+
+    len: usize,
+    inverse: bool,
+
+    parallelism: Parallelism,
+}
+
+impl<T: FFTnum> PrimePower<T> {
+    /// Creates a FFT instance which will process inputs/outputs of size `inner_fft.len()^exponent`
+    pub fn new(inner_fft: Arc<FFT<T>>, exponent: usize) -> Self {
+        Self::try_new(inner_fft, exponent).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Same as [`new`](#method.new), except that invalid inputs return an [`FftError`](../enum.FftError.html) instead
+    /// of panicking
+    pub fn try_new(inner_fft: Arc<FFT<T>>, exponent: usize) -> Result<Self, FftError> {
+        let radix = inner_fft.len();
+        if radix == 0 {
+            return Err(FftError::ZeroLength);
+        }
+
+        let mut len = 1;
+        for _ in 0..exponent {
+            len = check_product_len(len, radix)?;
+        }
+
+        // the stage combining FFTs of size m multiplies input t of inner FFT i by twiddle(i * t, m * radix)
+        let inverse = inner_fft.is_inverse();
+        let mut twiddle_factors = Vec::with_capacity(len);
+        let mut sub_len = radix;
+        while sub_len < len {
+            let twiddle_stride = len / (sub_len * radix);
+            for i in 0..sub_len {
+                for t in 1..radix {
+                    twiddle_factors.push(twiddles::single_twiddle(i * t * twiddle_stride, len, inverse));
+                }
+            }
+            sub_len *= radix;
+        }
+
+        Ok(PrimePower {
+            inner_fft,
+            exponent,
+
+            twiddles: twiddle_factors.into_boxed_slice(),
+
+            len,
+            inverse,
+
+            parallelism: Parallelism::default(),
+        })
+    }
+
+    /// Sets how this FFT instance splits its inner FFTs and transposes across threads
+    ///
+    /// Without the `rayon` feature, all work is done on the calling thread regardless of this setting
+    pub fn with_parallelism(mut self, parallelism: Parallelism) -> Self {
+        self.parallelism = parallelism;
+        self
+    }
+
+    fn perform_fft(&self, signal: &mut [Complex<T>], spectrum: &mut [Complex<T>]) {
+        if self.exponent == 0 {
+            spectrum.copy_from_slice(signal);
+            return;
+        }
+
+        // reorder the input so that each of the first stage's inner FFTs is contiguous, then compute them
+        let radix = self.inner_fft.len();
+        prepare_prime_power(signal, spectrum, 1, radix);
+        self.parallelism.process_multi(&*self.inner_fft, spectrum, signal);
+
+        // between stages, every sub-FFT of size m is stored with its output i + k * (m / radix) at i * radix + k,
+        // because that's the order the inner FFTs write their outputs in
+
+        let mut sub_len = radix;
+        let mut stage_twiddles: &[Complex<T>] = &self.twiddles;
+        while sub_len < self.len {
+            // each chunk of the stage's output is built from the `radix` consecutive sub-FFTs in the same place
+            let chunk_len = sub_len * radix;
+            self.parallelism.for_each_chunk(signal, spectrum, chunk_len, |sub_ffts, chunk| {
+                gather_stage(sub_ffts, chunk, stage_twiddles, radix, sub_len)
+            });
+            self.parallelism.process_multi(&*self.inner_fft, spectrum, signal);
+
+            //skip past all the twiddle factors used in this stage
+            stage_twiddles = &stage_twiddles[sub_len * (radix - 1)..];
+            sub_len = chunk_len;
+        }
+
+        // the last stage left output i + k * len/radix at i * radix + k
+        self.parallelism.transpose(signal, spectrum, radix, self.len / radix);
+    }
+}
+
+/// Copies `signal` into `spectrum` in digit-reversed order, so that the elements of each inner FFT of the first stage
+/// are contiguous, and the `radix` sub-FFTs that each inner FFT of a later stage combines are next to each other
+fn prepare_prime_power<T: FFTnum>(signal: &[Complex<T>], spectrum: &mut [Complex<T>], stride: usize, radix: usize) {
+    if spectrum.len() == radix {
+        assert!(signal.len() > (radix - 1) * stride);
+        for (i, element) in spectrum.iter_mut().enumerate() {
+            *element = unsafe { *signal.get_unchecked(i * stride) };
+        }
+    } else {
+        // sub-FFT j contains the elements at j, j + radix, j + 2 * radix, ...
+        let sub_len = spectrum.len() / radix;
+        for (j, sub_spectrum) in spectrum.chunks_mut(sub_len).enumerate() {
+            prepare_prime_power(&signal[j * stride..], sub_spectrum, stride * radix, radix);
+        }
+    }
+}
+
+/// Reads the `radix` sub-FFTs of size `sub_len` in `sub_ffts`, and writes the inputs of each of the stage's inner FFTs
+/// contiguously to `output`, multiplied by their twiddle factors
+fn gather_stage<T: FFTnum>(sub_ffts: &[Complex<T>],
+                           output: &mut [Complex<T>],
+                           twiddles: &[Complex<T>],
+                           radix: usize,
+                           sub_len: usize) {
+    assert!(sub_ffts.len() == sub_len * radix && output.len() == sub_len * radix);
+    assert!(twiddles.len() >= sub_len * (radix - 1));
+
+    // inner FFT i takes output i of each sub-FFT, which is stored at (i % sub_rows) * radix + (i / sub_rows)
+    let sub_rows = sub_len / radix;
+    let mut index = 0;
+    for k in 0..radix {
+        for row in 0..sub_rows {
+            let source_index = row * radix + k;
+            unsafe {
+                *output.get_unchecked_mut(index) = *sub_ffts.get_unchecked(source_index);
+                let fft_twiddles = twiddles.get_unchecked((index / radix) * (radix - 1)..);
+                for t in 1..radix {
+                    *output.get_unchecked_mut(index + t) = *sub_ffts.get_unchecked(t * sub_len + source_index)
+                        * *fft_twiddles.get_unchecked(t - 1);
+                }
+            }
+            index += radix;
+        }
+    }
+}
+
+impl<T: FFTnum> FFT<T> for PrimePower<T> {
+    fn process(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) {
+        verify_length(input, output, self.len());
+
+        self.perform_fft(input, output);
+    }
+    fn process_multi(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) {
+        verify_length_divisible(input, output, self.len());
+
+        for (in_chunk, out_chunk) in input.chunks_mut(self.len()).zip(output.chunks_mut(self.len())) {
+            self.perform_fft(in_chunk, out_chunk);
+        }
+    }
+}
+impl<T> Length for PrimePower<T> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+impl<T> IsInverse for PrimePower<T> {
+    #[inline(always)]
+    fn is_inverse(&self) -> bool {
+        self.inverse
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use algorithm::DFT;
+    use algorithm::butterflies::{Butterfly2, Butterfly3, Butterfly7, Butterfly17};
+    use test_utils::check_fft_algorithm;
+
+    #[test]
+    fn test_prime_power() {
+        for &(radix, max_exponent) in &[(2, 9), (3, 6), (5, 4), (7, 3), (11, 2), (13, 2)] {
+            for exponent in 0..max_exponent + 1 {
+                test_prime_power_with_dft(radix, exponent, false);
+                test_prime_power_with_dft(radix, exponent, true);
+            }
+        }
+    }
+
+    #[test]
+    fn test_prime_power_butterfly() {
+        let inner_ffts: Vec<(Arc<FFT<f32>>, usize)> = vec![
+            (Arc::new(Butterfly2::new(false)), 10),
+            (Arc::new(Butterfly3::new(false)), 7),
+            (Arc::new(Butterfly7::new(true)), 4),
+            (Arc::new(Butterfly17::new(true)), 2),
+        ];
+        for (inner_fft, exponent) in inner_ffts {
+            let inverse = inner_fft.is_inverse();
+            let fft = PrimePower::new(inner_fft, exponent);
+
+            check_fft_algorithm(&fft, fft.len(), inverse);
+        }
+    }
+
+    #[test]
+    fn test_prime_power_try_new() {
+        let inner_fft = Arc::new(DFT::<f32>::new(0, false));
+        assert_eq!(PrimePower::try_new(inner_fft, 2).err(), Some(FftError::ZeroLength));
+
+        let inner_fft = Arc::new(DFT::<f32>::new(1 << 20, false));
+        assert!(match PrimePower::try_new(inner_fft, 4) {
+            Err(FftError::LengthOverflow { .. }) => true,
+            _ => false,
+        });
+    }
+
+    fn test_prime_power_with_dft(radix: usize, exponent: usize, inverse: bool) {
+        let inner_fft = Arc::new(DFT::new(radix, inverse));
+        let fft = PrimePower::new(inner_fft, exponent);
+
+        check_fft_algorithm(&fft, radix.pow(exponent as u32), inverse);
+    }
+}
@@ -1,4 +1,6 @@
-use std::sync::Arc;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use alloc::boxed::Box;
 
 use num_complex::Complex;
 use num_traits::Zero;
@@ -8,7 +10,7 @@ use common::{FFTnum, verify_length, verify_length_divisible};
 
 use math_utils;
 use twiddles;
-use ::{Length, IsInverse, FFT};
+use ::{Length, IsInverse, FFT, FftError};
 
 /// Implementation of Rader's Algorithm
 ///
@@ -16,7 +18,12 @@ use ::{Length, IsInverse, FFT};
 /// size (n - 1) FFT, which is guaranteed to be composite.
 ///
 /// The worst case for this algorithm is when (n - 1) is 2 * prime, resulting in a
-/// [Cunningham Chain](https://en.wikipedia.org/wiki/Cunningham_chain)
+/// [Cunningham Chain](https://en.wikipedia.org/wiki/Cunningham_chain). To avoid that, the inner FFT can instead have
+/// any length of at least 2n - 3, such as a power of two. The size n - 1 cyclic convolution at the heart of the
+/// algorithm is then computed with zero padding, at the cost of a larger inner FFT and a scratch buffer for its input
+/// and output. Since that's larger than the input and output combined, it's allocated on every call to `process`, and
+/// the default cost model includes that allocation in its estimate. `process_multi` only allocates it once for all of
+/// its FFTs.
 ///
 /// ~~~
 /// // Computes a forward FFT of size 1201 (prime number), using Rader's Algorithm
@@ -44,6 +51,9 @@ pub struct RadersAlgorithm<T> {
     inner_fft: Arc<FFT<T>>,
     inner_fft_data: Box<[Complex<T>]>,
 
+    // true if the inner FFT is longer than len - 1, and the convolution has to be zero padded
+    zero_padded: bool,
+
     primitive_root: usize,
     primitive_root_inverse: usize,
 
@@ -51,50 +61,80 @@ pub struct RadersAlgorithm<T> {
 }
 
 impl<T: FFTnum> RadersAlgorithm<T> {
-    /// Creates a FFT instance which will process inputs/outputs of size `len`. `inner_fft.len()` must be `len - 1`
+    /// Creates a FFT instance which will process inputs/outputs of size `len`. `inner_fft.len()` must be `len - 1`,
+    /// or at least `2 * len - 3` to zero pad the convolution
     ///
     /// Note that this constructor is quite expensive to run; This algorithm must run a FFT of size n - 1 within the
     /// constructor. This further underlines the fact that Rader's Algorithm is more expensive to run than other
     /// FFT algorithms
     ///
-    /// Note also that if `len` is not prime, this algorithm may silently produce garbage output
+    /// Panics if `len` is not prime, or if `inner_fft.len()` is neither `len - 1` nor at least `2 * len - 3`
     pub fn new(len: usize, inner_fft: Arc<FFT<T>>) -> Self {
-        assert_eq!(len - 1, inner_fft.len(), "For raders algorithm, inner_fft.len() must be self.len() - 1. Expected {}, got {}", len - 1, inner_fft.len());
+        Self::try_new(len, inner_fft).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Same as [`new`](#method.new), except that invalid inputs return an [`FftError`](../enum.FftError.html) instead
+    /// of panicking
+    pub fn try_new(len: usize, inner_fft: Arc<FFT<T>>) -> Result<Self, FftError> {
+        if !math_utils::is_prime(len as u64) {
+            return Err(FftError::NotPrime { len });
+        }
+        // a linear convolution of the two size len - 1 sequences has 2 * len - 3 elements, so any inner FFT at least
+        // that long can compute the cyclic convolution without the ends wrapping around into each other
+        let convolution_len = len - 1;
+        let inner_fft_len = inner_fft.len();
+        let zero_padded = inner_fft_len != convolution_len;
+        if inner_fft_len < convolution_len {
+            return Err(FftError::InnerLength { expected: convolution_len, actual: inner_fft_len });
+        }
+        // 2 * len - 3 only saturates for sizes far too large to allocate
+        let min_padded_len = convolution_len.saturating_add(len - 2);
+        if zero_padded && inner_fft_len < min_padded_len {
+            return Err(FftError::InnerLengthTooShort { minimum: min_padded_len, actual: inner_fft_len });
+        }
 
-        let inner_fft_len = len - 1;
         let reduced_len = StrengthReducedUsize::new(len);
 
-        // compute the primitive root and its inverse for this size
+        // compute the primitive root and its inverse for this size. every prime has a primitive root
         let primitive_root = math_utils::primitive_root(len as u64).unwrap() as usize;
         let primitive_root_inverse = math_utils::multiplicative_inverse(primitive_root as usize, len);
 
         // precompute the coefficients to use inside the process method
-        let unity_scale = T::from_f64(1f64 / inner_fft_len as f64).unwrap();
-        let mut inner_fft_input = vec![Complex::zero(); inner_fft_len];
-        let mut twiddle_input = 1;
-        for input_cell in &mut inner_fft_input {
-            let twiddle = twiddles::single_twiddle(twiddle_input, len, inner_fft.is_inverse());
-            *input_cell = twiddle * unity_scale;
-
-            twiddle_input = (twiddle_input * primitive_root_inverse) % reduced_len;
-        }
+        let inner_fft_data = raders_kernel(len, primitive_root_inverse, &*inner_fft);
 
-        //precompute a FFT of our reordered twiddle factors
-        let mut inner_fft_output = vec![Zero::zero(); inner_fft_len];
-        inner_fft.process(&mut inner_fft_input, &mut inner_fft_output);
-
-        Self {
+        Ok(Self {
             inner_fft: inner_fft,
-            inner_fft_data: inner_fft_output.into_boxed_slice(),
+            inner_fft_data: inner_fft_data.into_boxed_slice(),
+
+            zero_padded,
 
             primitive_root,
             primitive_root_inverse,
 
             len: reduced_len,
+        })
+    }
+
+    /// Returns true if this instance zero pads its convolution, rather than using an inner FFT of size `len - 1`
+    pub fn is_zero_padded(&self) -> bool {
+        self.zero_padded
+    }
+
+    /// Allocates the scratch space needed by `perform_fft`, which is empty unless the convolution is zero padded
+    fn make_scratch(&self) -> Vec<Complex<T>> {
+        if self.zero_padded {
+            vec![Zero::zero(); 2 * self.inner_fft.len()]
+        } else {
+            Vec::new()
         }
     }
 
-    fn perform_fft(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) {
+    fn perform_fft(&self, input: &mut [Complex<T>], output: &mut [Complex<T>], scratch: &mut [Complex<T>]) {
+        if self.zero_padded {
+            self.perform_fft_zero_padded(input, output, scratch);
+            return;
+        }
+
 
         // The first output element is just the sum of all the input elements
         output[0] = input.iter().sum();
@@ -131,19 +171,84 @@ impl<T: FFTnum> RadersAlgorithm<T> {
             output[output_index - 1] = input_element.conj() + first_input_val;
         }
     }
+
+    fn perform_fft_zero_padded(&self, input: &[Complex<T>], output: &mut [Complex<T>], scratch: &mut [Complex<T>]) {
+        // same as perform_fft, except that the inner FFTs read and write the scratch space instead, and the padding
+        // after the reordered input has to be zeroed
+        output[0] = input.iter().sum();
+        let first_input_val = input[0];
+
+        let (_, output) = output.split_first_mut().unwrap();
+        let (_, input) = input.split_first().unwrap();
+        let (inner_input, inner_output) = scratch.split_at_mut(self.inner_fft.len());
+
+        let mut input_index = 1;
+        for inner_element in inner_input[..output.len()].iter_mut() {
+            input_index = (input_index * self.primitive_root) % self.len;
+            *inner_element = input[input_index - 1];
+        }
+        for inner_element in inner_input[output.len()..].iter_mut() {
+            *inner_element = Zero::zero();
+        }
+
+        self.inner_fft.process(inner_input, inner_output);
+
+        for ((&inner_output_cell, inner_input_cell), &multiple) in inner_output.iter().zip(inner_input.iter_mut()).zip(self.inner_fft_data.iter()) {
+            *inner_input_cell = (inner_output_cell * multiple).conj();
+        }
+
+        self.inner_fft.process(inner_input, inner_output);
+
+        // only the first len - 1 elements of the padded convolution are part of the cyclic convolution
+        let mut output_index = 1;
+        for inner_element in &inner_output[..output.len()] {
+            output_index = (output_index * self.primitive_root_inverse) % self.len;
+            output[output_index - 1] = inner_element.conj() + first_input_val;
+        }
+    }
+}
+
+/// Computes the data that Rader's Algorithm of size `len` multiplies the output of its first inner FFT by: the FFT of
+/// the twiddle factors in the order of the powers of `primitive_root_inverse`, divided by `inner_fft.len()`
+pub(crate) fn raders_kernel<T: FFTnum>(len: usize, primitive_root_inverse: usize, inner_fft: &FFT<T>) -> Vec<Complex<T>> {
+    let convolution_len = len - 1;
+    let inner_fft_len = inner_fft.len();
+    let zero_padded = inner_fft_len != convolution_len;
+
+    let unity_scale = T::from_f64(1f64 / inner_fft_len as f64).unwrap();
+    let mut inner_fft_input = vec![Complex::zero(); inner_fft_len];
+    let mut twiddle_input = 1;
+    for i in 0..convolution_len {
+        let twiddle = twiddles::single_twiddle(twiddle_input, len, inner_fft.is_inverse());
+        inner_fft_input[i] = twiddle * unity_scale;
+
+        // when zero padding, the kernel is cyclic, so its end has to be repeated before the padding's end too
+        if zero_padded && i > 0 {
+            inner_fft_input[inner_fft_len - convolution_len + i] = inner_fft_input[i];
+        }
+
+        twiddle_input = (twiddle_input * primitive_root_inverse) % len;
+    }
+
+    //precompute a FFT of our reordered twiddle factors
+    let mut inner_fft_output = vec![Zero::zero(); inner_fft_len];
+    inner_fft.process(&mut inner_fft_input, &mut inner_fft_output);
+    inner_fft_output
 }
 
 impl<T: FFTnum> FFT<T> for RadersAlgorithm<T> {
     fn process(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) {
         verify_length(input, output, self.len());
 
-        self.perform_fft(input, output);
+        let mut scratch = self.make_scratch();
+        self.perform_fft(input, output, &mut scratch);
     }
     fn process_multi(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) {
         verify_length_divisible(input, output, self.len());
 
+        let mut scratch = self.make_scratch();
         for (in_chunk, out_chunk) in input.chunks_mut(self.len()).zip(output.chunks_mut(self.len())) {
-            self.perform_fft(in_chunk, out_chunk);
+            self.perform_fft(in_chunk, out_chunk, &mut scratch);
         }
     }
 }
@@ -163,7 +268,7 @@ impl<T> IsInverse for RadersAlgorithm<T> {
 #[cfg(test)]
 mod unit_tests {
     use super::*;
-    use std::sync::Arc;
+    use alloc::sync::Arc;
     use test_utils::check_fft_algorithm;
     use algorithm::DFT;
 
@@ -175,10 +280,40 @@ mod unit_tests {
         }
     }
 
+    #[test]
+    fn test_raders_zero_padded() {
+        for &(len, inner_len) in &[(3, 3), (3, 4), (5, 7), (5, 8), (7, 16), (11, 19), (13, 32), (23, 64)] {
+            test_raders_zero_padded_with_length(len, inner_len, false);
+            test_raders_zero_padded_with_length(len, inner_len, true);
+        }
+    }
+
+    #[test]
+    fn test_raders_try_new() {
+        for &len in &[0usize, 1, 4, 9, 15] {
+            let inner_fft = Arc::new(DFT::new(len.saturating_sub(1), false)) as Arc<FFT<f32>>;
+            assert_eq!(RadersAlgorithm::try_new(len, inner_fft).err(), Some(FftError::NotPrime { len }));
+        }
+
+        let inner_fft = Arc::new(DFT::new(5, false)) as Arc<FFT<f32>>;
+        assert_eq!(RadersAlgorithm::try_new(7, inner_fft).err(), Some(FftError::InnerLength { expected: 6, actual: 5 }));
+
+        let inner_fft = Arc::new(DFT::new(10, false)) as Arc<FFT<f32>>;
+        assert_eq!(RadersAlgorithm::try_new(7, inner_fft).err(), Some(FftError::InnerLengthTooShort { minimum: 11, actual: 10 }));
+    }
+
     fn test_raders_with_length(len: usize, inverse: bool) {
         let inner_fft = Arc::new(DFT::new(len - 1, inverse));
         let fft = RadersAlgorithm::new(len, inner_fft);
 
         check_fft_algorithm(&fft, len, inverse);
     }
+
+    fn test_raders_zero_padded_with_length(len: usize, inner_len: usize, inverse: bool) {
+        let inner_fft = Arc::new(DFT::new(inner_len, inverse));
+        let fft = RadersAlgorithm::new(len, inner_fft);
+        assert_eq!(fft.is_zero_padded(), inner_len != len - 1);
+
+        check_fft_algorithm(&fft, len, inverse);
+    }
 }
@@ -1,10 +1,13 @@
+use alloc::vec::Vec;
+use alloc::boxed::Box;
+
 use num_complex::Complex;
 use num_traits::Zero;
 
 use common::{FFTnum, verify_length, verify_length_divisible};
 
 use algorithm::butterflies::{Butterfly2, Butterfly4, Butterfly8, Butterfly16, FFTButterfly};
-use ::{Length, IsInverse, FFT};
+use ::{Length, IsInverse, FFT, FftError};
 use twiddles;
 
 /// FFT algorithm optimized for power-of-two sizes
@@ -33,8 +36,18 @@ pub struct Radix4<T> {
 
 impl<T: FFTnum> Radix4<T> {
     /// Preallocates necessary arrays and precomputes necessary data to efficiently compute the power-of-two FFT
+    ///
+    /// Panics if `len` is not a power of two
     pub fn new(len: usize, inverse: bool) -> Self {
-        assert!(len.is_power_of_two(), "Radix4 algorithm requires a power-of-two input size. Got {}", len);
+        Self::try_new(len, inverse).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Same as [`new`](#method.new), except that if `len` is not a power of two, this returns an
+    /// [`FftError`](../enum.FftError.html) instead of panicking
+    pub fn try_new(len: usize, inverse: bool) -> Result<Self, FftError> {
+        if !len.is_power_of_two() {
+            return Err(FftError::NotPowerOfTwo { len });
+        }
 
         // precompute the twiddle factors this algorithm will use.
         // we're doing the same precomputation of twiddle factors as the mixed radix algorithm where width=4 and height=len/4
@@ -59,13 +72,13 @@ impl<T: FFTnum> Radix4<T> {
             twiddle_stride >>= 2;
         }
 
-        Radix4 {
+        Ok(Radix4 {
             twiddles: twiddle_factors.into_boxed_slice(),
             butterfly8: Butterfly8::new(inverse),
             butterfly16: Butterfly16::new(inverse),
             len: len,
             inverse: inverse,
-        }
+        })
     }
 
     fn perform_fft(&self, signal: &[Complex<T>], spectrum: &mut [Complex<T>]) {
@@ -239,6 +252,13 @@ mod unit_tests {
         }
     }
 
+    #[test]
+    fn test_radix4_try_new() {
+        for &len in &[0, 3, 12, 100] {
+            assert_eq!(Radix4::<f32>::try_new(len, false).err(), Some(FftError::NotPowerOfTwo { len }));
+        }
+    }
+
     fn test_radix4_with_length(len: usize, inverse: bool) {
         let fft = Radix4::new(len, inverse);
 
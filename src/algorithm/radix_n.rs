@@ -0,0 +1,226 @@
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use alloc::boxed::Box;
+
+use num_complex::Complex;
+
+use common::{FFTnum, verify_length, verify_length_divisible, check_product_len};
+
+use algorithm::butterflies::FFTButterfly;
+use ::{Length, IsInverse, FFT, FftError};
+use twiddles;
+
+/// Iterative Cooley-Tukey FFT algorithm, which applies a list of butterflies one stage at a time
+///
+/// This algorithm computes a FFT whose size is the product of its butterflies' sizes. Like
+/// [`Radix4`](struct.Radix4.html), it reorders the input with a digit-reversal pass, then does one in-place pass over
+/// the data for each butterfly, so unlike [`MixedRadix`](struct.MixedRadix.html), it never transposes the data. The
+/// first butterfly is applied first, to contiguous chunks of the reordered input.
+///
+/// ~~~
+/// // Computes a forward FFT of size 1029 = 3 * 7 * 7 * 7
+/// use std::sync::Arc;
+/// use rustfft::algorithm::RadixN;
+/// use rustfft::algorithm::butterflies::{Butterfly3, Butterfly7, FFTButterfly};
+/// use rustfft::FFT;
+/// use rustfft::num_complex::Complex;
+/// use rustfft::num_traits::Zero;
+///
+/// let mut input:  Vec<Complex<f32>> = vec![Zero::zero(); 1029];
+/// let mut output: Vec<Complex<f32>> = vec![Zero::zero(); 1029];
+///
+/// let butterfly3: Arc<FFTButterfly<f32>> = Arc::new(Butterfly3::new(false));
+/// let butterfly7: Arc<FFTButterfly<f32>> = Arc::new(Butterfly7::new(false));
+///
+/// let fft = RadixN::new(vec![butterfly7.clone(), butterfly7.clone(), butterfly7, butterfly3]);
+/// fft.process(&mut input, &mut output);
+/// ~~~
+pub struct RadixN<T> {
+    // the butterfly of each stage, in the order they're applied
+    butterflies: Box<[Arc<FFTButterfly<T>>]>,
+
+    // for each stage after the first, the twiddle factors for each of its butterflies, stored one butterfly after another
+    twiddles: Box<[Complex<T>]>,
+
+    len: usize,
+    inverse: bool,
+}
+
+impl<T: FFTnum> RadixN<T> {
+    /// Creates a FFT instance which will process inputs/outputs whose size is the product of the butterflies' sizes
+    ///
+    /// There must be at least one butterfly, and every butterfly must have the same direction
+    pub fn new(butterflies: Vec<Arc<FFTButterfly<T>>>) -> Self {
+        Self::try_new(butterflies).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Same as [`new`](#method.new), except that invalid inputs return an [`FftError`](../enum.FftError.html) instead
+    /// of panicking
+    pub fn try_new(butterflies: Vec<Arc<FFTButterfly<T>>>) -> Result<Self, FftError> {
+        let inverse = match butterflies.first() {
+            Some(butterfly) => butterfly.is_inverse(),
+            None => return Err(FftError::NoButterflies),
+        };
+
+        let mut len = 1;
+        for butterfly in &butterflies {
+            if butterfly.is_inverse() != inverse {
+                return Err(FftError::MismatchedDirection {
+                    width_inverse: inverse,
+                    height_inverse: butterfly.is_inverse(),
+                });
+            }
+            len = check_product_len(len, butterfly.len())?;
+        }
+
+        let radices: Vec<usize> = butterflies.iter().map(|butterfly| butterfly.len()).collect();
+        let twiddle_factors = radix_n_twiddles(&radices, inverse);
+
+        Ok(RadixN {
+            butterflies: butterflies.into_boxed_slice(),
+            twiddles: twiddle_factors.into_boxed_slice(),
+            len,
+            inverse,
+        })
+    }
+
+    /// Returns the size of each of this algorithm's butterflies, in the order they're applied
+    pub fn radices(&self) -> Vec<usize> {
+        self.butterflies.iter().map(|butterfly| butterfly.len()).collect()
+    }
+
+    fn perform_fft(&self, signal: &mut [Complex<T>], spectrum: &mut [Complex<T>]) {
+        // reorder the input and apply the first stage's butterflies
+        prepare_radix_n(signal, spectrum, 1, &self.butterflies);
+
+        // now that the input has been reordered, we can use it as scratch space for the remaining stages
+        let mut sub_len = self.butterflies[0].len();
+        let mut stage_twiddles: &[Complex<T>] = &self.twiddles;
+        for butterfly in &self.butterflies[1..] {
+            let radix = butterfly.len();
+            assert!(stage_twiddles.len() >= sub_len * (radix - 1));
+            unsafe { butterfly.process_stage_inplace(spectrum, stage_twiddles, sub_len) };
+
+            //skip past all the twiddle factors used in this stage
+            stage_twiddles = &stage_twiddles[sub_len * (radix - 1)..];
+            sub_len *= radix;
+        }
+    }
+}
+
+/// Computes the twiddle factors used by every stage after the first, for a RadixN algorithm whose butterflies have the
+/// sizes in `radices`
+pub fn radix_n_twiddles<T: FFTnum>(radices: &[usize], inverse: bool) -> Vec<Complex<T>> {
+    // the stage with radix r after sub-FFTs of size m computes m butterflies, and multiplies input t of butterfly i
+    // by twiddle(i * t, m * r)
+    let len = radices.iter().product();
+    let mut twiddle_factors = Vec::with_capacity(len);
+    let mut sub_len = radices[0];
+    for &radix in &radices[1..] {
+        let twiddle_stride = len / (sub_len * radix);
+        for i in 0..sub_len {
+            for t in 1..radix {
+                twiddle_factors.push(twiddles::single_twiddle(i * t * twiddle_stride, len, inverse));
+            }
+        }
+        sub_len *= radix;
+    }
+    twiddle_factors
+}
+
+/// Copies `signal` into `spectrum` in digit-reversed order, so that each of the last butterfly's sub-FFTs is contiguous,
+/// and applies the first butterfly to each of the contiguous chunks at the bottom of the recursion
+fn prepare_radix_n<T: FFTnum>(signal: &[Complex<T>],
+                              spectrum: &mut [Complex<T>],
+                              stride: usize,
+                              butterflies: &[Arc<FFTButterfly<T>>]) {
+    let (last_butterfly, inner_butterflies) = butterflies.split_last().unwrap();
+    let radix = last_butterfly.len();
+
+    if inner_butterflies.is_empty() {
+        assert!(spectrum.len() == radix && signal.len() > (radix - 1) * stride);
+        unsafe {
+            for i in 0..radix {
+                *spectrum.get_unchecked_mut(i) = *signal.get_unchecked(i * stride);
+            }
+            last_butterfly.process_inplace(spectrum);
+        }
+    } else {
+        // sub-FFT j of the last stage contains the elements at j, j + radix, j + 2 * radix, ...
+        let sub_len = spectrum.len() / radix;
+        for (j, sub_spectrum) in spectrum.chunks_mut(sub_len).enumerate() {
+            prepare_radix_n(&signal[j * stride..], sub_spectrum, stride * radix, inner_butterflies);
+        }
+    }
+}
+
+impl<T: FFTnum> FFT<T> for RadixN<T> {
+    fn process(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) {
+        verify_length(input, output, self.len());
+
+        self.perform_fft(input, output);
+    }
+    fn process_multi(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) {
+        verify_length_divisible(input, output, self.len());
+
+        for (in_chunk, out_chunk) in input.chunks_mut(self.len()).zip(output.chunks_mut(self.len())) {
+            self.perform_fft(in_chunk, out_chunk);
+        }
+    }
+}
+impl<T> Length for RadixN<T> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+impl<T> IsInverse for RadixN<T> {
+    #[inline(always)]
+    fn is_inverse(&self) -> bool {
+        self.inverse
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use test_utils::{check_fft_algorithm, make_butterfly};
+
+    #[test]
+    fn test_radix_n() {
+        let radix_lists: &[&[usize]] = &[
+            &[2],
+            &[7],
+            &[2, 3],
+            &[4, 4, 4],
+            &[3, 3, 3, 3, 3],
+            &[5, 5, 5],
+            &[8, 9, 3, 5],
+            &[7, 11, 2],
+            &[13, 4, 17],
+            &[16, 2, 2],
+        ];
+        for radices in radix_lists {
+            test_radix_n_with_radices(radices, false);
+            test_radix_n_with_radices(radices, true);
+        }
+    }
+
+    #[test]
+    fn test_radix_n_try_new() {
+        assert_eq!(RadixN::<f32>::try_new(Vec::new()).err(), Some(FftError::NoButterflies));
+
+        let mismatched = vec![make_butterfly(3, false), make_butterfly(4, true)];
+        assert_eq!(RadixN::try_new(mismatched).err(),
+                   Some(FftError::MismatchedDirection { width_inverse: false, height_inverse: true }));
+    }
+
+    fn test_radix_n_with_radices(radices: &[usize], inverse: bool) {
+        let butterflies: Vec<Arc<FFTButterfly<f32>>> = radices.iter().map(|&radix| make_butterfly(radix, inverse)).collect();
+        let fft = RadixN::new(butterflies);
+        assert_eq!(fft.radices(), radices);
+
+        let len = radices.iter().product();
+        check_fft_algorithm(&fft, len, inverse);
+    }
+}
@@ -0,0 +1,198 @@
+use alloc::vec::Vec;
+use alloc::boxed::Box;
+
+use num_complex::Complex;
+
+use common::{FFTnum, verify_length, verify_length_divisible};
+
+use algorithm::butterflies::{Butterfly2, Butterfly4, Butterfly8, Butterfly16, FFTButterfly};
+use ::{Length, IsInverse, FFT, FftError};
+use twiddles;
+
+/// FFT algorithm for power-of-two sizes, using the split-radix decomposition
+///
+/// Each step of this algorithm splits a size n FFT into one FFT of size n/2 for the even-indexed elements, and two
+/// FFTs of size n/4 for the odd-indexed elements. This takes fewer arithmetic operations than any other power-of-two
+/// algorithm, and unlike [`Radix4`](struct.Radix4.html), computing the sub-FFTs depth-first keeps the data for each
+/// of them in the cache until it's done.
+///
+/// In practice, it's still slower than Radix4, so the planner never uses it. To use it, create it directly.
+///
+/// ~~~
+/// // Computes a forward FFT of size 4096
+/// use rustfft::algorithm::SplitRadix;
+/// use rustfft::FFT;
+/// use rustfft::num_complex::Complex;
+/// use rustfft::num_traits::Zero;
+///
+/// let mut input:  Vec<Complex<f32>> = vec![Zero::zero(); 4096];
+/// let mut output: Vec<Complex<f32>> = vec![Zero::zero(); 4096];
+///
+/// let fft = SplitRadix::new(4096, false);
+/// fft.process(&mut input, &mut output);
+/// ~~~
+pub struct SplitRadix<T> {
+    // for every step of size n >= 32, n/4 pairs of twiddle factors w^k and w^3k, starting with the smallest step.
+    // the step of size n starts at index n/4 - 8
+    twiddles: Box<[[Complex<T>; 2]]>,
+    butterfly8: Butterfly8<T>,
+    butterfly16: Butterfly16<T>,
+    len: usize,
+    inverse: bool,
+}
+
+impl<T: FFTnum> SplitRadix<T> {
+    /// Preallocates necessary arrays and precomputes necessary data to efficiently compute the power-of-two FFT
+    ///
+    /// Panics if `len` is not a power of two
+    pub fn new(len: usize, inverse: bool) -> Self {
+        Self::try_new(len, inverse).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Same as [`new`](#method.new), except that if `len` is not a power of two, this returns an
+    /// [`FftError`](../enum.FftError.html) instead of panicking
+    pub fn try_new(len: usize, inverse: bool) -> Result<Self, FftError> {
+        if !len.is_power_of_two() {
+            return Err(FftError::NotPowerOfTwo { len });
+        }
+
+        let mut twiddle_factors = Vec::with_capacity(len / 2);
+        let mut step_len = 32;
+        while step_len <= len {
+            let stride = len / step_len;
+            for k in 0..step_len / 4 {
+                twiddle_factors.push([twiddles::single_twiddle(k * stride, len, inverse),
+                                      twiddles::single_twiddle(3 * k * stride, len, inverse)]);
+            }
+            step_len *= 2;
+        }
+
+        Ok(SplitRadix {
+            twiddles: twiddle_factors.into_boxed_slice(),
+            butterfly8: Butterfly8::new(inverse),
+            butterfly16: Butterfly16::new(inverse),
+            len,
+            inverse,
+        })
+    }
+
+    fn perform_fft(&self, signal: &[Complex<T>], spectrum: &mut [Complex<T>]) {
+        match self.len() {
+            0|1 => spectrum.copy_from_slice(signal),
+            2 => {
+                spectrum.copy_from_slice(signal);
+                unsafe { Butterfly2::new(self.inverse).process_inplace(spectrum) }
+            },
+            4 => {
+                spectrum.copy_from_slice(signal);
+                unsafe { Butterfly4::new(self.inverse).process_inplace(spectrum) }
+            },
+            _ => self.perform_step(signal, spectrum, 1),
+        }
+    }
+
+    /// Computes the FFT of the `spectrum.len()` elements of `signal` that are `stride` apart, writing it to `spectrum`
+    fn perform_step(&self, signal: &[Complex<T>], spectrum: &mut [Complex<T>], stride: usize) {
+        let len = spectrum.len();
+
+        // every step of size 32 or more splits into steps of size 8 or more, so we only need those two base cases
+        if len <= 16 {
+            assert!(signal.len() > (len - 1) * stride);
+            unsafe {
+                for i in 0..len {
+                    *spectrum.get_unchecked_mut(i) = *signal.get_unchecked(i * stride);
+                }
+                if len == 16 {
+                    self.butterfly16.process_inplace(spectrum);
+                } else {
+                    self.butterfly8.process_inplace(spectrum);
+                }
+            }
+            return;
+        }
+
+        let quarter_len = len / 4;
+        let (evens, odds) = spectrum.split_at_mut(len / 2);
+        let (odds_n1, odds_n3) = odds.split_at_mut(quarter_len);
+
+        // compute the FFT of the elements at 2k, 4k + 1, and 4k + 3
+        self.perform_step(signal, evens, stride * 2);
+        self.perform_step(&signal[stride..], odds_n1, stride * 4);
+        self.perform_step(&signal[3 * stride..], odds_n3, stride * 4);
+
+        // combine them with a size-4 butterfly, using the even FFT's first and second halves as two of its inputs
+        let (evens_lo, evens_hi) = evens.split_at_mut(quarter_len);
+        let twiddles = &self.twiddles[quarter_len - 8..len / 2 - 8];
+        for ((((even_lo, even_hi), odd_n1), odd_n3), twiddle) in evens_lo.iter_mut()
+            .zip(evens_hi.iter_mut())
+            .zip(odds_n1.iter_mut())
+            .zip(odds_n3.iter_mut())
+            .zip(twiddles.iter())
+        {
+            let n1 = *odd_n1 * twiddle[0];
+            let n3 = *odd_n3 * twiddle[1];
+            let sum = n1 + n3;
+            let difference = twiddles::rotate_90(n1 - n3, self.inverse);
+
+            *odd_n1 = *even_lo - sum;
+            *even_lo = *even_lo + sum;
+            *odd_n3 = *even_hi - difference;
+            *even_hi = *even_hi + difference;
+        }
+    }
+}
+
+impl<T: FFTnum> FFT<T> for SplitRadix<T> {
+    fn process(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) {
+        verify_length(input, output, self.len());
+
+        self.perform_fft(input, output);
+    }
+    fn process_multi(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) {
+        verify_length_divisible(input, output, self.len());
+
+        for (in_chunk, out_chunk) in input.chunks_mut(self.len()).zip(output.chunks_mut(self.len())) {
+            self.perform_fft(in_chunk, out_chunk);
+        }
+    }
+}
+impl<T> Length for SplitRadix<T> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+impl<T> IsInverse for SplitRadix<T> {
+    #[inline(always)]
+    fn is_inverse(&self) -> bool {
+        self.inverse
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use test_utils::check_fft_algorithm;
+
+    #[test]
+    fn test_split_radix() {
+        for pow in 0..10 {
+            let len = 1 << pow;
+            test_split_radix_with_length(len, false);
+            test_split_radix_with_length(len, true);
+        }
+    }
+
+    #[test]
+    fn test_split_radix_try_new() {
+        for &len in &[0, 3, 12, 100] {
+            assert_eq!(SplitRadix::<f32>::try_new(len, false).err(), Some(FftError::NotPowerOfTwo { len }));
+        }
+    }
+
+    fn test_split_radix_with_length(len: usize, inverse: bool) {
+        let fft = SplitRadix::new(len, inverse);
+
+        check_fft_algorithm(&fft, len, inverse);
+    }
+}
@@ -0,0 +1,302 @@
+use alloc::vec::Vec;
+use alloc::boxed::Box;
+
+use num_complex::Complex;
+use num_traits::Zero;
+
+use common::{FFTnum, verify_length, verify_length_divisible};
+
+use algorithm::butterflies::{Butterfly2, Butterfly3, Butterfly4, Butterfly5, Butterfly8, FFTButterfly};
+use ::{Length, IsInverse, FFT, FftError};
+use twiddles;
+
+/// Self-sorting FFT algorithm for sizes whose only prime factors are 2, 3, and 5
+///
+/// This algorithm splits the FFT into one stage per radix, using radices of 2, 3, 4, 5, and 8. Each stage reads from
+/// one buffer and writes to the other, with unit-stride access to both, in an order that leaves the output sorted. So
+/// unlike [`Radix4`](struct.Radix4.html), it doesn't need a bit-reversal pass to reorder the input first.
+///
+/// ~~~
+/// // Computes a forward FFT of size 1200
+/// use rustfft::algorithm::Stockham;
+/// use rustfft::FFT;
+/// use rustfft::num_complex::Complex;
+/// use rustfft::num_traits::Zero;
+///
+/// let mut input:  Vec<Complex<f32>> = vec![Zero::zero(); 1200];
+/// let mut output: Vec<Complex<f32>> = vec![Zero::zero(); 1200];
+///
+/// let fft = Stockham::new(1200, false);
+/// fft.process(&mut input, &mut output);
+/// ~~~
+pub struct Stockham<T> {
+    // the radix of each stage, in the order they're applied
+    radices: Box<[usize]>,
+
+    // for each stage, the twiddle factors for each of its butterflies, stored one butterfly after another
+    twiddles: Box<[Complex<T>]>,
+
+    butterfly2: Butterfly2,
+    butterfly3: Butterfly3<T>,
+    butterfly4: Butterfly4,
+    butterfly5: Butterfly5<T>,
+    butterfly8: Butterfly8<T>,
+
+    len: usize,
+    inverse: bool,
+}
+
+impl<T: FFTnum> Stockham<T> {
+    /// Preallocates necessary arrays and precomputes necessary data to efficiently compute the FFT
+    ///
+    /// Panics if `len` has a prime factor other than 2, 3, or 5
+    pub fn new(len: usize, inverse: bool) -> Self {
+        Self::try_new(len, inverse).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Same as [`new`](#method.new), except that if `len` has a prime factor other than 2, 3, or 5, this returns an
+    /// [`FftError`](../enum.FftError.html) instead of panicking
+    pub fn try_new(len: usize, inverse: bool) -> Result<Self, FftError> {
+        let radices = compute_radices(len).ok_or(FftError::NotSmooth { len })?;
+
+        // stage i computes FFTs of size `len / stride`, where `stride` is the product of the previous stages' radices.
+        // each of its `len / (stride * radix)` butterflies multiplies output t by twiddle(butterfly_index * t)
+        let mut twiddle_factors = Vec::with_capacity(len);
+        let mut stride = 1;
+        for &radix in &radices {
+            let num_butterflies = len / (stride * radix);
+            for i in 0..num_butterflies {
+                for t in 1..radix {
+                    twiddle_factors.push(twiddles::single_twiddle(i * t * stride, len, inverse));
+                }
+            }
+            stride *= radix;
+        }
+
+        Ok(Stockham {
+            radices: radices.into_boxed_slice(),
+            twiddles: twiddle_factors.into_boxed_slice(),
+
+            butterfly2: Butterfly2::new(inverse),
+            butterfly3: Butterfly3::new(inverse),
+            butterfly4: Butterfly4::new(inverse),
+            butterfly5: Butterfly5::new(inverse),
+            butterfly8: Butterfly8::new(inverse),
+
+            len,
+            inverse,
+        })
+    }
+
+    fn perform_fft(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) {
+        // the stages ping-pong between the two buffers, starting with input -> output, so with an odd number of stages
+        // the last one writes to output. the last stage has a single butterfly, which reads exactly the elements it
+        // writes, so with an even number of stages, it runs in-place on output instead
+        let num_stages = self.radices.len();
+        let inplace_stage = if num_stages % 2 == 0 { num_stages.checked_sub(1) } else { None };
+
+        let mut stride = 1;
+        let mut twiddles: &[Complex<T>] = &self.twiddles;
+        for (stage, &radix) in self.radices.iter().enumerate() {
+            if Some(stage) == inplace_stage {
+                unsafe {
+                    match radix {
+                        2 => perform_final_stage_inplace(&self.butterfly2, output, stride),
+                        3 => perform_final_stage_inplace(&self.butterfly3, output, stride),
+                        4 => perform_final_stage_inplace(&self.butterfly4, output, stride),
+                        5 => perform_final_stage_inplace(&self.butterfly5, output, stride),
+                        8 => perform_final_stage_inplace(&self.butterfly8, output, stride),
+                        _ => unreachable!(),
+                    }
+                }
+                break;
+            }
+
+            let (source, destination) = if stage % 2 == 0 {
+                (&*input, &mut *output)
+            } else {
+                (&*output, &mut *input)
+            };
+
+            unsafe {
+                match radix {
+                    2 => perform_stage(&self.butterfly2, source, destination, twiddles, stride),
+                    3 => perform_stage(&self.butterfly3, source, destination, twiddles, stride),
+                    4 => perform_stage(&self.butterfly4, source, destination, twiddles, stride),
+                    5 => perform_stage(&self.butterfly5, source, destination, twiddles, stride),
+                    8 => perform_stage(&self.butterfly8, source, destination, twiddles, stride),
+                    _ => unreachable!(),
+                }
+            }
+
+            // skip past the twiddle factors used in this stage
+            twiddles = &twiddles[(self.len / stride) / radix * (radix - 1)..];
+            stride *= radix;
+        }
+
+        // with no stages at all, the FFT of size 1 just copies its input
+        if num_stages == 0 {
+            output.copy_from_slice(input);
+        }
+    }
+}
+
+/// Performs one stage of the algorithm: For each butterfly index i and each offset q < `stride`, computes a FFT of
+/// the `radix` elements of `source` at `q + stride * (i + r * num_butterflies)`, multiplies output t by twiddle i * t,
+/// and writes it to `destination[q + stride * (i * radix + t)]`
+unsafe fn perform_stage<T: FFTnum, B: FFTButterfly<T>>(butterfly: &B,
+                                                     source: &[Complex<T>],
+                                                     destination: &mut [Complex<T>],
+                                                     twiddles: &[Complex<T>],
+                                                     stride: usize) {
+    let radix = butterfly.len();
+    let num_butterflies = source.len() / (stride * radix);
+    assert!(destination.len() == source.len() && twiddles.len() >= num_butterflies * (radix - 1));
+
+    let mut scratch = [Zero::zero(); 8];
+    let scratch = &mut scratch[..radix];
+    for i in 0..num_butterflies {
+        let butterfly_twiddles = &twiddles[i * (radix - 1)..];
+        for q in 0..stride {
+            for (r, element) in scratch.iter_mut().enumerate() {
+                *element = *source.get_unchecked(q + stride * (i + r * num_butterflies));
+            }
+
+            butterfly.process_inplace(scratch);
+
+            let output_index = q + stride * i * radix;
+            *destination.get_unchecked_mut(output_index) = scratch[0];
+            for (t, (&element, &twiddle)) in scratch[1..].iter().zip(butterfly_twiddles.iter()).enumerate() {
+                *destination.get_unchecked_mut(output_index + stride * (t + 1)) = element * twiddle;
+            }
+        }
+    }
+}
+
+/// Performs the last stage of the algorithm in-place. It has a single butterfly, whose twiddle factors are all 1, so
+/// for each offset q < `stride`, this computes a FFT of the `radix` elements of `buffer` at `q + stride * r`, and
+/// writes it back to the same elements
+unsafe fn perform_final_stage_inplace<T: FFTnum, B: FFTButterfly<T>>(butterfly: &B,
+                                                                    buffer: &mut [Complex<T>],
+                                                                    stride: usize) {
+    let radix = butterfly.len();
+    assert!(buffer.len() == radix * stride);
+
+    let mut scratch = [Zero::zero(); 8];
+    let scratch = &mut scratch[..radix];
+    for q in 0..stride {
+        for (r, element) in scratch.iter_mut().enumerate() {
+            *element = *buffer.get_unchecked(q + stride * r);
+        }
+
+        butterfly.process_inplace(scratch);
+
+        for (t, &element) in scratch.iter().enumerate() {
+            *buffer.get_unchecked_mut(q + stride * t) = element;
+        }
+    }
+}
+
+/// Splits `len` into the radices of the algorithm's stages, or returns None if `len` has a prime factor other than
+/// 2, 3, or 5
+fn compute_radices(len: usize) -> Option<Vec<usize>> {
+    if len == 0 {
+        return None;
+    }
+
+    // use as many radix-8 stages as possible, since they do the most work per pass over the data
+    let mut radices = Vec::new();
+    let mut twos = len.trailing_zeros();
+    while twos > 4 || twos == 3 {
+        radices.push(8);
+        twos -= 3;
+    }
+    match twos {
+        4 => radices.extend_from_slice(&[4, 4]),
+        2 => radices.push(4),
+        1 => radices.push(2),
+        _ => {},
+    }
+
+    let mut remaining = len >> len.trailing_zeros();
+    for &radix in &[5, 3] {
+        while remaining % radix == 0 {
+            radices.push(radix);
+            remaining /= radix;
+        }
+    }
+
+    if remaining == 1 {
+        Some(radices)
+    } else {
+        None
+    }
+}
+
+impl<T: FFTnum> FFT<T> for Stockham<T> {
+    fn process(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) {
+        verify_length(input, output, self.len());
+
+        self.perform_fft(input, output);
+    }
+    fn process_multi(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) {
+        verify_length_divisible(input, output, self.len());
+
+        for (in_chunk, out_chunk) in input.chunks_mut(self.len()).zip(output.chunks_mut(self.len())) {
+            self.perform_fft(in_chunk, out_chunk);
+        }
+    }
+}
+impl<T> Length for Stockham<T> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+impl<T> IsInverse for Stockham<T> {
+    #[inline(always)]
+    fn is_inverse(&self) -> bool {
+        self.inverse
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use test_utils::check_fft_algorithm;
+
+    #[test]
+    fn test_stockham() {
+        for len in 1..200 {
+            if compute_radices(len).is_some() {
+                test_stockham_with_length(len, false);
+                test_stockham_with_length(len, true);
+            }
+        }
+        for &len in &[256, 512, 1024, 1200, 2048, 3375] {
+            test_stockham_with_length(len, false);
+        }
+    }
+
+    #[test]
+    fn test_compute_radices() {
+        assert_eq!(compute_radices(1), Some(vec![]));
+        assert_eq!(compute_radices(16), Some(vec![4, 4]));
+        assert_eq!(compute_radices(32), Some(vec![8, 4]));
+        assert_eq!(compute_radices(1024), Some(vec![8, 8, 4, 4]));
+        assert_eq!(compute_radices(1200), Some(vec![4, 4, 5, 5, 3]));
+    }
+
+    #[test]
+    fn test_stockham_try_new() {
+        for &len in &[0, 7, 22, 1201] {
+            assert_eq!(Stockham::<f32>::try_new(len, false).err(), Some(FftError::NotSmooth { len }));
+        }
+    }
+
+    fn test_stockham_with_length(len: usize, inverse: bool) {
+        let fft = Stockham::new(len, inverse);
+
+        check_fft_algorithm(&fft, len, inverse);
+    }
+}
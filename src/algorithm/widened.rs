@@ -0,0 +1,209 @@
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use num_complex::Complex;
+use num_traits::Zero;
+
+use common::{FFTnum, verify_length, verify_length_divisible};
+
+use ::{Length, IsInverse, FFT};
+
+/// Computes FFTs of a low-precision type, like `half::f16` or `half::bf16`, with an inner f32 FFT
+///
+/// Half-precision types only have 11 (f16) or 8 (bf16) bits of precision, so an algorithm that computes in them
+/// directly rounds the data at every step, and its twiddle factors are rounded as well. This algorithm converts the
+/// input to f32, computes the inner FFT, and converts the result back, so the result is only rounded once. Since
+/// half-precision arithmetic is emulated with f32 anyway, this is also faster than computing in half precision.
+///
+/// The planner uses this algorithm automatically for `half::f16` and `half::bf16`, so it only needs to be created
+/// manually to choose the inner FFT.
+///
+/// Since the inner FFT can't use the half-precision buffers as scratch space, every call allocates a f32 buffer twice as
+/// long as its input, which holds the widened input and output. `process_multi` widens its whole batch at once, so it
+/// only allocates once for all of its FFTs: to compute many FFTs of the same size, pass them to `process_multi`
+/// together, rather than calling `process` for each one.
+///
+/// ~~~
+/// // Computes a forward FFT of size 1200, in f16
+/// # extern crate rustfft;
+/// # extern crate half;
+/// # fn main() {
+/// use half::f16;
+/// use rustfft::algorithm::Widened;
+/// use rustfft::{FFT, FFTplanner};
+/// use rustfft::num_complex::Complex;
+/// use rustfft::num_traits::Zero;
+///
+/// let mut input:  Vec<Complex<f16>> = vec![Zero::zero(); 1200];
+/// let mut output: Vec<Complex<f16>> = vec![Zero::zero(); 1200];
+///
+/// let mut planner = FFTplanner::<f32>::new(false);
+/// let inner_fft = planner.plan_fft(1200);
+///
+/// let fft = Widened::new(inner_fft);
+/// fft.process(&mut input, &mut output);
+/// # }
+/// ~~~
+pub struct Widened<T> {
+    inner_fft: Arc<FFT<f32>>,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: FFTnum + Into<f32>> Widened<T> {
+    /// Creates a FFT instance which will process inputs/outputs of size `inner_fft.len()`
+    pub fn new(inner_fft: Arc<FFT<f32>>) -> Self {
+        Widened {
+            inner_fft,
+            _phantom: PhantomData,
+        }
+    }
+
+    fn perform_fft(&self, signal: &[Complex<T>], spectrum: &mut [Complex<T>], multi: bool) {
+        // a single allocation, for the whole batch, holds both the widened signal and the inner FFT's output
+        let mut scratch: Vec<Complex<f32>> = Vec::with_capacity(signal.len() * 2);
+        scratch.extend(signal.iter().map(|element| Complex::new(element.re.into(), element.im.into())));
+        scratch.resize(signal.len() * 2, Complex::zero());
+        let (wide_signal, wide_spectrum) = scratch.split_at_mut(signal.len());
+
+        if multi {
+            self.inner_fft.process_multi(wide_signal, wide_spectrum);
+        } else {
+            self.inner_fft.process(wide_signal, wide_spectrum);
+        }
+
+        for (element, wide_element) in spectrum.iter_mut().zip(wide_spectrum.iter()) {
+            *element = Complex::new(T::from_f32(wide_element.re).unwrap(), T::from_f32(wide_element.im).unwrap());
+        }
+    }
+}
+
+impl<T: FFTnum + Into<f32>> FFT<T> for Widened<T> {
+    fn process(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) {
+        verify_length(input, output, self.len());
+
+        self.perform_fft(input, output, false);
+    }
+    fn process_multi(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) {
+        verify_length_divisible(input, output, self.len());
+
+        self.perform_fft(input, output, true);
+    }
+}
+impl<T> Length for Widened<T> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.inner_fft.len()
+    }
+}
+impl<T> IsInverse for Widened<T> {
+    #[inline(always)]
+    fn is_inverse(&self) -> bool {
+        self.inner_fft.is_inverse()
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use half::{f16, bf16};
+    use num_traits::ToPrimitive;
+    use algorithm::{DFT, Radix4};
+    use test_utils::random_signal;
+    use FFTplanner;
+
+    #[test]
+    fn test_widened_f16() {
+        for &len in &[1, 2, 7, 16, 60, 97, 256, 1000, 1024] {
+            for &inverse in &[false, true] {
+                let error = widened_error::<f16>(len, inverse);
+                assert!(error < 5e-4, "len = {}, inverse = {}, relative error = {}", len, inverse, error);
+            }
+        }
+    }
+
+    #[test]
+    fn test_widened_bf16() {
+        for &len in &[1, 2, 7, 16, 60, 97, 256, 1000, 1024] {
+            for &inverse in &[false, true] {
+                let error = widened_error::<bf16>(len, inverse);
+                assert!(error < 4e-3, "len = {}, inverse = {}, relative error = {}", len, inverse, error);
+            }
+        }
+    }
+
+    #[test]
+    fn test_widened_vs_half_arithmetic() {
+        // computing in half precision rounds after every stage, so it should be noticeably worse
+        let len = 1024;
+        for &inverse in &[false, true] {
+            let input = half_signal::<f16>(len);
+            let expected = reference_fft(&input, inverse);
+
+            let half_fft = Radix4::<f16>::new(len, inverse);
+            let half_error = relative_error(&half_fft, &input, &expected);
+
+            let widened_error = widened_error::<f16>(len, inverse);
+            assert!(widened_error * 2f64 < half_error, "widened error = {}, half error = {}", widened_error, half_error);
+        }
+    }
+
+    #[test]
+    fn test_widened_planner() {
+        // the planner should plan half-precision FFTs in f32, and still reuse instances
+        let mut planner = FFTplanner::<f16>::new(false);
+        let fft = planner.plan_fft(60);
+        assert_eq!(fft.len(), 60);
+        assert!(Arc::ptr_eq(&fft, &planner.plan_fft(60)));
+
+        let input = half_signal::<f16>(60);
+        let expected = reference_fft(&input, false);
+        assert!(relative_error(&*fft, &input, &expected) < 1e-3);
+
+        let mut multi_input = [input.clone(), input.clone()].concat();
+        let mut multi_output = vec![Zero::zero(); 120];
+        fft.process_multi(&mut multi_input, &mut multi_output);
+        assert_eq!(&multi_output[..60], &multi_output[60..]);
+    }
+
+    /// Returns the RMS error of a Widened FFT of a random signal, relative to the RMS of the f64 FFT of the same signal
+    fn widened_error<T: FFTnum + Into<f32> + ToPrimitive>(len: usize, inverse: bool) -> f64 {
+        let input = half_signal::<T>(len);
+        let expected = reference_fft(&input, inverse);
+
+        let fft = Widened::new(Arc::new(DFT::new(len, inverse)));
+        assert_eq!(fft.len(), len);
+        assert_eq!(fft.is_inverse(), inverse);
+        relative_error(&fft, &input, &expected)
+    }
+
+    fn half_signal<T: FFTnum>(len: usize) -> Vec<Complex<T>> {
+        random_signal(len).iter()
+            .map(|value| Complex::new(T::from_f32(value.re).unwrap(), T::from_f32(value.im).unwrap()))
+            .collect()
+    }
+
+    fn reference_fft<T: FFTnum + ToPrimitive>(input: &[Complex<T>], inverse: bool) -> Vec<Complex<f64>> {
+        let mut wide_input: Vec<Complex<f64>> = input.iter()
+            .map(|value| Complex::new(value.re.to_f64().unwrap(), value.im.to_f64().unwrap()))
+            .collect();
+        let mut expected = vec![Zero::zero(); input.len()];
+        DFT::new(input.len(), inverse).process(&mut wide_input, &mut expected);
+        expected
+    }
+
+    fn relative_error<T: FFTnum + ToPrimitive>(fft: &FFT<T>, input: &[Complex<T>], expected: &[Complex<f64>]) -> f64 {
+        let mut input = input.to_vec();
+        let mut output = vec![Zero::zero(); input.len()];
+        fft.process(&mut input, &mut output);
+
+        let mut error = 0f64;
+        let mut magnitude = 0f64;
+        for (actual, expected) in output.iter().zip(expected.iter()) {
+            let actual = Complex::new(actual.re.to_f64().unwrap(), actual.im.to_f64().unwrap());
+            error += (actual - expected).norm_sqr();
+            magnitude += expected.norm_sqr();
+        }
+        (error / magnitude).sqrt()
+    }
+}
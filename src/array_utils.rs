@@ -18,6 +18,7 @@ pub unsafe fn transpose_small<T: Copy>(width: usize, height: usize, input: &[T],
 #[cfg(test)]
 mod unit_tests {
     use super::*;
+    use alloc::vec::Vec;
     use test_utils::random_signal;
     use num_complex::Complex;
     use num_traits::Zero;
@@ -1,21 +1,62 @@
 use num_traits::{FromPrimitive, Signed};
 
-/// Generic floating point number, implemented for f32 and f64
+use error::FftError;
+
+/// Generic floating point number, implemented for f32 and f64, and with the `half` feature, for `half::f16` and
+/// `half::bf16`
 pub trait FFTnum: Copy + FromPrimitive + Signed + Sync + Send + 'static {}
 
 impl FFTnum for f32 {}
 impl FFTnum for f64 {}
+#[cfg(feature = "half")]
+impl FFTnum for ::half::f16 {}
+#[cfg(feature = "half")]
+impl FFTnum for ::half::bf16 {}
 
 
 #[inline(always)]
 pub fn verify_length<T>(input: &[T], output: &[T], expected: usize) {
-	assert_eq!(input.len(), expected, "Input is the wrong length. Expected {}, got {}", expected, input.len());
-	assert_eq!(output.len(), expected, "Output is the wrong length. Expected {}, got {}", expected, output.len());
+	if let Err(error) = check_length(input, output, expected) {
+		panic!("{}", error);
+	}
 }
 
 
 #[inline(always)]
 pub fn verify_length_divisible<T>(input: &[T], output: &[T], expected: usize) {
-	assert_eq!(input.len() % expected, 0, "Input is the wrong length. Expected multiple of {}, got {}", expected, input.len());
-	assert_eq!(input.len(), output.len(), "Input and output must have the same length. Expected {}, got {}", input.len(), output.len());
+	if let Err(error) = check_length_divisible(input, output, expected) {
+		panic!("{}", error);
+	}
+}
+
+
+#[inline(always)]
+pub fn check_length<T>(input: &[T], output: &[T], expected: usize) -> Result<(), FftError> {
+	if input.len() != expected {
+		Err(FftError::InputLength { expected, actual: input.len() })
+	} else if output.len() != expected {
+		Err(FftError::OutputLength { expected, actual: output.len() })
+	} else {
+		Ok(())
+	}
+}
+
+
+#[inline(always)]
+pub fn check_length_divisible<T>(input: &[T], output: &[T], expected: usize) -> Result<(), FftError> {
+	// the only multiple of zero is zero, and checking the remainder would divide by zero
+	let is_multiple = if expected == 0 { input.is_empty() } else { input.len() % expected == 0 };
+	if !is_multiple {
+		Err(FftError::NotMultiple { fft_len: expected, actual: input.len() })
+	} else if input.len() != output.len() {
+		Err(FftError::MismatchedBuffers { input_len: input.len(), output_len: output.len() })
+	} else {
+		Ok(())
+	}
+}
+
+
+#[inline(always)]
+pub fn check_product_len(width: usize, height: usize) -> Result<usize, FftError> {
+	width.checked_mul(height).ok_or(FftError::LengthOverflow { width, height })
 }
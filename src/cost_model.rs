@@ -0,0 +1,163 @@
+use core::cmp;
+
+/// Estimates how long each FFT algorithm takes to run, so that the planner can choose between them
+///
+/// When planning a FFT of a given size, the [`FFTplanner`](struct.FFTplanner.html) considers every way of building
+/// it from the available algorithms: Each way of splitting the size into two factors for MixedRadix, FourStep, and
+/// Good-Thomas, Radix4 for powers of two, Stockham for sizes whose only prime factors are 2, 3, and 5, RadixN for sizes
+/// whose prime factors all have butterflies, PrimePower for powers of a single prime, Rader's Algorithm for primes, both
+/// with an inner FFT of size `len - 1` and zero padded to a power of two or a size whose only prime factors are 2, 3,
+/// and 5, hardcoded butterflies, and the naive DFT. It then chooses the plan with the lowest total estimated cost.
+///
+/// The estimates for algorithms that contain inner FFTs should only include the cost of the algorithm itself. The
+/// planner adds the cost of the inner FFTs, so for example, the total cost of a MixedRadix plan is
+/// `mixed_radix(width, height) + height * cost(width) + width * cost(height)`. Similarly, a RadixN plan adds
+/// `len / radix * butterfly(radix)` for each of its butterflies, and a PrimePower plan adds
+/// `exponent * len / radix * cost(radix)`.
+///
+/// The units don't matter, as long as every method uses the same ones. Every method has a default implementation,
+/// which is the estimate used by [`DefaultCostModel`](struct.DefaultCostModel.html), so implementors only need to
+/// override the methods they have better estimates for.
+///
+/// ~~~
+/// // Plan FFTs with a cost model that strongly prefers to avoid Rader's Algorithm
+/// use std::sync::Arc;
+/// use rustfft::{FFTplanner, CostModel};
+///
+/// struct AvoidRaders;
+/// impl CostModel for AvoidRaders {
+///     fn raders(&self, len: usize) -> f64 {
+///         100.0 * len as f64
+///     }
+///     fn raders_zero_padded(&self, len: usize, inner_len: usize) -> f64 {
+///         100.0 * (len + inner_len) as f64
+///     }
+/// }
+///
+/// let mut planner = FFTplanner::<f32>::new(false);
+/// planner.set_cost_model(Arc::new(AvoidRaders));
+/// let fft = planner.plan_fft(1201);
+/// ~~~
+///
+/// The `tune_cost_model` example times each algorithm next to its default estimate, which can be used to check the
+/// estimates on a particular machine, or to tune a custom cost model.
+pub trait CostModel: Send + Sync {
+    /// The estimated cost of the naive O(n^2) DFT algorithm of size `len`
+    fn dft(&self, len: usize) -> f64 {
+        2.0 * len as f64 * len as f64
+    }
+
+    /// The estimated cost of a hardcoded butterfly of size `len`
+    fn butterfly(&self, len: usize) -> f64 {
+        len as f64 * log2(len)
+    }
+
+    /// The estimated cost of the Radix4 algorithm of size `len`, which is always a power of two
+    fn radix4(&self, len: usize) -> f64 {
+        // Radix4 makes log4(len) passes over the whole buffer, so once the buffer doesn't fit in the cache,
+        // every pass is dominated by memory access
+        let cache_penalty = if len > DEFAULT_CACHE_LEN { 1.5 } else { 1.0 };
+        cache_penalty * len as f64 * log2(len)
+    }
+
+    /// The estimated cost of the Stockham algorithm of size `len`, whose only prime factors are 2, 3, and 5
+    fn stockham(&self, len: usize) -> f64 {
+        // like Radix4, every stage is a pass over the whole buffer, so it pays the same penalty for leaving the cache.
+        // each stage also has some fixed overhead, which dominates for small sizes
+        let cache_penalty = if len > DEFAULT_CACHE_LEN { 1.5 } else { 1.0 };
+        cache_penalty * 1.1 * len as f64 * log2(len) + 500.0 * log2(len)
+    }
+
+    /// The estimated cost of the RadixN algorithm of size `len` with `num_stages` butterfly stages, excluding its
+    /// butterflies
+    fn radix_n(&self, len: usize, num_stages: usize) -> f64 {
+        // a digit-reversal pass over the whole buffer, then a twiddle factor multiplication for every stage after the
+        // first. unlike MixedRadix, there are no transposes
+        2.0 * num_stages as f64 * len as f64
+    }
+
+    /// The estimated cost of the PrimePower algorithm of size `len`, which is `exponent` copies of the same prime
+    /// multiplied together, excluding its inner FFTs
+    fn prime_power(&self, len: usize, exponent: usize) -> f64 {
+        // a digit-reversal pass, a pass over the whole buffer for every stage after the first, and a final transpose
+        (exponent + 1) as f64 * len as f64
+    }
+
+    /// The estimated cost of the MixedRadix algorithm, excluding its inner FFTs
+    fn mixed_radix(&self, width: usize, height: usize) -> f64 {
+        // three transposes and a twiddle factor multiplication, each of which is a pass over the whole buffer. the
+        // transposes read or write with a stride of a whole row, so they suffer even more than Radix4 once the buffer
+        // doesn't fit in the cache
+        let len = width * height;
+        let cache_penalty = if len > DEFAULT_CACHE_LEN { 3.0 } else { 1.0 };
+        cache_penalty * 3.0 * len as f64
+    }
+
+    /// The estimated cost of the FourStep algorithm, excluding its inner FFTs
+    fn four_step(&self, width: usize, height: usize) -> f64 {
+        // FourStep makes fewer passes over memory than MixedRadix, but copying each block into a scratch buffer
+        // is only worth it if the whole buffer doesn't fit in the cache. its inner FFTs then run in the cache, which
+        // is what makes it cheaper than Radix4 and Stockham for large sizes
+        let len = width * height;
+        let passes = if len > DEFAULT_CACHE_LEN { 8.0 * len as f64 } else { 6.0 * len as f64 };
+
+        // its scratch buffer, which is allocated and zeroed on every call, holds two blocks of 128KiB, or of 8 columns
+        // if they're taller than that. this assumes single precision, where 128KiB is 2^14 elements
+        let block_len = cmp::min(cmp::max(1 << 14, height.saturating_mul(8)), len);
+        passes + 2.0 * block_len as f64
+    }
+
+    /// The estimated cost of the MixedRadixDoubleButterfly algorithm, excluding its inner butterflies
+    fn mixed_radix_double_butterfly(&self, width: usize, height: usize) -> f64 {
+        2.0 * (width * height) as f64
+    }
+
+    /// The estimated cost of the Good-Thomas Algorithm, excluding its inner FFTs
+    fn good_thomas(&self, width: usize, height: usize) -> f64 {
+        // no twiddle factors, but the input and output reordering needs a modulo for every element. like MixedRadix's
+        // transposes, the reordering jumps around the whole buffer, so it suffers as much once the buffer doesn't fit
+        // in the cache
+        let len = width * height;
+        let cache_penalty = if len > DEFAULT_CACHE_LEN { 3.0 } else { 1.0 };
+        cache_penalty * 3.5 * len as f64
+    }
+
+    /// The estimated cost of the GoodThomasAlgorithmDoubleButterfly algorithm, excluding its inner butterflies
+    fn good_thomas_double_butterfly(&self, width: usize, height: usize) -> f64 {
+        1.5 * (width * height) as f64
+    }
+
+    /// The estimated cost of Rader's Algorithm of size `len`, excluding its two inner FFTs of size `len - 1`
+    fn raders(&self, len: usize) -> f64 {
+        12.0 * len as f64
+    }
+
+    /// The estimated cost of Rader's Algorithm of size `len` with its convolution zero padded to `inner_len`,
+    /// excluding its two inner FFTs of size `inner_len`
+    fn raders_zero_padded(&self, len: usize, inner_len: usize) -> f64 {
+        // the multiplication covers the whole padding, and the scratch space, which is allocated and zeroed on every
+        // call, holds both inner FFTs' buffers
+        12.0 * len as f64 + inner_len as f64 + 2.0 * inner_len as f64
+    }
+}
+
+/// The cost model used by the planner unless a different one is chosen
+///
+/// See [`CostModel`](trait.CostModel.html) for a description of how the estimates are used.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultCostModel;
+
+impl CostModel for DefaultCostModel {}
+
+/// FFTs larger than this are assumed to no longer fit in the cache
+const DEFAULT_CACHE_LEN: usize = 1 << 18;
+
+#[cfg(feature = "std")]
+fn log2(len: usize) -> f64 {
+    (len as f64).log2()
+}
+
+#[cfg(not(feature = "std"))]
+fn log2(len: usize) -> f64 {
+    ::libm::log2(len as f64)
+}
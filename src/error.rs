@@ -0,0 +1,107 @@
+#[cfg(feature = "std")]
+use std::error::Error;
+use core::fmt;
+
+/// The error type returned by the non-panicking `try_*` methods and constructors
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FftError {
+    /// The input buffer passed to a `process` method was the wrong length
+    InputLength { expected: usize, actual: usize },
+
+    /// The output buffer passed to a `process` method was the wrong length
+    OutputLength { expected: usize, actual: usize },
+
+    /// The buffers passed to a `process_multi` method were not a multiple of the FFT length
+    NotMultiple { fft_len: usize, actual: usize },
+
+    /// The input and output buffers passed to a `process_multi` method had different lengths
+    MismatchedBuffers { input_len: usize, output_len: usize },
+
+    /// An inner FFT passed to a constructor was the wrong length
+    InnerLength { expected: usize, actual: usize },
+
+    /// An inner FFT passed to a constructor was shorter than the minimum length the constructor supports
+    InnerLengthTooShort { minimum: usize, actual: usize },
+
+    /// The inner FFTs passed to a constructor compute FFTs in different directions
+    MismatchedDirection { width_inverse: bool, height_inverse: bool },
+
+    /// The inner FFT lengths passed to the Good-Thomas Algorithm share a common factor
+    NotCoprime { width: usize, height: usize },
+
+    /// The length passed to Rader's Algorithm is not prime
+    NotPrime { len: usize },
+
+    /// The length passed to a power-of-two algorithm is not a power of two
+    NotPowerOfTwo { len: usize },
+
+    /// The length passed to the Stockham algorithm has a prime factor other than 2, 3, or 5
+    NotSmooth { len: usize },
+
+    /// The product of the inner FFT lengths passed to a constructor doesn't fit in a `usize`
+    LengthOverflow { width: usize, height: usize },
+
+    /// An inner FFT passed to a constructor has length zero, which the constructor doesn't support
+    ZeroLength,
+
+    /// The list of butterflies passed to the RadixN algorithm was empty
+    NoButterflies,
+}
+
+impl fmt::Display for FftError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FftError::InputLength { expected, actual } =>
+                write!(f, "Input is the wrong length. Expected {}, got {}", expected, actual),
+            FftError::OutputLength { expected, actual } =>
+                write!(f, "Output is the wrong length. Expected {}, got {}", expected, actual),
+            FftError::NotMultiple { fft_len, actual } =>
+                write!(f, "Input is the wrong length. Expected multiple of {}, got {}", fft_len, actual),
+            FftError::MismatchedBuffers { input_len, output_len } =>
+                write!(f, "Input and output must have the same length. Expected {}, got {}", input_len, output_len),
+            FftError::InnerLength { expected, actual } =>
+                write!(f, "Inner FFT is the wrong length. Expected {}, got {}", expected, actual),
+            FftError::InnerLengthTooShort { minimum, actual } =>
+                write!(f, "Inner FFT is too short. Expected at least {}, got {}", minimum, actual),
+            FftError::MismatchedDirection { width_inverse, height_inverse } =>
+                write!(f, "width_fft and height_fft must both be inverse, or neither. got width inverse={}, height inverse={}",
+                    width_inverse, height_inverse),
+            FftError::NotCoprime { width, height } =>
+                write!(f, "Invalid input width and height to Good-Thomas Algorithm: ({},{}): Inputs must be coprime", width, height),
+            FftError::NotPrime { len } =>
+                write!(f, "Rader's Algorithm requires a prime input size. Got {}", len),
+            FftError::NotPowerOfTwo { len } =>
+                write!(f, "Power-of-two algorithms require a power-of-two input size. Got {}", len),
+            FftError::NotSmooth { len } =>
+                write!(f, "Stockham algorithm requires an input size whose only prime factors are 2, 3, and 5. Got {}", len),
+            FftError::LengthOverflow { width, height } =>
+                write!(f, "The FFT size {} * {} is too large to fit in a usize", width, height),
+            FftError::ZeroLength =>
+                write!(f, "Inner FFTs must have a nonzero length"),
+            FftError::NoButterflies =>
+                write!(f, "RadixN algorithm requires at least one butterfly"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for FftError {
+    fn description(&self) -> &str {
+        match *self {
+            FftError::InputLength { .. } => "input is the wrong length",
+            FftError::OutputLength { .. } => "output is the wrong length",
+            FftError::NotMultiple { .. } => "buffer length is not a multiple of the FFT length",
+            FftError::MismatchedBuffers { .. } => "input and output have different lengths",
+            FftError::InnerLength { .. } => "inner FFT is the wrong length",
+            FftError::InnerLengthTooShort { .. } => "inner FFT is too short",
+            FftError::MismatchedDirection { .. } => "inner FFTs have different directions",
+            FftError::NotCoprime { .. } => "inner FFT lengths are not coprime",
+            FftError::NotPrime { .. } => "length is not prime",
+            FftError::NotPowerOfTwo { .. } => "length is not a power of two",
+            FftError::NotSmooth { .. } => "length has a prime factor other than 2, 3, or 5",
+            FftError::LengthOverflow { .. } => "FFT length overflows usize",
+            FftError::ZeroLength => "inner FFT has length zero",
+            FftError::NoButterflies => "no butterflies were provided",
+        }
+    }
+}
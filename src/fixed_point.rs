@@ -0,0 +1,752 @@
+use core::cmp;
+use alloc::vec::Vec;
+use alloc::boxed::Box;
+
+use num_complex::Complex;
+use num_integer::gcd;
+use num_traits::{FromPrimitive, One, PrimInt, Signed, ToPrimitive};
+
+use common::{verify_length, verify_length_divisible};
+use algorithm::{make_twiddles, good_thomas_map, raders_kernel};
+use plan::FFTplanner;
+use parallel::Parallelism;
+use math_utils;
+use twiddles;
+use ::{Length, IsInverse};
+
+/// A fixed-point number format that [`FixedPointFFT`](struct.FixedPointFFT.html) can process, implemented for `i16`
+/// (Q15) and `i32` (Q31)
+///
+/// A value `v` represents the real number `v / 2^FRAC_BITS`, so every value is in [-1, 1).
+pub trait FixedPoint: PrimInt + Signed + Send + Sync + 'static {
+    /// The number of fractional bits
+    const FRAC_BITS: usize;
+
+    /// A signed integer type wide enough to hold the product of two values without overflowing
+    type Wide: PrimInt + Signed + FromPrimitive + Send + Sync + 'static;
+
+    /// Converts this value to the wide type, without changing its scale
+    fn widen(self) -> Self::Wide;
+
+    /// Converts a wide value back, clamping it to this type's range
+    fn saturate(wide: Self::Wide) -> Self;
+}
+
+impl FixedPoint for i16 {
+    const FRAC_BITS: usize = 15;
+    type Wide = i32;
+
+    #[inline(always)]
+    fn widen(self) -> i32 {
+        self as i32
+    }
+    #[inline(always)]
+    fn saturate(wide: i32) -> i16 {
+        if wide > i16::max_value() as i32 {
+            i16::max_value()
+        } else if wide < i16::min_value() as i32 {
+            i16::min_value()
+        } else {
+            wide as i16
+        }
+    }
+}
+
+impl FixedPoint for i32 {
+    const FRAC_BITS: usize = 31;
+    type Wide = i64;
+
+    #[inline(always)]
+    fn widen(self) -> i64 {
+        self as i64
+    }
+    #[inline(always)]
+    fn saturate(wide: i64) -> i32 {
+        if wide > i32::max_value() as i64 {
+            i32::max_value()
+        } else if wide < i32::min_value() as i64 {
+            i32::min_value()
+        } else {
+            wide as i32
+        }
+    }
+}
+
+/// Fixed-point FFT for Q15 (`i16`) and Q31 (`i32`) data, using only integer arithmetic once it's been created
+///
+/// The FFT is planned like the [`FFTplanner`](../struct.FFTplanner.html) plans composite and prime sizes, and
+/// computed with the same steps: Each size is split into the two factors closest to its square root, which are
+/// combined with the steps of the [`Good-Thomas Algorithm`](../algorithm/struct.GoodThomasAlgorithm.html) if they're
+/// coprime, and of [`MixedRadix`](../algorithm/struct.MixedRadix.html) otherwise. Primes are computed with
+/// [`Rader's Algorithm`](../algorithm/struct.RadersAlgorithm.html), so every size is supported. Once a size is at most
+/// 16, it's computed with a DFT, so that every stage is small.
+///
+/// Instead of always scaling the data down to make room for growth, the data uses block floating point: before each
+/// DFT stage, and before each multiplication by twiddle factors, the values in the buffer are checked, and the data is
+/// only shifted right by the number of bits needed to guarantee that the stage doesn't overflow. The total number of
+/// bits shifted is returned from [`process`](#method.process), so that the exact FFT of the input is
+/// `output * 2^exponent`. All arithmetic rounds to the nearest value, and saturates instead of overflowing.
+///
+/// ~~~
+/// // Computes a forward FFT of size 256, on Q15 data
+/// use rustfft::fixed_point::FixedPointFFT;
+/// use rustfft::num_complex::Complex;
+///
+/// let input: Vec<Complex<i16>> = (0..256).map(|i| Complex::new(if i % 8 < 4 { 16000 } else { -16000 }, 0)).collect();
+/// let mut output: Vec<Complex<i16>> = vec![Complex::new(0, 0); 256];
+///
+/// let fft = FixedPointFFT::new(256, false);
+/// let exponent = fft.process(&input, &mut output);
+///
+/// // the exact FFT of the input is output * 2^exponent
+/// let scale = (1 << exponent) as f32 / 32768f32;
+/// let dc = output[0].re as f32 * scale;
+/// assert!(dc.abs() < 0.1);
+/// ~~~
+pub struct FixedPointFFT<T: FixedPoint> {
+    // None for sizes 0 and 1, whose FFT is a copy
+    algorithm: Option<Box<FixedPointAlgorithm<T>>>,
+    len: usize,
+    inverse: bool,
+}
+
+impl<T: FixedPoint> FixedPointFFT<T> {
+    /// Creates a fixed-point FFT instance which will process inputs/outputs of size `len`
+    ///
+    /// If `inverse` is false, this FFT will compute forward FFTs. If `inverse` is true, it will compute inverse FFTs.
+    pub fn new(len: usize, inverse: bool) -> Self {
+        FixedPointFFT {
+            algorithm: if len <= 1 { None } else { Some(plan_fft(len, inverse)) },
+            len,
+            inverse,
+        }
+    }
+
+    /// Computes a FFT of `input`, storing the result in `output`, and returns the number of bits the result was
+    /// shifted right by to avoid overflowing
+    ///
+    /// The exact FFT of the input is `output * 2^exponent`, where `exponent` is the returned value. Both buffers must
+    /// have the same length as this FFT.
+    pub fn process(&self, input: &[Complex<T>], output: &mut [Complex<T>]) -> u32 {
+        verify_length(input, output, self.len());
+
+        let mut scratch = self.make_scratch();
+        self.perform_fft(input, output, &mut scratch)
+    }
+
+    /// Divides `input` and `output` into chunks of length self.len(), computes a FFT of each chunk, and returns the
+    /// exponent of each chunk
+    ///
+    /// Each chunk is scaled independently, so the exact FFT of chunk `i` is that chunk of `output` times
+    /// `2^exponents[i]`. Both buffers must have the same length, which must be a multiple of the FFT length.
+    pub fn process_multi(&self, input: &[Complex<T>], output: &mut [Complex<T>]) -> Vec<u32> {
+        verify_length_divisible(input, output, self.len());
+        if input.is_empty() {
+            return Vec::new();
+        }
+
+        let mut scratch = self.make_scratch();
+        input.chunks(self.len).zip(output.chunks_mut(self.len))
+            .map(|(in_chunk, out_chunk)| self.perform_fft(in_chunk, out_chunk, &mut scratch))
+            .collect()
+    }
+
+    /// Allocates the copy of the input that the algorithms are allowed to overwrite
+    fn make_scratch(&self) -> Vec<Complex<T>> {
+        vec![Complex::new(T::zero(), T::zero()); self.len]
+    }
+
+    fn perform_fft(&self, input: &[Complex<T>], output: &mut [Complex<T>], scratch: &mut [Complex<T>]) -> u32 {
+        match self.algorithm {
+            Some(ref algorithm) => {
+                scratch.copy_from_slice(input);
+                algorithm.process(scratch, output)
+            }
+            None => {
+                output.copy_from_slice(input);
+                0
+            }
+        }
+    }
+}
+
+impl<T: FixedPoint> Length for FixedPointFFT<T> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+impl<T: FixedPoint> IsInverse for FixedPointFFT<T> {
+    #[inline(always)]
+    fn is_inverse(&self) -> bool {
+        self.inverse
+    }
+}
+
+/// The largest size that's computed with a DFT, rather than split into smaller FFTs
+const MAX_DFT_LEN: usize = 16;
+
+/// The algorithm used for a fixed-point FFT of a particular size
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FixedPointPlan {
+    Dft,
+    MixedRadix { width: usize, height: usize },
+    GoodThomas { width: usize, height: usize },
+    Raders,
+}
+
+/// Chooses the algorithm for a fixed-point FFT of size `len`, which must be at least 2
+fn choose_plan(len: usize) -> FixedPointPlan {
+    if len <= MAX_DFT_LEN {
+        return FixedPointPlan::Dft;
+    }
+
+    // the factors closest to the square root make the shallowest plan. a prime has no factors to split into
+    let width = (1..).take_while(|&width| width <= len / width).filter(|&width| len % width == 0).last().unwrap();
+    let height = len / width;
+    if width == 1 {
+        FixedPointPlan::Raders
+    } else if gcd(width, height) == 1 {
+        FixedPointPlan::GoodThomas { width, height }
+    } else {
+        FixedPointPlan::MixedRadix { width, height }
+    }
+}
+
+/// Creates the algorithm `choose_plan` chooses for `len`, and for each of its inner FFTs
+fn plan_fft<T: FixedPoint>(len: usize, inverse: bool) -> Box<FixedPointAlgorithm<T>> {
+    match choose_plan(len) {
+        FixedPointPlan::Dft => Box::new(FixedPointDFT::new(len, inverse)),
+        FixedPointPlan::MixedRadix { width, height } => Box::new(FixedPointMixedRadix {
+            width_fft: plan_fft(width, inverse),
+            height_fft: plan_fft(height, inverse),
+            twiddles: make_twiddles::<f64>(width, height, inverse).iter().map(|&twiddle| quantize::<T>(twiddle, 1f64)).collect(),
+        }),
+        FixedPointPlan::GoodThomas { width, height } => Box::new(FixedPointGoodThomas {
+            width_fft: plan_fft(width, inverse),
+            height_fft: plan_fft(height, inverse),
+            input_output_map: good_thomas_map(width, height).unwrap().into_boxed_slice(),
+        }),
+        FixedPointPlan::Raders => Box::new(FixedPointRaders::new(len, plan_fft(len - 1, inverse), inverse)),
+    }
+}
+
+/// One step of a planned fixed-point FFT. Each step computes every FFT in its buffers with the same exponent
+///
+/// The steps only compute with a few operations on fixed-point values, each of which rounds to the nearest value and
+/// saturates instead of overflowing: `add`, `twiddle_multiply`, and `scale`, which divides by a power of two. The
+/// steps of Rader's Algorithm also add values with different exponents, with `add_shifted`
+trait FixedPointAlgorithm<T: FixedPoint>: Send + Sync {
+    fn len(&self) -> usize;
+
+    /// Computes a FFT of each chunk of `input`, storing the results in `output`, and returns the exponent of all of
+    /// them. The contents of `input` should be considered garbage afterwards
+    fn process(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) -> u32;
+}
+
+/// A twiddle factor, or another multiplier with components in [-1, 1], stored in `T::Wide` with `T::FRAC_BITS`
+/// fractional bits, so that 1 is representable
+type Twiddle<T> = Complex<<T as FixedPoint>::Wide>;
+
+/// The naive DFT, for the small FFTs at the leaves of the plan
+struct FixedPointDFT<T: FixedPoint> {
+    twiddles: Box<[Twiddle<T>]>,
+}
+
+impl<T: FixedPoint> FixedPointDFT<T> {
+    fn new(len: usize, inverse: bool) -> Self {
+        let twiddles = twiddles::generate_twiddle_factors::<f64>(len, inverse);
+        FixedPointDFT {
+            twiddles: twiddles.iter().map(|&twiddle| quantize::<T>(twiddle, 1f64)).collect(),
+        }
+    }
+}
+
+impl<T: FixedPoint> FixedPointAlgorithm<T> for FixedPointDFT<T> {
+    fn len(&self) -> usize {
+        self.twiddles.len()
+    }
+
+    fn process(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) -> u32 {
+        let len = self.len();
+
+        // every output, and every partial sum along the way, is a sum of inputs multiplied by twiddle factors, so its
+        // components are at most the sum of |re| + |im| of the inputs
+        let bound = input.chunks(len).map(|chunk| chunk.iter().map(magnitude).sum::<u64>()).max().unwrap_or(0);
+        let shift = shift_to_fit::<T>(bound);
+        scale(input, shift);
+
+        for (in_chunk, out_chunk) in input.chunks(len).zip(output.chunks_mut(len)) {
+            for (k, output_cell) in out_chunk.iter_mut().enumerate() {
+                let mut sum = Complex::new(T::zero(), T::zero());
+                let mut twiddle_index = 0;
+                for &input_cell in in_chunk.iter() {
+                    sum = add(sum, twiddle_multiply(input_cell, self.twiddles[twiddle_index]));
+
+                    twiddle_index += k;
+                    if twiddle_index >= len {
+                        twiddle_index -= len;
+                    }
+                }
+                *output_cell = sum;
+            }
+        }
+        shift
+    }
+}
+
+/// The steps of [`MixedRadix`](../algorithm/struct.MixedRadix.html), with the data rescaled before the twiddle factors
+struct FixedPointMixedRadix<T: FixedPoint> {
+    width_fft: Box<FixedPointAlgorithm<T>>,
+    height_fft: Box<FixedPointAlgorithm<T>>,
+    twiddles: Box<[Twiddle<T>]>,
+}
+
+impl<T: FixedPoint> FixedPointAlgorithm<T> for FixedPointMixedRadix<T> {
+    fn len(&self) -> usize {
+        self.twiddles.len()
+    }
+
+    fn process(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) -> u32 {
+        let (width, height) = (self.width_fft.len(), self.height_fft.len());
+
+        // STEP 1: transpose
+        transpose_chunks(input, output, width, height);
+
+        // STEP 2: perform FFTs of size `height`
+        let height_exponent = self.height_fft.process(output, input);
+
+        // STEP 3: Apply twiddle factors
+        let twiddle_shift = headroom(input);
+        scale(input, twiddle_shift);
+        for chunk in input.chunks_mut(width * height) {
+            for (element, &twiddle) in chunk.iter_mut().zip(self.twiddles.iter()) {
+                *element = twiddle_multiply(*element, twiddle);
+            }
+        }
+
+        // STEP 4: transpose again
+        transpose_chunks(input, output, height, width);
+
+        // STEP 5: perform FFTs of size `width`
+        let width_exponent = self.width_fft.process(output, input);
+
+        // STEP 6: transpose again
+        transpose_chunks(input, output, width, height);
+
+        height_exponent + twiddle_shift + width_exponent
+    }
+}
+
+/// The steps of the [`Good-Thomas Algorithm`](../algorithm/struct.GoodThomasAlgorithm.html), which has no twiddle
+/// factors, so its exponent is just the sum of its inner FFTs' exponents
+struct FixedPointGoodThomas<T: FixedPoint> {
+    width_fft: Box<FixedPointAlgorithm<T>>,
+    height_fft: Box<FixedPointAlgorithm<T>>,
+    input_output_map: Box<[usize]>,
+}
+
+impl<T: FixedPoint> FixedPointAlgorithm<T> for FixedPointGoodThomas<T> {
+    fn len(&self) -> usize {
+        self.input_output_map.len() / 2
+    }
+
+    fn process(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) -> u32 {
+        let (width, height) = (self.width_fft.len(), self.height_fft.len());
+        let len = width * height;
+        let (input_map, output_map) = self.input_output_map.split_at(len);
+
+        // copy the input using our reordering mapping
+        for (in_chunk, out_chunk) in input.chunks(len).zip(output.chunks_mut(len)) {
+            for (output_element, &input_index) in out_chunk.iter_mut().zip(input_map.iter()) {
+                *output_element = in_chunk[input_index];
+            }
+        }
+
+        // run FFTs of size `width`, transpose, and run FFTs of size `height`
+        let width_exponent = self.width_fft.process(output, input);
+        transpose_chunks(input, output, width, height);
+        let height_exponent = self.height_fft.process(output, input);
+
+        // copy to the output, using our output reordering mapping
+        for (in_chunk, out_chunk) in input.chunks(len).zip(output.chunks_mut(len)) {
+            for (input_element, &output_index) in in_chunk.iter().zip(output_map.iter()) {
+                out_chunk[output_index] = *input_element;
+            }
+        }
+
+        width_exponent + height_exponent
+    }
+}
+
+/// The steps of [`RadersAlgorithm`](../algorithm/struct.RadersAlgorithm.html), with the convolution's kernel scaled up
+/// to use the whole range of `T`
+struct FixedPointRaders<T: FixedPoint> {
+    inner_fft: Box<FixedPointAlgorithm<T>>,
+
+    // the kernel times 2^kernel_exponent
+    kernel: Box<[Twiddle<T>]>,
+    kernel_exponent: i32,
+
+    primitive_root: usize,
+    primitive_root_inverse: usize,
+
+    len: usize,
+}
+
+impl<T: FixedPoint> FixedPointRaders<T> {
+    fn new(len: usize, inner_fft: Box<FixedPointAlgorithm<T>>, inverse: bool) -> Self {
+        let primitive_root = math_utils::primitive_root(len as u64).unwrap() as usize;
+        let primitive_root_inverse = math_utils::multiplicative_inverse(primitive_root, len);
+
+        // the kernel is the same as RadersAlgorithm's. its components are much smaller than 1, so compute it in f64,
+        // and scale it up by the largest power of two that keeps them at most 1
+        let float_inner_fft = FFTplanner::<f64>::new(inverse).plan_fft(inner_fft.len());
+        let float_kernel = raders_kernel(len, primitive_root_inverse, &*float_inner_fft);
+        let abs = |x: f64| if x < 0f64 { -x } else { x };
+        let max_component = float_kernel.iter().fold(0f64, |max, value| {
+            let component = if abs(value.re) > abs(value.im) { abs(value.re) } else { abs(value.im) };
+            if component > max { component } else { max }
+        });
+
+        let mut kernel_exponent = 0;
+        let mut kernel_scale = 1f64;
+        while max_component * kernel_scale * 2f64 <= 1f64 {
+            kernel_exponent += 1;
+            kernel_scale *= 2f64;
+        }
+
+        FixedPointRaders {
+            inner_fft,
+            kernel: float_kernel.iter().map(|&value| quantize::<T>(value, kernel_scale)).collect(),
+            kernel_exponent,
+            primitive_root,
+            primitive_root_inverse,
+            len,
+        }
+    }
+}
+
+impl<T: FixedPoint> FixedPointAlgorithm<T> for FixedPointRaders<T> {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn process(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) -> u32 {
+        let len = self.len;
+        let inner_len = len - 1;
+
+        // the inner FFTs read and write the scratch space, with the reordered input of each FFT
+        let mut scratch = vec![Complex::new(T::zero(), T::zero()); 2 * input.len() / len * inner_len];
+        let (inner_input, inner_output) = scratch.split_at_mut(input.len() / len * inner_len);
+        for (in_chunk, inner_chunk) in input.chunks(len).zip(inner_input.chunks_mut(inner_len)) {
+            let mut input_index = 1;
+            for inner_element in inner_chunk.iter_mut() {
+                input_index = (input_index * self.primitive_root) % len;
+                *inner_element = in_chunk[input_index];
+            }
+        }
+
+        // perform the first of two inner FFTs. its first output is the sum of every input except the first
+        let first_exponent = self.inner_fft.process(inner_input, inner_output) as i32;
+        let sums: Vec<Complex<T>> = inner_output.chunks(inner_len).map(|chunk| chunk[0]).collect();
+
+        // multiply the inner result with the kernel, and conjugate it to set up an inverse FFT. a value with
+        // |re| + |im| in range times a kernel element with both components at most 1 can't overflow
+        let multiply_shift = headroom(inner_output);
+        scale(inner_output, multiply_shift);
+        for (output_chunk, input_chunk) in inner_output.chunks(inner_len).zip(inner_input.chunks_mut(inner_len)) {
+            let cells = output_chunk.iter().zip(input_chunk.iter_mut()).zip(self.kernel.iter());
+            for ((&output_cell, input_cell), &multiple) in cells {
+                *input_cell = conj(twiddle_multiply(output_cell, multiple));
+            }
+        }
+
+        // execute the second FFT, whose output is the convolution, scaled up along with the kernel
+        let second_exponent = self.inner_fft.process(inner_input, inner_output) as i32;
+        let convolution_exponent = first_exponent + multiply_shift as i32 + second_exponent - self.kernel_exponent;
+
+        // each output is the first input plus either the sum or an element of the convolution. they're added with the
+        // largest exponent of the three, plus one more bit if adding the largest of each could overflow
+        let first_max = input.chunks(len).map(|chunk| max_component(chunk[0])).max().unwrap();
+        let sum_max = sums.iter().map(|&value| max_component(value)).max().unwrap();
+        let convolution_max = inner_output.iter().map(|&value| max_component(value)).max().unwrap();
+        let mut exponent = cmp::max(0, cmp::max(first_exponent, convolution_exponent));
+        let bound = round_shift::<T>(first_max, exponent as u32) + cmp::max(
+            round_shift::<T>(sum_max, (exponent - first_exponent) as u32),
+            round_shift::<T>(convolution_max, (exponent - convolution_exponent) as u32));
+        if bound > T::max_value().widen() {
+            exponent += 1;
+        }
+
+        let first_shift = exponent as u32;
+        let sum_shift = (exponent - first_exponent) as u32;
+        let convolution_shift = (exponent - convolution_exponent) as u32;
+        for ((in_chunk, out_chunk), (inner_chunk, &sum)) in input.chunks(len).zip(output.chunks_mut(len))
+                .zip(inner_output.chunks(inner_len).zip(sums.iter())) {
+            let first_input = in_chunk[0];
+            out_chunk[0] = add_shifted(first_input, first_shift, sum, sum_shift);
+
+            // copy the final values into the output, reordering as we go
+            let mut output_index = 1;
+            for &inner_element in inner_chunk {
+                output_index = (output_index * self.primitive_root_inverse) % len;
+                out_chunk[output_index] = add_shifted(first_input, first_shift, conj(inner_element), convolution_shift);
+            }
+        }
+        exponent as u32
+    }
+}
+
+/// Transposes each chunk of `input` into the same chunk of `output`
+fn transpose_chunks<T: Copy + Send + Sync>(input: &[T], output: &mut [T], width: usize, height: usize) {
+    let parallelism = Parallelism::disabled();
+    for (in_chunk, out_chunk) in input.chunks(width * height).zip(output.chunks_mut(width * height)) {
+        parallelism.transpose(in_chunk, out_chunk, width, height);
+    }
+}
+
+/// Returns `value * scale` as a `Twiddle`, rounding to the nearest value. Both components of the
+/// result must be in [-1, 1]
+fn quantize<T: FixedPoint>(value: Complex<f64>, scale: f64) -> Twiddle<T> {
+    let quantize_component = |x: f64| {
+        // casting truncates towards zero, so round away from zero first
+        let scaled = x * scale * (1u64 << T::FRAC_BITS) as f64;
+        T::Wide::from_f64(if scaled < 0f64 { scaled - 0.5 } else { scaled + 0.5 }).unwrap()
+    };
+    Complex::new(quantize_component(value.re), quantize_component(value.im))
+}
+
+/// Returns `a + b`
+#[inline(always)]
+fn add<T: FixedPoint>(a: Complex<T>, b: Complex<T>) -> Complex<T> {
+    Complex::new(a.re.saturating_add(b.re), a.im.saturating_add(b.im))
+}
+
+/// Returns `value * twiddle`. Since |re| and |im| of the product are both at most |re| + |im| of `value`, this can
+/// only saturate if that's out of range
+#[inline(always)]
+fn twiddle_multiply<T: FixedPoint>(value: Complex<T>, twiddle: Twiddle<T>) -> Complex<T> {
+    let (re, im) = (value.re.widen(), value.im.widen());
+    let product_re = re * twiddle.re - im * twiddle.im;
+    let product_im = re * twiddle.im + im * twiddle.re;
+    Complex::new(T::saturate(round_shift::<T>(product_re, T::FRAC_BITS as u32)),
+                 T::saturate(round_shift::<T>(product_im, T::FRAC_BITS as u32)))
+}
+
+/// Divides every value in `buffer` by 2^shift
+fn scale<T: FixedPoint>(buffer: &mut [Complex<T>], shift: u32) {
+    if shift > 0 {
+        for value in buffer.iter_mut() {
+            let re = T::saturate(round_shift::<T>(value.re.widen(), shift));
+            let im = T::saturate(round_shift::<T>(value.im.widen(), shift));
+            *value = Complex::new(re, im);
+        }
+    }
+}
+
+/// Returns the complex conjugate of `value`
+#[inline(always)]
+fn conj<T: FixedPoint>(value: Complex<T>) -> Complex<T> {
+    Complex::new(value.re, T::zero().saturating_sub(value.im))
+}
+
+/// Returns the smallest number of bits to shift the values in `buffer` right by, so that the largest |re| + |im| fits in
+/// `T`. |re| + |im| bounds both components of the value multiplied by any twiddle factor
+fn headroom<T: FixedPoint>(buffer: &[Complex<T>]) -> u32 {
+    shift_to_fit::<T>(buffer.iter().map(magnitude).max().unwrap_or(0))
+}
+
+/// Returns the smallest number of bits to shift `bound` right by so that it fits in `T`
+fn shift_to_fit<T: FixedPoint>(bound: u64) -> u32 {
+    let max = T::max_value().to_u64().unwrap();
+    let mut shift = 0;
+    while (bound >> shift) > max {
+        shift += 1;
+    }
+    shift
+}
+
+/// Returns |re| + |im|
+fn magnitude<T: FixedPoint>(value: &Complex<T>) -> u64 {
+    (value.re.widen().abs() + value.im.widen().abs()).to_u64().unwrap()
+}
+
+/// Returns `a / 2^a_shift + b / 2^b_shift`, rounding each term to the nearest value
+fn add_shifted<T: FixedPoint>(a: Complex<T>, a_shift: u32, b: Complex<T>, b_shift: u32) -> Complex<T> {
+    let add = |a: T, b: T| T::saturate(round_shift::<T>(a.widen(), a_shift) + round_shift::<T>(b.widen(), b_shift));
+    Complex::new(add(a.re, b.re), add(a.im, b.im))
+}
+
+/// Returns the larger of |re| and |im|
+fn max_component<T: FixedPoint>(value: Complex<T>) -> T::Wide {
+    cmp::max(value.re.widen().abs(), value.im.widen().abs())
+}
+
+/// Returns `value / 2^shift`, rounded to the nearest integer
+fn round_shift<T: FixedPoint>(value: T::Wide, shift: u32) -> T::Wide {
+    if shift == 0 {
+        return value;
+    }
+    // every value in T's range rounds to zero when shifted by more than this, so larger shifts don't overflow the
+    // wide type
+    let shift = cmp::min(shift as usize, T::FRAC_BITS + 2);
+    (value + (T::Wide::one() << (shift - 1))) >> shift
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use num_traits::Zero;
+    use algorithm::DFT;
+    use FFT;
+    use test_utils::random_signal;
+
+    #[test]
+    fn test_fixed_point_q15() {
+        for &len in &[1, 2, 3, 4, 5, 8, 12, 16, 30, 31, 64, 128, 210, 1000, 1024] {
+            check_fixed_point::<i16>(len, false, 1e-3);
+            check_fixed_point::<i16>(len, true, 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_fixed_point_q31() {
+        for &len in &[1, 2, 3, 4, 5, 8, 12, 16, 30, 31, 64, 128, 210, 1000, 1024] {
+            check_fixed_point::<i32>(len, false, 2e-8);
+            check_fixed_point::<i32>(len, true, 2e-8);
+        }
+    }
+
+    #[test]
+    fn test_fixed_point_large_primes() {
+        // Rader's Algorithm computes two inner FFTs of at least len - 1, so its results are rounded about twice as often
+        for &len in &[37, 97 * 8, 1201] {
+            check_fixed_point::<i16>(len, false, 2e-3);
+            check_fixed_point::<i16>(len, true, 2e-3);
+            check_fixed_point::<i32>(len, false, 4e-8);
+            check_fixed_point::<i32>(len, true, 4e-8);
+        }
+    }
+
+    #[test]
+    fn test_fixed_point_plan() {
+        // every FFT should be split until each stage is a DFT of at most 16
+        assert_eq!(choose_plan(1024), FixedPointPlan::MixedRadix { width: 32, height: 32 });
+        assert_eq!(choose_plan(1000), FixedPointPlan::MixedRadix { width: 25, height: 40 });
+        assert_eq!(choose_plan(210), FixedPointPlan::GoodThomas { width: 14, height: 15 });
+        assert_eq!(leaf_lens(1024).iter().product::<usize>(), 1024);
+        for &len in &[64, 1000, 1024, 1201, 48000] {
+            let leaves = leaf_lens(len);
+            assert!(leaves.iter().all(|&leaf| leaf <= MAX_DFT_LEN), "len = {}, leaves = {:?}", len, leaves);
+        }
+
+        // primes are computed with Rader's Algorithm, however large they are
+        for &len in &[37, 1201, 10007] {
+            assert_eq!(choose_plan(len), FixedPointPlan::Raders, "len = {}", len);
+        }
+
+        assert_eq!(choose_plan(16), FixedPointPlan::Dft);
+        assert_eq!(FixedPointFFT::<i32>::new(10007, true).len(), 10007);
+        assert!(FixedPointFFT::<i16>::new(1, false).algorithm.is_none());
+    }
+
+    /// Returns the size of every DFT in the plan for `len`
+    fn leaf_lens(len: usize) -> Vec<usize> {
+        match choose_plan(len) {
+            FixedPointPlan::Dft => vec![len],
+            FixedPointPlan::MixedRadix { width, height } | FixedPointPlan::GoodThomas { width, height } => {
+                let mut lens = leaf_lens(width);
+                lens.extend(leaf_lens(height));
+                lens
+            }
+            FixedPointPlan::Raders => leaf_lens(len - 1),
+        }
+    }
+
+    #[test]
+    fn test_fixed_point_multi() {
+        // each chunk should be processed and scaled exactly like a single FFT
+        let len = 60;
+        let fft = FixedPointFFT::<i16>::new(len, false);
+        let chunk_scales = [10, 20000, 300];
+        let input: Vec<Complex<i16>> = chunk_scales.iter().flat_map(|&chunk_scale| {
+            random_signal(len).into_iter().map(move |value| {
+                let scale = chunk_scale as f32 / 40f32;
+                Complex::new((value.re * scale) as i16, (value.im * scale) as i16)
+            })
+        }).collect();
+
+        let mut output = vec![Complex::new(0i16, 0i16); input.len()];
+        let exponents = fft.process_multi(&input, &mut output);
+        assert_eq!(exponents.len(), chunk_scales.len());
+
+        let mut chunk_output = vec![Complex::new(0i16, 0i16); len];
+        for (i, &exponent) in exponents.iter().enumerate() {
+            let chunk = i * len..(i + 1) * len;
+            assert_eq!(fft.process(&input[chunk.clone()], &mut chunk_output), exponent, "chunk = {}", i);
+            assert_eq!(&output[chunk], &chunk_output[..], "chunk = {}", i);
+        }
+        assert!(exponents[0] < exponents[1], "exponents = {:?}", exponents);
+
+        assert_eq!(fft.process_multi(&[], &mut []), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn test_fixed_point_exponent() {
+        // a small impulse has a flat spectrum with the same magnitude, so nothing needs to be shifted
+        let mut input = vec![Complex::new(0i16, 0i16); 64];
+        input[0] = Complex::new(100, -100);
+        let mut output = vec![Complex::new(0i16, 0i16); 64];
+        let fft = FixedPointFFT::new(64, false);
+        assert_eq!(fft.process(&input, &mut output), 0);
+        assert!(output.iter().all(|&value| value == Complex::new(100, -100)));
+
+        // a full-scale constant signal grows by a factor of len in the DC bin, and must not overflow
+        for &value in &[i16::max_value(), i16::min_value()] {
+            let input = vec![Complex::new(value, value); 64];
+            let exponent = fft.process(&input, &mut output);
+            assert!(exponent >= 6, "exponent = {}", exponent);
+
+            let dc = output[0].re as f64 * (1u64 << exponent) as f64;
+            let expected = value as f64 * 64f64;
+            assert!((dc - expected).abs() <= expected.abs() * 1e-3, "dc = {}, expected = {}", dc, expected);
+            assert!(output[1..].iter().all(|value| value.re.abs() <= 1 && value.im.abs() <= 1));
+        }
+    }
+
+    /// Compares the FFT of a random signal against a f64 DFT of the same quantized signal, and checks that the error
+    /// relative to the size of the spectrum is less than `tolerance`
+    fn check_fixed_point<T: FixedPoint>(len: usize, inverse: bool, tolerance: f64) {
+        let scale = (1u64 << T::FRAC_BITS) as f64;
+        let input: Vec<Complex<T>> = random_signal(len).iter().map(|value| {
+            // the random signal's standard deviation is 10, so this uses about a tenth of the range
+            let quantize = |x: f32| T::saturate(T::Wide::from_f64(x as f64 * scale / 100f64).unwrap());
+            Complex::new(quantize(value.re), quantize(value.im))
+        }).collect();
+
+        let mut expected_input: Vec<Complex<f64>> = input.iter()
+            .map(|value| Complex::new(value.re.to_f64().unwrap(), value.im.to_f64().unwrap()))
+            .collect();
+        let mut expected = vec![Complex::zero(); len];
+        DFT::new(len, inverse).process(&mut expected_input, &mut expected);
+
+        let fft = FixedPointFFT::new(len, inverse);
+        assert_eq!(fft.len(), len);
+        assert_eq!(fft.is_inverse(), inverse);
+        let mut output = vec![Complex::new(T::zero(), T::zero()); len];
+        let exponent = fft.process(&input, &mut output);
+
+        let factor = (1u64 << exponent) as f64;
+        let mut error = 0f64;
+        let mut magnitude = 0f64;
+        for (actual, expected) in output.iter().zip(expected.iter()) {
+            let re = actual.re.to_f64().unwrap();
+            let im = actual.im.to_f64().unwrap();
+            error += (Complex::new(re * factor, im * factor) - expected).norm_sqr();
+            magnitude += expected.norm_sqr();
+        }
+        let relative_error = (error / magnitude).sqrt();
+        assert!(relative_error < tolerance, "len = {}, inverse = {}, relative error = {}", len, inverse, relative_error);
+    }
+}
@@ -1,4 +1,5 @@
 #![cfg_attr(all(feature = "bench", test), feature(test))]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 //! RustFFT allows users to compute arbitrary-sized FFTs in O(nlogn) time.
 //!
@@ -43,10 +44,27 @@
 //! fft.process(&mut input, &mut output);
 //! ```
 //!
+//! With the `const-generics` feature, which requires rustc 1.51 or newer, `algorithm::FixedFFT` goes one step further
+//! for sizes known at compile time: It processes arrays instead of slices, and chooses its algorithm from the array
+//! size, without any trait objects.
+//!
 //! For the vast majority of situations, simply using the [`FFTplanner`](struct.FFTplanner.html) will be enough, but
 //! advanced users may have better insight than the planner into which algorithms are best for a specific size. See the
 //! [`algorithm`](algorithm/index.html) module for a complete list of algorithms implemented by RustFFT.
 //!
+//! ### `no_std`
+//!
+//! RustFFT's algorithms and planner work without the standard library, as long as the `alloc` crate is available. To
+//! use them, disable the default `std` feature, and enable the `libm` feature, which computes twiddle factors with
+//! [libm](https://docs.rs/libm) instead of the standard library's trig functions. Without `std`,
+//! `ConcurrentFFTplanner` isn't available, and the `rayon` feature can't be used.
+//!
+//! ### Half precision
+//!
+//! With the `half` feature, [`FFTnum`](trait.FFTnum.html) is also implemented for `half::f16` and `half::bf16`. The
+//! planner computes their FFTs in f32 internally, with f32 twiddle factors, using the
+//! [`Widened`](algorithm/struct.Widened.html) algorithm, so the result is only rounded to half precision once.
+//!
 //! ### Normalization
 //!
 //! RustFFT does not normalize outputs. Callers must manually normalize the results by scaling each element by
@@ -56,30 +74,67 @@
 //! ### Output Order
 //!
 //! Elements in the output are ordered by ascending frequency, with the first element corresponding to frequency 0.
+//! The [`spectrum`](spectrum/index.html) module contains helpers to reorder the output so that frequency 0 is in the
+//! center, and to compute the frequency corresponding to each element.
 
 #![allow(unknown_lints)] // The "bare trait objects" lint is unknown on rustc 1.26
 #![allow(bare_trait_objects)]
 
+// without std, Vec, Box, and Arc come from the alloc crate. with std, alias std as alloc, so that the same paths work
+#[cfg(not(feature = "std"))]
+#[macro_use]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std as alloc;
+#[cfg(feature = "std")]
+extern crate core;
+
+#[cfg(all(not(feature = "std"), not(feature = "libm")))]
+compile_error!("RustFFT requires either the std feature or the libm feature");
+#[cfg(all(feature = "rayon", not(feature = "std")))]
+compile_error!("The rayon feature requires the std feature");
+
 pub extern crate num_complex;
 pub extern crate num_traits;
 extern crate num_integer;
 extern crate strength_reduce;
+#[cfg(feature = "std")]
 extern crate transpose;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+extern crate libm;
+#[cfg(feature = "half")]
+pub extern crate half;
 
 
 
 /// Individual FFT algorithms
 pub mod algorithm;
+/// Signal processing helpers built on top of the FFT planner
+pub mod signal;
+/// Utilities for reordering FFT outputs and computing the frequency of each output element
+pub mod spectrum;
+/// Fixed-point FFTs for Q15 and Q31 data, with block floating point scaling
+pub mod fixed_point;
 mod math_utils;
 mod array_utils;
 mod plan;
+mod cost_model;
 mod twiddles;
 mod common;
+mod error;
+mod parallel;
 
 use num_complex::Complex;
 
 pub use plan::FFTplanner;
+#[cfg(feature = "std")]
+pub use plan::ConcurrentFFTplanner;
+pub use cost_model::{CostModel, DefaultCostModel};
 pub use common::FFTnum;
+pub use error::FftError;
+pub use parallel::Parallelism;
 
 
 
@@ -116,8 +171,67 @@ pub trait FFT<T: FFTnum>: Length + IsInverse + Sync + Send {
     /// This method uses the `input` buffer as scratch space, so the contents of `input` should be considered garbage
     /// after calling
     fn process_multi(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]);
+
+    /// Same as [`process`](#tymethod.process), except that if `input` or `output` is the wrong length, this returns an
+    /// [`FftError`](enum.FftError.html) instead of panicking
+    ///
+    /// ~~~
+    /// use rustfft::{FFT, FFTplanner, FftError};
+    /// use rustfft::num_complex::Complex;
+    /// use rustfft::num_traits::Zero;
+    ///
+    /// let mut input:  Vec<Complex<f32>> = vec![Zero::zero(); 100];
+    /// let mut output: Vec<Complex<f32>> = vec![Zero::zero(); 100];
+    ///
+    /// let mut planner = FFTplanner::new(false);
+    /// let fft = planner.plan_fft(128);
+    ///
+    /// let result = fft.try_process(&mut input, &mut output);
+    /// assert_eq!(result, Err(FftError::InputLength { expected: 128, actual: 100 }));
+    /// ~~~
+    fn try_process(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) -> Result<(), FftError> {
+        common::check_length(input, output, self.len())?;
+
+        self.process(input, output);
+        Ok(())
+    }
+
+    /// Same as [`process_multi`](#tymethod.process_multi), except that if `input` or `output` is not a multiple of
+    /// self.len(), or if they have different lengths, this returns an [`FftError`](enum.FftError.html) instead of panicking
+    fn try_process_multi(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) -> Result<(), FftError> {
+        common::check_length_divisible(input, output, self.len())?;
+
+        // there's nothing to compute in empty buffers, and a FFT of length zero can't split them into chunks
+        if !input.is_empty() {
+            self.process_multi(input, output);
+        }
+        Ok(())
+    }
+
+    /// Divides the `input` and `output` buffers into chunks of length self.len(), then computes an FFT on each chunk,
+    /// distributing batches of chunks across the current [rayon](https://docs.rs/rayon) thread pool.
+    ///
+    /// This is the global thread pool, unless called from inside a custom pool's `install` method. Each thread uses its
+    /// own chunks of `input` as scratch space, so no additional memory is allocated. Only available with the `rayon`
+    /// feature.
+    ///
+    /// The output is not normalized. Callers must manually normalize the results by scaling each element by
+    /// `1/len().sqrt()`. Multiple normalization steps can be merged into one via pairwise multiplication, so when
+    /// doing a forward FFT followed by an inverse FFT, callers can normalize once by scaling each element by `1/len()`
+    ///
+    /// This method uses the `input` buffer as scratch space, so the contents of `input` should be considered garbage
+    /// after calling
+    #[cfg(feature = "rayon")]
+    fn process_multi_parallel(&self, input: &mut [Complex<T>], output: &mut [Complex<T>]) {
+        common::verify_length_divisible(input, output, self.len());
+
+        Parallelism::batch().process_multi(self, input, output);
+    }
 }
 
+// the test harness links std anyway, and the tests use its float methods, like sqrt and cos, which core doesn't have
+#[cfg(all(test, not(feature = "std")))]
+extern crate std;
 #[cfg(test)]
 extern crate rand;
 #[cfg(test)]
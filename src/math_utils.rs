@@ -1,6 +1,9 @@
 
 use num_traits::{Zero, One, FromPrimitive, PrimInt, Signed};
-use std::mem::swap;
+use num_integer::gcd;
+use core::cmp;
+use core::mem::swap;
+use alloc::vec::Vec;
 
 pub fn primitive_root(prime: u64) -> Option<u64> {
     let test_exponents: Vec<u64> = distinct_prime_factors(prime - 1)
@@ -97,77 +100,244 @@ pub fn extended_euclidean_algorithm<T: PrimInt + Signed + FromPrimitive>(a: T,
     (r_old, s_old, t_old)
 }
 
-/// return all of the prime factors of n, but omit duplicate prime factors
-pub fn distinct_prime_factors(mut n: u64) -> Vec<u64> {
-    let mut result = Vec::new();
+/// Returns true if n is prime, using a deterministic version of the Miller-Rabin primality test
+///
+/// Testing against every prime witness up to 37 is enough to make the test exact for every 64-bit input
+pub fn is_prime(n: u64) -> bool {
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
 
-    // handle 2 separately so we dont have to worry about adding 2 vs 1
-    if n % 2 == 0 {
-        while n % 2 == 0 {
-            n /= 2;
+    if n < 2 {
+        return false;
+    }
+    for &witness in &WITNESSES {
+        if n % witness == 0 {
+            return n == witness;
         }
-        result.push(2);
     }
-    if n > 1 {
-        let mut divisor = 3;
-        let mut limit = (n as f32).sqrt() as u64 + 1;
-        while divisor < limit {
-            if n % divisor == 0 {
-
-                // remove as many factors as possible from n
-                while n % divisor == 0 {
-                    n /= divisor;
-                }
-                result.push(divisor);
 
-                // recalculate the limit to reduce the amount of work we need to do
-                limit = (n as f32).sqrt() as u64 + 1;
+    // write n - 1 as d * 2^s, where d is odd
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+
+    'next_witness: for &witness in &WITNESSES {
+        let mut x = pow_mod(witness, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'next_witness;
             }
+        }
+
+        // this witness proves that n is composite
+        return false;
+    }
+    true
+}
 
-            divisor += 2;
+/// computes a * b % modulo without overflowing
+#[inline]
+fn mul_mod(a: u64, b: u64, modulo: u64) -> u64 {
+    (a as u128 * b as u128 % modulo as u128) as u64
+}
+
+/// computes base^exponent % modulo without overflowing, using exponentiation by squaring
+fn pow_mod(mut base: u64, mut exponent: u64, modulo: u64) -> u64 {
+    let mut result = 1;
+    base %= modulo;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, modulo);
         }
+        exponent >>= 1;
+        base = mul_mod(base, base, modulo);
+    }
 
-        if n > 1 {
-            result.push(n);
+    result
+}
+
+/// return all of the prime factors of n, but omit duplicate prime factors
+pub fn distinct_prime_factors(n: u64) -> Vec<u64> {
+    let mut result = factor(n);
+    result.dedup();
+    result
+}
+
+/// Factors an integer into its prime factors, in ascending order
+pub fn prime_factors(n: usize) -> Vec<usize> {
+    factor(n as u64).into_iter().map(|factor| factor as usize).collect()
+}
+
+/// Returns the smallest number that is at least `min` and whose only prime factors are 2, 3, and 5, or None if that
+/// number doesn't fit in a usize
+pub fn next_smooth(min: usize) -> Option<usize> {
+    // search in u128, where none of the products below can overflow
+    let min = min as u128;
+    let mut best = min.next_power_of_two();
+    let mut power_of_5 = 1;
+    while power_of_5 < best {
+        let mut power_of_3 = power_of_5;
+        while power_of_3 < best {
+            // the smallest power of two that brings this product up to min
+            let mut candidate = power_of_3;
+            while candidate < min {
+                candidate *= 2;
+            }
+            best = cmp::min(best, candidate);
+            power_of_3 *= 3;
+        }
+        power_of_5 *= 5;
+    }
+
+    // the conversion is lossless exactly when the result fits
+    if best as usize as u128 == best {
+        Some(best as usize)
+    } else {
+        None
+    }
+}
+
+/// Returns every divisor of the number whose prime factors are `factors`, in ascending order. `factors` must be sorted
+pub fn divisors(factors: &[usize]) -> Vec<usize> {
+    let mut result = vec![1];
+
+    for (i, &factor) in factors.iter().enumerate() {
+        // for repeated factors, the divisors that don't include this one were already multiplied by earlier copies
+        if i > 0 && factors[i - 1] == factor {
+            continue;
         }
+        let count = factors[i..].iter().take_while(|&&other| other == factor).count();
+
+        let mut next = Vec::with_capacity(result.len() * (count + 1));
+        for &divisor in &result {
+            let mut multiple = divisor;
+            next.push(multiple);
+            for _ in 0..count {
+                multiple *= factor;
+                next.push(multiple);
+            }
+        }
+        result = next;
     }
 
+    result.sort();
     result
 }
 
-/// Factors an integer into its prime factors.
-pub fn prime_factors(mut n: usize) -> Vec<usize> {
+/// Trial division removes every factor up to this limit before we switch to Pollard's rho algorithm
+const TRIAL_DIVISION_LIMIT: u64 = 1 << 10;
+
+/// Returns every prime factor of n, including duplicates, in ascending order. Exact for every 64-bit input
+fn factor(mut n: u64) -> Vec<u64> {
     let mut result = Vec::new();
+    if n == 0 {
+        return result;
+    }
 
+    // most FFT sizes only have small factors, so trial division usually finishes the job on its own
     while n % 2 == 0 {
         n /= 2;
         result.push(2);
     }
-    if n > 1 {
-        let mut divisor = 3;
-        let mut limit = (n as f32).sqrt() as usize + 1;
-        while divisor < limit {
-            while n % divisor == 0 {
-                n /= divisor;
-                result.push(divisor);
-            }
-
-            // recalculate the limit to reduce the amount of other factors we need to check
-            limit = (n as f32).sqrt() as usize + 1;
-            divisor += 2;
+    let mut divisor = 3;
+    while divisor < TRIAL_DIVISION_LIMIT && divisor * divisor <= n {
+        while n % divisor == 0 {
+            n /= divisor;
+            result.push(divisor);
         }
+        divisor += 2;
+    }
 
-        if n > 1 {
+    // whatever is left has no factors below `divisor`, so if it's smaller than divisor^2, it must be prime
+    if n > 1 {
+        if n < divisor * divisor {
             result.push(n);
+        } else {
+            let first_large_factor = result.len();
+            factor_large(n, &mut result);
+            result[first_large_factor..].sort();
         }
     }
 
     result
 }
 
+/// Pushes every prime factor of n onto `result`, in no particular order. n must be odd and greater than 1
+fn factor_large(n: u64, result: &mut Vec<u64>) {
+    if is_prime(n) {
+        result.push(n);
+    } else {
+        let divisor = pollard_rho(n);
+        factor_large(divisor, result);
+        factor_large(n / divisor, result);
+    }
+}
+
+/// Finds a nontrivial divisor of the odd composite number n, using Brent's variant of Pollard's rho algorithm
+fn pollard_rho(n: u64) -> u64 {
+    // how many steps to take between each gcd computation
+    const BATCH_SIZE: u64 = 128;
+
+    let mut increment = 1;
+    loop {
+        let step = |x: u64| ((mul_mod(x, x, n) as u128 + increment as u128) % n as u128) as u64;
+        // (a - b) mod n has the same gcd with n as |a - b|
+        let difference = |a: u64, b: u64| if a >= b { a - b } else { a + (n - b) };
+
+        let mut x = 2;
+        let mut y = 2;
+        let mut saved_y = 2;
+        let mut product = 1;
+        let mut divisor = 1;
+        let mut cycle_len = 1;
+
+        while divisor == 1 {
+            x = y;
+            for _ in 0..cycle_len {
+                y = step(y);
+            }
+
+            // accumulate (x - y) for a batch of steps, so that we only need to compute one gcd per batch
+            let mut steps_taken = 0;
+            while steps_taken < cycle_len && divisor == 1 {
+                saved_y = y;
+                for _ in 0..cmp::min(BATCH_SIZE, cycle_len - steps_taken) {
+                    y = step(y);
+                    product = mul_mod(product, difference(x, y), n);
+                }
+                divisor = gcd(product, n);
+                steps_taken += BATCH_SIZE;
+            }
+            cycle_len *= 2;
+        }
+
+        // if the batch overshot and multiplied every factor of n into the product, redo it one step at a time
+        if divisor == n {
+            loop {
+                saved_y = step(saved_y);
+                divisor = gcd(difference(x, saved_y), n);
+                if divisor > 1 {
+                    break;
+                }
+            }
+        }
+
+        if divisor != n {
+            return divisor;
+        }
+
+        // this sequence cycled without finding a factor, so try again with a different sequence
+        increment += 1;
+    }
+}
+
 #[cfg(test)]
 mod unit_tests {
     use super::*;
+    use num_traits::Bounded;
 
     #[test]
     fn test_modular_exponent() {
@@ -244,6 +414,82 @@ mod unit_tests {
         }
     }
 
+    #[test]
+    fn test_is_prime() {
+        let primes: Vec<u64> = (0..1000).filter(|&n| n > 1 && (2..n).all(|d| n % d != 0)).collect();
+        for n in 0..1000 {
+            assert_eq!(is_prime(n), primes.contains(&n), "n = {}", n);
+        }
+
+        // large primes, including ones close to the 32 and 64 bit limits
+        let large_primes = [65537, 2147483647, 4294967291, 1000000007, 999999999989, 18446744073709551557];
+        for &n in &large_primes {
+            assert!(is_prime(n), "n = {}", n);
+        }
+
+        // strong pseudoprimes to several bases, squares and products of large primes, and carmichael numbers
+        let composites = [561, 1105, 3215031751, 2152302898747, 3474749660383, 341550071728321,
+            3825123056546413051, 65537 * 65537, 4294967291 * 4294967279, 18446744073709551615];
+        for &n in &composites {
+            assert!(!is_prime(n), "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn test_factor() {
+        // compare against naive trial division for small inputs
+        for n in 1..2000usize {
+            let mut remaining = n;
+            let mut expected = Vec::new();
+            for divisor in 2..n + 1 {
+                while remaining % divisor == 0 {
+                    remaining /= divisor;
+                    expected.push(divisor);
+                }
+            }
+            assert_eq!(prime_factors(n), expected, "n = {}", n);
+        }
+
+        let test_list: Vec<(u64, Vec<u64>)> = vec![
+            (1 << 63, vec![2; 63]),
+            (2305843009213693951, vec![2305843009213693951]),
+            (18446744073709551615, vec![3, 5, 17, 257, 641, 65537, 6700417]),
+            (1031 * 1031, vec![1031, 1031]),
+            (4294967279 * 4294967291, vec![4294967279, 4294967291]),
+            (2 * 3 * 1000003 * 1000033 * 1009, vec![2, 3, 1009, 1000003, 1000033]),
+            (999999999989 * 1000003, vec![1000003, 999999999989]),
+            (65537 * 65537 * 65537, vec![65537, 65537, 65537]),
+        ];
+        for (input, expected) in test_list {
+            assert_eq!(factor(input), expected, "n = {}", input);
+        }
+
+        assert_eq!(distinct_prime_factors(65537 * 65537 * 12), vec![2, 3, 65537]);
+    }
+
+    #[test]
+    fn test_divisors() {
+        for n in 1..500usize {
+            let expected: Vec<usize> = (1..n + 1).filter(|d| n % d == 0).collect();
+            assert_eq!(divisors(&prime_factors(n)), expected, "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn test_next_smooth() {
+        for min in 1..2000usize {
+            let expected = (min..).find(|&n| prime_factors(n).iter().all(|&factor| factor <= 5)).unwrap();
+            assert_eq!(next_smooth(min), Some(expected), "min = {}", min);
+        }
+
+        // past the largest power of two, there are still smooth numbers that fit, but not past the largest of those
+        let max: usize = Bounded::max_value();
+        let min = max / 2 + 2;
+        let smooth = next_smooth(min).unwrap();
+        assert!(smooth >= min && prime_factors(smooth).iter().all(|&factor| factor <= 5), "smooth = {}", smooth);
+        assert_eq!(next_smooth(max), None);
+    }
+
     #[test]
     fn test_prime_factors() {
         let test_list = vec![
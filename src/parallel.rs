@@ -0,0 +1,332 @@
+use num_complex::Complex;
+#[cfg(feature = "std")]
+use transpose;
+
+use common::FFTnum;
+
+use FFT;
+
+#[cfg(feature = "rayon")]
+use alloc::sync::Arc;
+#[cfg(feature = "rayon")]
+use core::cmp;
+#[cfg(feature = "rayon")]
+use rayon;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// The default minimum FFT size (or total `process_multi` buffer size) before work is split across threads
+#[cfg(feature = "rayon")]
+const DEFAULT_MIN_PARALLEL_LEN: usize = 1 << 16;
+
+/// The number of rows and columns in each block of the parallel transpose
+#[cfg(feature = "rayon")]
+const TRANSPOSE_BLOCK_SIZE: usize = 16;
+
+/// Without std, the transpose crate isn't available, so fall back to a straightforward out-of-place transpose
+#[cfg(not(feature = "std"))]
+mod transpose {
+    pub fn transpose<T: Copy>(input: &[T], output: &mut [T], width: usize, height: usize) {
+        assert!(input.len() == width * height && output.len() == width * height);
+        for (y, row) in input.chunks(width).enumerate() {
+            for (x, &element) in row.iter().enumerate() {
+                output[x * height + y] = element;
+            }
+        }
+    }
+}
+
+/// Controls whether FFT algorithms split large transforms across multiple threads
+///
+/// With the `rayon` feature enabled, `MixedRadix` and `GoodThomasAlgorithm` will distribute their inner FFT batches
+/// and transposes across a [rayon](https://docs.rs/rayon) thread pool once the amount of data being processed exceeds
+/// a threshold. Without the `rayon` feature, all work is done on the calling thread.
+///
+/// ~~~
+/// // Plan FFTs that never use more than one thread
+/// use rustfft::{FFTplanner, Parallelism};
+///
+/// let mut planner = FFTplanner::<f32>::new(false);
+/// planner.set_parallelism(Parallelism::disabled());
+/// let fft = planner.plan_fft(1 << 20);
+/// ~~~
+#[derive(Clone)]
+pub struct Parallelism {
+    #[cfg(feature = "rayon")]
+    pool: Option<Arc<rayon::ThreadPool>>,
+    #[cfg(feature = "rayon")]
+    min_len: usize,
+}
+
+impl Parallelism {
+    /// Returns a configuration that does all work on the calling thread
+    pub fn disabled() -> Self {
+        Parallelism {
+            #[cfg(feature = "rayon")]
+            pool: None,
+            #[cfg(feature = "rayon")]
+            min_len: usize::max_value(),
+        }
+    }
+
+    /// Returns a configuration that splits large FFTs across rayon's global thread pool
+    #[cfg(feature = "rayon")]
+    pub fn global() -> Self {
+        Parallelism {
+            pool: None,
+            min_len: DEFAULT_MIN_PARALLEL_LEN,
+        }
+    }
+
+    /// Returns a configuration that splits large FFTs across a dedicated pool of `num_threads` threads
+    ///
+    /// If `num_threads` is 1, parallelism is disabled. If `num_threads` is 0, rayon chooses the number of threads.
+    #[cfg(feature = "rayon")]
+    pub fn with_num_threads(num_threads: usize) -> Self {
+        if num_threads == 1 {
+            return Self::disabled();
+        }
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()
+            .expect("Failed to create the FFT thread pool");
+
+        Parallelism {
+            pool: Some(Arc::new(pool)),
+            min_len: DEFAULT_MIN_PARALLEL_LEN,
+        }
+    }
+
+    /// Returns a configuration that always splits batches of FFTs across the current rayon thread pool
+    #[cfg(feature = "rayon")]
+    pub(crate) fn batch() -> Self {
+        Parallelism {
+            pool: None,
+            min_len: 0,
+        }
+    }
+
+    /// Sets the minimum number of elements an operation must process before its work is split across threads
+    ///
+    /// Splitting small FFTs across threads costs more in synchronization than it gains, so the default is 65536.
+    #[cfg(feature = "rayon")]
+    pub fn with_min_len(mut self, min_len: usize) -> Self {
+        if self.min_len != usize::max_value() {
+            self.min_len = min_len;
+        }
+        self
+    }
+}
+
+impl Default for Parallelism {
+    #[cfg(feature = "rayon")]
+    fn default() -> Self {
+        Self::global()
+    }
+    #[cfg(not(feature = "rayon"))]
+    fn default() -> Self {
+        Self::disabled()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl Parallelism {
+    #[inline(always)]
+    fn is_parallel(&self, len: usize) -> bool {
+        len >= self.min_len
+    }
+
+    fn num_threads(&self) -> usize {
+        match self.pool {
+            Some(ref pool) => pool.current_num_threads(),
+            None => rayon::current_num_threads(),
+        }
+    }
+
+    fn install<F: FnOnce() + Send>(&self, f: F) {
+        match self.pool {
+            Some(ref pool) => pool.install(f),
+            None => f(),
+        }
+    }
+
+    /// Returns a chunk length that is a multiple of `unit_len` and divides `total_len` into a few tasks per thread
+    fn task_len(&self, total_len: usize, unit_len: usize) -> usize {
+        let num_units = total_len / unit_len;
+        let units_per_task = cmp::max(1, num_units / (self.num_threads() * 4));
+        units_per_task * unit_len
+    }
+
+    /// Divides `input` and `output` into chunks of length `chunk_len`, and calls `f` on each pair of chunks
+    pub(crate) fn for_each_chunk<T, F>(&self, input: &mut [T], output: &mut [T], chunk_len: usize, f: F)
+        where T: Send, F: Fn(&mut [T], &mut [T]) + Sync
+    {
+        if self.is_parallel(input.len()) && input.len() > chunk_len {
+            self.install(|| {
+                input.par_chunks_mut(chunk_len).zip(output.par_chunks_mut(chunk_len)).for_each(|(in_chunk, out_chunk)| f(in_chunk, out_chunk));
+            });
+        } else {
+            for (in_chunk, out_chunk) in input.chunks_mut(chunk_len).zip(output.chunks_mut(chunk_len)) {
+                f(in_chunk, out_chunk);
+            }
+        }
+    }
+
+    /// Computes `fft.process_multi()` on the given buffers, splitting the work into batches of FFTs on separate threads
+    pub(crate) fn process_multi<T: FFTnum, F: FFT<T> + ?Sized>(&self, fft: &F, input: &mut [Complex<T>], output: &mut [Complex<T>]) {
+        if self.is_parallel(input.len()) && input.len() > fft.len() {
+            let task_len = self.task_len(input.len(), fft.len());
+            self.for_each_chunk(input, output, task_len, |in_chunk, out_chunk| fft.process_multi(in_chunk, out_chunk));
+        } else {
+            fft.process_multi(input, output);
+        }
+    }
+
+    /// Divides `buffer` into rows of length `row_len`, and calls `f` with the index and contents of each row
+    pub(crate) fn for_each_row<T, F>(&self, buffer: &mut [T], row_len: usize, f: F)
+        where T: Send, F: Fn(usize, &mut [T]) + Sync
+    {
+        if self.is_parallel(buffer.len()) {
+            let rows_per_task = self.task_len(buffer.len(), row_len) / row_len;
+            self.install(|| {
+                buffer.par_chunks_mut(row_len).with_min_len(rows_per_task).enumerate().for_each(|(index, row)| f(index, row));
+            });
+        } else {
+            for (index, row) in buffer.chunks_mut(row_len).enumerate() {
+                f(index, row);
+            }
+        }
+    }
+
+    /// Transposes `input`, a `height` x `width` row-major array, into `output`
+    pub(crate) fn transpose<T: Copy + Send + Sync>(&self, input: &[T], output: &mut [T], width: usize, height: usize) {
+        if !self.is_parallel(input.len()) || width < 2 || height < 2 {
+            transpose::transpose(input, output, width, height);
+            return;
+        }
+
+        // each task writes a band of consecutive output rows, which corresponds to a band of input columns
+        let rows_per_task = self.task_len(width, TRANSPOSE_BLOCK_SIZE) / TRANSPOSE_BLOCK_SIZE * TRANSPOSE_BLOCK_SIZE;
+        let rows_per_task = cmp::max(rows_per_task, TRANSPOSE_BLOCK_SIZE);
+        self.install(|| {
+            output.par_chunks_mut(rows_per_task * height).enumerate().for_each(|(task_index, band)| {
+                let x_start = task_index * rows_per_task;
+                let band_width = band.len() / height;
+                transpose_band(input, band, width, height, x_start, band_width);
+            });
+        });
+    }
+}
+
+/// Copies columns `x_start..x_start + band_width` of the `height` x `width` array `input` into the rows of `band`
+#[cfg(feature = "rayon")]
+fn transpose_band<T: Copy>(input: &[T], band: &mut [T], width: usize, height: usize, x_start: usize, band_width: usize) {
+    // the start of every block along each axis. step_by needs rustc 1.28
+    let block_starts = |len: usize| (0..(len + TRANSPOSE_BLOCK_SIZE - 1) / TRANSPOSE_BLOCK_SIZE).map(|block| block * TRANSPOSE_BLOCK_SIZE);
+    for y_block in block_starts(height) {
+        let y_end = cmp::min(y_block + TRANSPOSE_BLOCK_SIZE, height);
+        for x_block in block_starts(band_width) {
+            let x_end = cmp::min(x_block + TRANSPOSE_BLOCK_SIZE, band_width);
+            for x in x_block..x_end {
+                for y in y_block..y_end {
+                    band[x * height + y] = input[y * width + x_start + x];
+                }
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "rayon"))]
+impl Parallelism {
+    /// Divides `input` and `output` into chunks of length `chunk_len`, and calls `f` on each pair of chunks
+    #[inline(always)]
+    pub(crate) fn for_each_chunk<T, F>(&self, input: &mut [T], output: &mut [T], chunk_len: usize, f: F)
+        where F: Fn(&mut [T], &mut [T])
+    {
+        for (in_chunk, out_chunk) in input.chunks_mut(chunk_len).zip(output.chunks_mut(chunk_len)) {
+            f(in_chunk, out_chunk);
+        }
+    }
+
+    /// Computes `fft.process_multi()` on the given buffers
+    #[inline(always)]
+    pub(crate) fn process_multi<T: FFTnum, F: FFT<T> + ?Sized>(&self, fft: &F, input: &mut [Complex<T>], output: &mut [Complex<T>]) {
+        fft.process_multi(input, output);
+    }
+
+    /// Divides `buffer` into rows of length `row_len`, and calls `f` with the index and contents of each row
+    #[inline(always)]
+    pub(crate) fn for_each_row<T, F>(&self, buffer: &mut [T], row_len: usize, f: F)
+        where F: Fn(usize, &mut [T])
+    {
+        for (index, row) in buffer.chunks_mut(row_len).enumerate() {
+            f(index, row);
+        }
+    }
+
+    /// Transposes `input`, a `height` x `width` row-major array, into `output`
+    #[inline(always)]
+    pub(crate) fn transpose<T: Copy>(&self, input: &[T], output: &mut [T], width: usize, height: usize) {
+        transpose::transpose(input, output, width, height);
+    }
+}
+
+#[cfg(all(test, feature = "rayon"))]
+mod unit_tests {
+    use super::*;
+    use alloc::sync::Arc;
+    use algorithm::{DFT, MixedRadix, GoodThomasAlgorithm};
+    use test_utils::{check_fft_algorithm, random_signal, compare_vectors};
+    use num_traits::Zero;
+    use FFTplanner;
+
+    #[test]
+    fn test_parallel_transpose() {
+        let parallelism = Parallelism::with_num_threads(4).with_min_len(0);
+        for &(width, height) in &[(2, 2), (3, 70), (70, 3), (33, 47), (64, 64)] {
+            let input: Vec<usize> = (0..width * height).collect();
+            let mut expected = vec![0; width * height];
+            let mut actual = vec![0; width * height];
+
+            transpose::transpose(&input, &mut expected, width, height);
+            parallelism.transpose(&input, &mut actual, width, height);
+
+            assert_eq!(expected, actual, "width = {}, height = {}", width, height);
+        }
+    }
+
+    #[test]
+    fn test_parallel_algorithms() {
+        let parallelism = Parallelism::with_num_threads(3).with_min_len(0);
+        for &(width, height) in &[(4, 5), (9, 16), (7, 2)] {
+            for &inverse in &[false, true] {
+                let width_fft = Arc::new(DFT::new(width, inverse)) as Arc<FFT<f32>>;
+                let height_fft = Arc::new(DFT::new(height, inverse)) as Arc<FFT<f32>>;
+
+                let mixed_radix = MixedRadix::new(Arc::clone(&width_fft), Arc::clone(&height_fft)).with_parallelism(parallelism.clone());
+                check_fft_algorithm(&mixed_radix, width * height, inverse);
+
+                let good_thomas = GoodThomasAlgorithm::new(width_fft, height_fft).with_parallelism(parallelism.clone());
+                check_fft_algorithm(&good_thomas, width * height, inverse);
+            }
+        }
+    }
+
+    #[test]
+    fn test_process_multi_parallel() {
+        for &(len, count) in &[(1, 7), (16, 1), (30, 100), (97, 33)] {
+            let mut planner = FFTplanner::new(false);
+            let fft = planner.plan_fft(len);
+
+            let mut serial_input = random_signal(len * count);
+            let mut parallel_input = serial_input.clone();
+            let mut serial_output = vec![Zero::zero(); len * count];
+            let mut parallel_output = vec![Zero::zero(); len * count];
+
+            fft.process_multi(&mut serial_input, &mut serial_output);
+            fft.process_multi_parallel(&mut parallel_input, &mut parallel_output);
+
+            assert!(compare_vectors(&serial_output, &parallel_output), "len = {}, count = {}", len, count);
+        }
+    }
+}
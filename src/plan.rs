@@ -1,20 +1,58 @@
-use std::collections::HashMap;
-use std::sync::Arc;
+use core::cmp;
+#[cfg(feature = "half")]
+use core::any::{Any, TypeId};
+use alloc::sync::Arc;
+#[cfg(feature = "std")]
+use std::sync::{Mutex, MutexGuard};
+// without std there's no HashMap, so the planner caches its plans and instances in a BTreeMap instead
+#[cfg(feature = "std")]
+use std::collections::HashMap as Map;
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeMap as Map;
+use alloc::vec::Vec;
+use alloc::boxed::Box;
 use num_integer::gcd;
 
 use common::FFTnum;
 
 use FFT;
+use parallel::Parallelism;
+use cost_model::{CostModel, DefaultCostModel};
 use algorithm::*;
-use algorithm::butterflies::*;
+use algorithm::butterflies::{self, FFTButterfly, BUTTERFLIES};
 
 use math_utils;
 
 
-const MIN_RADIX4_BITS: u32 = 5; // smallest size to consider radix 4 an option is 2^5 = 32
-const MAX_RADIX4_BITS: u32 = 16; // largest size to consider radix 4 an option is 2^16 = 65536
-const BUTTERFLIES: [usize; 9] = [2, 3, 4, 5, 6, 7, 8, 16, 32];
-const COMPOSITE_BUTTERFLIES: [usize; 5] = [4, 6, 8, 16, 32];
+
+/// The algorithm chosen by the planner for a particular size
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum PlannedAlgorithm {
+    Dft,
+    Butterfly,
+    Radix4,
+    Stockham,
+    RadixN,
+    Raders,
+    RadersZeroPadded { inner_len: usize },
+    PrimePower { radix: usize, exponent: usize },
+    MixedRadix { width: usize, height: usize },
+    FourStep { width: usize, height: usize },
+    GoodThomas { width: usize, height: usize },
+}
+
+/// The cheapest way the planner has found to compute a FFT of a particular size, and its estimated cost
+#[derive(Debug, Clone, Copy)]
+struct Plan {
+    algorithm: PlannedAlgorithm,
+    cost: f64,
+}
+
+impl Plan {
+    fn is_butterfly(&self) -> bool {
+        self.algorithm == PlannedAlgorithm::Butterfly
+    }
+}
 
 /// The FFT planner is used to make new FFT algorithm instances.
 ///
@@ -46,10 +84,28 @@ const COMPOSITE_BUTTERFLIES: [usize; 5] = [4, 6, 8, 16, 32];
 ///
 /// Each FFT instance owns `Arc`s to its internal data, rather than borrowing it from the planner, so it's perfectly
 /// safe to drop the planner after creating FFT instances.
+///
+/// With the `rayon` feature enabled, large FFTs created by the planner will split their work across rayon's global
+/// thread pool. Use [`set_parallelism`](struct.FFTplanner.html#method.set_parallelism) to choose a different thread
+/// pool size, or to disable multithreading.
+///
+/// The planner chooses between algorithms by searching every way of factoring the FFT size, and picking the one with
+/// the lowest estimated cost. Use [`set_cost_model`](struct.FFTplanner.html#method.set_cost_model) to supply your own
+/// estimates.
+///
+/// The search only evaluates each divisor of the size once, and remembers the best plan for each of them, so it stays
+/// fast even for sizes with many divisors, and planning any of those divisors afterwards with the same planner doesn't
+/// search again.
 pub struct FFTplanner<T> {
     inverse: bool,
-    algorithm_cache: HashMap<usize, Arc<FFT<T>>>,
-    butterfly_cache: HashMap<usize, Arc<FFTButterfly<T>>>,
+    parallelism: Parallelism,
+    cost_model: Arc<CostModel>,
+    plan_cache: Map<usize, Plan>,
+    algorithm_cache: Map<usize, Arc<FFT<T>>>,
+    butterfly_cache: Map<usize, Arc<FFTButterfly<T>>>,
+
+    // plans the f32 FFTs used by types that compute in f32, like half-precision floats
+    widened_planner: Option<Box<FFTplanner<f32>>>,
 }
 
 impl<T: FFTnum> FFTplanner<T> {
@@ -59,159 +115,559 @@ impl<T: FFTnum> FFTplanner<T> {
     pub fn new(inverse: bool) -> Self {
         FFTplanner {
             inverse: inverse,
-            algorithm_cache: HashMap::new(),
-            butterfly_cache: HashMap::new(),
+            parallelism: Parallelism::default(),
+            cost_model: Arc::new(DefaultCostModel),
+            plan_cache: Map::new(),
+            algorithm_cache: Map::new(),
+            butterfly_cache: Map::new(),
+            widened_planner: None,
+        }
+    }
+
+    /// Sets how FFT instances planned after this call will split their work across threads
+    ///
+    /// ~~~
+    /// // Plan a large FFT that uses no more than 4 threads
+    /// # #[cfg(feature = "rayon")] {
+    /// use rustfft::{FFTplanner, Parallelism};
+    ///
+    /// let mut planner = FFTplanner::<f64>::new(false);
+    /// planner.set_parallelism(Parallelism::with_num_threads(4));
+    /// let fft = planner.plan_fft(1 << 20);
+    /// # }
+    /// ~~~
+    pub fn set_parallelism(&mut self, parallelism: Parallelism) {
+        if let Some(ref mut widened_planner) = self.widened_planner {
+            widened_planner.set_parallelism(parallelism.clone());
         }
+        self.parallelism = parallelism;
+
+        // previously planned FFTs still use the old setting, so they can't be reused by new FFTs
+        self.algorithm_cache.clear();
+    }
+
+    /// Sets the cost model used to choose between algorithms for FFT instances planned after this call
+    ///
+    /// See [`CostModel`](trait.CostModel.html) for details.
+    pub fn set_cost_model(&mut self, cost_model: Arc<CostModel>) {
+        if let Some(ref mut widened_planner) = self.widened_planner {
+            widened_planner.set_cost_model(Arc::clone(&cost_model));
+        }
+        self.cost_model = cost_model;
+
+        // previously planned FFTs were chosen with the old estimates, so they can't be reused by new FFTs
+        self.plan_cache.clear();
+        self.algorithm_cache.clear();
+    }
+
+    /// Returns true if this planner plans inverse FFTs
+    #[cfg(feature = "const-generics")]
+    pub(crate) fn is_inverse(&self) -> bool {
+        self.inverse
     }
 
     /// Returns a FFT instance which processes signals of size `len`
     /// If this is called multiple times, it will attempt to re-use internal data between instances
     pub fn plan_fft(&mut self, len: usize) -> Arc<FFT<T>> {
-        if len < 2 {
-            Arc::new(DFT::new(len, self.inverse)) as Arc<FFT<T>>
-        } else {
-            let factors = math_utils::prime_factors(len);
-            self.plan_fft_with_factors(len, &factors)
+        if let Some(instance) = self.algorithm_cache.get(&len) {
+            return Arc::clone(instance);
         }
-    }
 
-    fn plan_butterfly(&mut self, len: usize) -> Arc<FFTButterfly<T>> {
-        let inverse = self.inverse;
-        let instance = self.butterfly_cache.entry(len).or_insert_with(|| 
-            match len {
-                2 => Arc::new(Butterfly2::new(inverse)),
-                3 => Arc::new(Butterfly3::new(inverse)),
-                4 => Arc::new(Butterfly4::new(inverse)),
-                5 => Arc::new(Butterfly5::new(inverse)),
-                6 => Arc::new(Butterfly6::new(inverse)),
-                7 => Arc::new(Butterfly7::new(inverse)),
-                8 => Arc::new(Butterfly8::new(inverse)),
-                16 => Arc::new(Butterfly16::new(inverse)),
-                32 => Arc::new(Butterfly32::new(inverse)),
-                _ => panic!("Invalid butterfly size: {}", len),
+        if let Some(instance) = self.plan_widened(len) {
+            self.algorithm_cache.insert(len, Arc::clone(&instance));
+            return instance;
+        }
+
+        let instance = match self.choose_plan(len).algorithm {
+            PlannedAlgorithm::Dft => Arc::new(DFT::new(len, self.inverse)) as Arc<FFT<T>>,
+            PlannedAlgorithm::Butterfly => butterflies::make_butterfly_fft(len, self.inverse),
+            PlannedAlgorithm::Radix4 => Arc::new(Radix4::new(len, self.inverse)) as Arc<FFT<T>>,
+            PlannedAlgorithm::Stockham => Arc::new(Stockham::new(len, self.inverse)) as Arc<FFT<T>>,
+            PlannedAlgorithm::RadixN => self.plan_radix_n(len),
+            PlannedAlgorithm::Raders => {
+                let inner_fft = self.plan_fft(len - 1);
+                Arc::new(RadersAlgorithm::new(len, inner_fft)) as Arc<FFT<T>>
             }
-        );
-        Arc::clone(instance)
+            PlannedAlgorithm::RadersZeroPadded { inner_len } => {
+                let inner_fft = self.plan_fft(inner_len);
+                Arc::new(RadersAlgorithm::new(len, inner_fft)) as Arc<FFT<T>>
+            }
+            PlannedAlgorithm::PrimePower { radix, exponent } => {
+                let inner_fft = self.plan_fft(radix);
+                Arc::new(PrimePower::new(inner_fft, exponent).with_parallelism(self.parallelism.clone())) as Arc<FFT<T>>
+            }
+            PlannedAlgorithm::MixedRadix { width, height } => self.plan_mixed_radix(width, height),
+            PlannedAlgorithm::FourStep { width, height } => {
+                let width_fft = self.plan_fft(width);
+                let height_fft = self.plan_fft(height);
+                Arc::new(FourStep::new(width_fft, height_fft).with_parallelism(self.parallelism.clone())) as Arc<FFT<T>>
+            }
+            PlannedAlgorithm::GoodThomas { width, height } => self.plan_good_thomas(width, height),
+        };
+        self.algorithm_cache.insert(len, Arc::clone(&instance));
+        instance
     }
-    
-    fn plan_fft_with_factors(&mut self, len: usize, factors: &[usize]) -> Arc<FFT<T>> {
-        if self.algorithm_cache.contains_key(&len) {
-            Arc::clone(self.algorithm_cache.get(&len).unwrap())
-        } else {
-            let result = if factors.len() == 1 || COMPOSITE_BUTTERFLIES.contains(&len) {
-                self.plan_fft_single_factor(len)
 
-            } else if len.trailing_zeros() <= MAX_RADIX4_BITS && len.trailing_zeros() >= MIN_RADIX4_BITS {
-                //the number of trailing zeroes in len is the number of `2` factors
-                //ie if len = 2048 * n, len.trailing_zeros() will equal 11 because 2^11 == 2048
+    /// For types that compute their FFTs in f32, plans a f32 FFT of size `len` with this planner's settings, and returns
+    /// it wrapped so that it processes T. Returns None for every other type
+    ///
+    /// Half-precision types round every intermediate result to 11 (f16) or 8 (bf16) bits, and their arithmetic is
+    /// emulated with f32 anyway, so they compute their FFTs in f32, with f32 twiddle factors
+    #[cfg(feature = "half")]
+    pub(crate) fn plan_widened(&mut self, len: usize) -> Option<Arc<FFT<T>>> {
+        self.plan_widened_as::<::half::f16>(len).or_else(|| self.plan_widened_as::<::half::bf16>(len))
+    }
 
-                if len.is_power_of_two() {
-                    Arc::new(Radix4::new(len, self.inverse))
-                } else {
-                    let left_len = 1 << len.trailing_zeros();
-                    let right_len = len / left_len;
+    #[cfg(not(feature = "half"))]
+    pub(crate) fn plan_widened(&mut self, _len: usize) -> Option<Arc<FFT<T>>> {
+        None
+    }
 
-                    let (left_factors, right_factors) = factors.split_at(len.trailing_zeros() as usize);
+    /// If T is `H`, plans a f32 FFT of size `len`, and returns it wrapped in a `Widened<H>`. Returns None otherwise
+    #[cfg(feature = "half")]
+    fn plan_widened_as<H: FFTnum + Into<f32>>(&mut self, len: usize) -> Option<Arc<FFT<T>>> {
+        if TypeId::of::<T>() != TypeId::of::<H>() {
+            return None;
+        }
+
+        let (inverse, parallelism, cost_model) = (self.inverse, &self.parallelism, &self.cost_model);
+        let inner_fft = self.widened_planner.get_or_insert_with(|| {
+            let mut planner = FFTplanner::new(inverse);
+            planner.set_parallelism(parallelism.clone());
+            planner.set_cost_model(Arc::clone(cost_model));
+            Box::new(planner)
+        }).plan_fft(len);
+
+        // T and H are the same type, so this downcast always succeeds
+        let fft: Box<Any> = Box::new(Arc::new(Widened::<H>::new(inner_fft)) as Arc<FFT<H>>);
+        fft.downcast::<Arc<FFT<T>>>().ok().map(|fft| *fft)
+    }
+
+    /// Returns the algorithm this planner would use for a FFT of size `len`, so that FixedFFT can store it by value
+    #[cfg(feature = "const-generics")]
+    pub(crate) fn planned_algorithm(&mut self, len: usize) -> PlannedAlgorithm {
+        self.choose_plan(len).algorithm
+    }
 
-                    self.plan_mixed_radix(left_len, left_factors, right_len, right_factors)
+    /// Finds the cheapest plan for a FFT of size `len`, according to the cost model
+    ///
+    /// The best plan for every size visited by the search is memoized, so planning many sizes that share divisors
+    /// only evaluates each of those divisors once
+    fn choose_plan(&mut self, len: usize) -> Plan {
+        if let Some(&plan) = self.plan_cache.get(&len) {
+            return plan;
+        }
+
+        let cost_model = Arc::clone(&self.cost_model);
+        let mut best = Plan { algorithm: PlannedAlgorithm::Dft, cost: cost_model.dft(len) };
+        {
+            let mut consider = |algorithm: PlannedAlgorithm, cost: f64| {
+                if cost < best.cost {
+                    best = Plan { algorithm, cost };
                 }
+            };
 
-            } else {
-                let sqrt = (len as f32).sqrt() as usize;
-                if sqrt * sqrt == len {
-                    // since len is a perfect square, each of its prime factors is duplicated.
-                    // since we know they're sorted, we can loop through them in chunks of 2 and keep one out of each chunk
-                    // if the stride iterator ever becomes stabilized, it'll be cleaner to use that instead of chunks
-                    let mut sqrt_factors = Vec::with_capacity(factors.len() / 2);
-                    for chunk in factors.chunks(2) {
-                        sqrt_factors.push(chunk[0]);
+            if BUTTERFLIES.contains(&len) {
+                consider(PlannedAlgorithm::Butterfly, cost_model.butterfly(len));
+            }
+            if len > 1 && len.is_power_of_two() {
+                consider(PlannedAlgorithm::Radix4, cost_model.radix4(len));
+            }
+
+            if len > 1 {
+                let factors = math_utils::prime_factors(len);
+                if factors.iter().all(|&factor| factor <= 5) {
+                    consider(PlannedAlgorithm::Stockham, cost_model.stockham(len));
+                }
+                if let Some(radices) = radix_n_radices(&factors) {
+                    if radices.len() > 1 {
+                        let butterfly_cost: f64 = radices.iter()
+                            .map(|&radix| (len / radix) as f64 * cost_model.butterfly(radix))
+                            .sum();
+                        consider(PlannedAlgorithm::RadixN, cost_model.radix_n(len, radices.len()) + butterfly_cost);
                     }
+                }
+                if factors.len() == 1 {
+                    let inner_plan = self.choose_plan(len - 1);
+                    consider(PlannedAlgorithm::Raders, cost_model.raders(len) + 2.0 * inner_plan.cost);
 
-                    self.plan_mixed_radix(sqrt, &sqrt_factors, sqrt, &sqrt_factors)
+                    // when len - 1 has a large prime factor, a bigger convolution with only small factors can be faster.
+                    // padded sizes too large for a usize are skipped
+                    let min_inner_len = len.checked_mul(2).map(|double_len| double_len - 3);
+                    let padded_lens = [
+                        min_inner_len.and_then(|min_len| min_len.checked_next_power_of_two()),
+                        min_inner_len.and_then(math_utils::next_smooth),
+                    ];
+                    for inner_len in padded_lens.iter().filter_map(|&inner_len| inner_len) {
+                        // for len 3, the padded size can be 3 itself, which we're still planning
+                        if inner_len > len {
+                            let inner_plan = self.choose_plan(inner_len);
+                            consider(PlannedAlgorithm::RadersZeroPadded { inner_len },
+                                     cost_model.raders_zero_padded(len, inner_len) + 2.0 * inner_plan.cost);
+                        }
+                    }
                 } else {
-                    //len isn't a perfect square. greedily take factors from the list until both sides are as close as possible to sqrt(len)
-                    //TODO: We can probably make this more optimal by using a more sophisticated non-greedy algorithm
-                    let mut product = 1;
-                    let mut second_half_index = 1;
-                    for (i, factor) in factors.iter().enumerate() {
-                        if product * *factor > sqrt {
-                            second_half_index = i;
-                            break;
+                    if factors.iter().all(|&factor| factor == factors[0]) {
+                        // every stage computes len / radix inner FFTs
+                        let (radix, exponent) = (factors[0], factors.len());
+                        let inner_cost = (exponent * (len / radix)) as f64 * self.choose_plan(radix).cost;
+                        consider(PlannedAlgorithm::PrimePower { radix, exponent }, cost_model.prime_power(len, exponent) + inner_cost);
+                    }
+
+                    // try the most balanced splits first, so that if several splits have the same cost, we use the
+                    // one whose inner FFTs are the smallest
+                    let mut widths = math_utils::divisors(&factors);
+                    widths.retain(|&width| width != 1 && width != len);
+                    widths.sort_by_key(|&width| cmp::max(width, len / width));
+
+                    for width in widths {
+                        let height = len / width;
+                        let width_plan = self.choose_plan(width);
+                        let height_plan = self.choose_plan(height);
+
+                        // we compute `height` FFTs of size `width`, and `width` FFTs of size `height`
+                        let inner_cost = height as f64 * width_plan.cost + width as f64 * height_plan.cost;
+                        let butterflies = width_plan.is_butterfly() && height_plan.is_butterfly();
+
+                        let mixed_radix_cost = if butterflies {
+                            cost_model.mixed_radix_double_butterfly(width, height)
                         } else {
-                            product = product * *factor;
+                            cost_model.mixed_radix(width, height)
+                        };
+                        consider(PlannedAlgorithm::MixedRadix { width, height }, mixed_radix_cost + inner_cost);
+                        consider(PlannedAlgorithm::FourStep { width, height }, cost_model.four_step(width, height) + inner_cost);
+
+                        if gcd(width, height) == 1 {
+                            let good_thomas_cost = if butterflies {
+                                cost_model.good_thomas_double_butterfly(width, height)
+                            } else {
+                                cost_model.good_thomas(width, height)
+                            };
+                            consider(PlannedAlgorithm::GoodThomas { width, height }, good_thomas_cost + inner_cost);
                         }
                     }
+                }
+            }
+        }
 
-                    //we now know that product is the largest it can be without being greater than len / product
-                    //there's one more thing we can try to make them closer together -- if product * factors[index] < len / product,
-                    if product * factors[second_half_index] < len / product {
-                        product = product * factors[second_half_index];
-                        second_half_index = second_half_index + 1;
-                    }
+        self.plan_cache.insert(len, best);
+        best
+    }
 
-                    //we now have our two FFT sizes: product and product / len
-                    let (left_factors, right_factors) = factors.split_at(second_half_index);
-                    self.plan_mixed_radix(product, left_factors, len / product, right_factors)
-                }
-            };
-            self.algorithm_cache.insert(len, Arc::clone(&result));
-            result
+    fn plan_butterfly(&mut self, len: usize) -> Arc<FFTButterfly<T>> {
+        let inverse = self.inverse;
+        let instance = self.butterfly_cache.entry(len).or_insert_with(|| butterflies::make_butterfly(len, inverse));
+        Arc::clone(instance)
+    }
+
+    fn plan_radix_n(&mut self, len: usize) -> Arc<FFT<T>> {
+        let radices = radix_n_radices(&math_utils::prime_factors(len)).unwrap();
+        let butterflies = radices.into_iter().map(|radix| self.plan_butterfly(radix)).collect();
+
+        Arc::new(RadixN::new(butterflies)) as Arc<FFT<T>>
+    }
+
+    fn plan_mixed_radix(&mut self, width: usize, height: usize) -> Arc<FFT<T>> {
+        //if both sizes are planned as butterflies, use a mixed radix implementation specialized for butterfly sub-FFTs
+        if self.choose_plan(width).is_butterfly() && self.choose_plan(height).is_butterfly() {
+            let width_fft = self.plan_butterfly(width);
+            let height_fft = self.plan_butterfly(height);
+
+            Arc::new(MixedRadixDoubleButterfly::new(width_fft, height_fft)) as Arc<FFT<T>>
+        } else {
+            let width_fft = self.plan_fft(width);
+            let height_fft = self.plan_fft(height);
+
+            Arc::new(MixedRadix::new(width_fft, height_fft).with_parallelism(self.parallelism.clone())) as Arc<FFT<T>>
         }
     }
 
-    fn plan_mixed_radix(&mut self,
-                        left_len: usize,
-                        left_factors: &[usize],
-                        right_len: usize,
-                        right_factors: &[usize])
-                        -> Arc<FFT<T>> {
+    fn plan_good_thomas(&mut self, width: usize, height: usize) -> Arc<FFT<T>> {
+        if self.choose_plan(width).is_butterfly() && self.choose_plan(height).is_butterfly() {
+            let width_fft = self.plan_butterfly(width);
+            let height_fft = self.plan_butterfly(height);
+
+            Arc::new(GoodThomasAlgorithmDoubleButterfly::new(width_fft, height_fft)) as Arc<FFT<T>>
+        } else {
+            let width_fft = self.plan_fft(width);
+            let height_fft = self.plan_fft(height);
+
+            Arc::new(GoodThomasAlgorithm::new(width_fft, height_fft).with_parallelism(self.parallelism.clone())) as Arc<FFT<T>>
+        }
+    }
+}
 
-        let left_is_butterfly = BUTTERFLIES.contains(&left_len);
-        let right_is_butterfly = BUTTERFLIES.contains(&right_len);
 
-        //if both left_len and right_len are butterflies, use a mixed radix implementation specialized for butterfly sub-FFTs
-        if left_is_butterfly && right_is_butterfly {
-            let left_fft = self.plan_butterfly(left_len);
-            let right_fft = self.plan_butterfly(right_len);
+/// Splits the number whose prime factors are `factors` into the butterfly sizes used by the RadixN algorithm, or
+/// returns None if it has a prime factor without a butterfly
+pub(crate) fn radix_n_radices(factors: &[usize]) -> Option<Vec<usize>> {
+    if !factors.iter().all(|factor| BUTTERFLIES.contains(factor)) {
+        return None;
+    }
 
-            // for butterflies, if gcd is 1, we always want to use good-thomas
-            if gcd(left_len, right_len) == 1 {
-                Arc::new(GoodThomasAlgorithmDoubleButterfly::new(left_fft, right_fft)) as Arc<FFT<T>>
-            } else {
-                Arc::new(MixedRadixDoubleButterfly::new(left_fft, right_fft)) as Arc<FFT<T>>
-            }
+    // fewer, larger butterflies mean fewer passes over the data
+    let twos = factors.iter().filter(|&&factor| factor == 2).count();
+    let mut radices = vec![8; twos / 3];
+    if twos % 3 == 2 {
+        radices.push(4);
+    } else if twos % 3 == 1 {
+        // replace an 8 and a 2 with a 16, if there's an 8 to replace
+        if radices.is_empty() {
+            radices.push(2);
         } else {
-            //neither size is a butterfly, so go with the normal algorithm
-            let left_fft = self.plan_fft_with_factors(left_len, left_factors);
-            let right_fft = self.plan_fft_with_factors(right_len, right_factors);
+            radices[0] = 16;
+        }
+    }
+
+    let threes = factors.iter().filter(|&&factor| factor == 3).count();
+    radices.extend(vec![9; threes / 2]);
+    if threes % 2 == 1 {
+        radices.push(3);
+    }
+
+    radices.extend(factors.iter().filter(|&&factor| factor > 3));
+    Some(radices)
+}
+
+/// A thread-safe FFT planner, which can be shared between threads behind an `Arc`
+///
+/// `FFTplanner` requires `&mut self` to plan new FFTs, because it caches the algorithms and precomputed data it
+/// creates. This planner wraps a `FFTplanner` in a lock, so that every thread planning FFTs through it shares the same
+/// cache, and FFT instances created from different threads still re-use each other's internal data.
+///
+/// ~~~
+/// // Plan FFTs from several threads, sharing one cache
+/// use std::sync::Arc;
+/// use std::thread;
+/// use rustfft::ConcurrentFFTplanner;
+/// use rustfft::num_complex::Complex;
+/// use rustfft::num_traits::Zero;
+///
+/// let planner = Arc::new(ConcurrentFFTplanner::<f32>::new(false));
+///
+/// let threads: Vec<_> = (0..4).map(|_| {
+///     let planner = Arc::clone(&planner);
+///     thread::spawn(move || {
+///         let fft = planner.plan_fft(1234);
+///
+///         let mut input:  Vec<Complex<f32>> = vec![Zero::zero(); 1234];
+///         let mut output: Vec<Complex<f32>> = vec![Zero::zero(); 1234];
+///         fft.process(&mut input, &mut output);
+///     })
+/// }).collect();
+///
+/// for thread in threads {
+///     thread.join().unwrap();
+/// }
+/// ~~~
+///
+/// Planning is done while holding the lock, so threads planning FFTs at the same time will wait for each other. Once
+/// an FFT instance has been created, using it doesn't involve the planner at all.
+///
+/// This planner is only available with the `std` feature.
+#[cfg(feature = "std")]
+pub struct ConcurrentFFTplanner<T> {
+    planner: Mutex<FFTplanner<T>>,
+}
+
+#[cfg(feature = "std")]
+impl<T: FFTnum> ConcurrentFFTplanner<T> {
+    /// Creates a new thread-safe FFT planner.
+    ///
+    /// If `inverse` is false, this planner will plan forward FFTs. If `inverse` is true, it will plan inverse FFTs.
+    pub fn new(inverse: bool) -> Self {
+        ConcurrentFFTplanner {
+            planner: Mutex::new(FFTplanner::new(inverse)),
+        }
+    }
+
+    /// Returns a FFT instance which processes signals of size `len`
+    /// If this is called multiple times, from any thread, it will attempt to re-use internal data between instances
+    pub fn plan_fft(&self, len: usize) -> Arc<FFT<T>> {
+        self.lock().plan_fft(len)
+    }
+
+    /// Sets how FFT instances planned after this call will split their work across threads
+    ///
+    /// See [`FFTplanner::set_parallelism`](struct.FFTplanner.html#method.set_parallelism)
+    pub fn set_parallelism(&self, parallelism: Parallelism) {
+        self.lock().set_parallelism(parallelism);
+    }
+
+    /// Sets the cost model used to choose between algorithms for FFT instances planned after this call
+    ///
+    /// See [`FFTplanner::set_cost_model`](struct.FFTplanner.html#method.set_cost_model)
+    pub fn set_cost_model(&self, cost_model: Arc<CostModel>) {
+        self.lock().set_cost_model(cost_model);
+    }
+
+    fn lock<'a>(&'a self) -> MutexGuard<'a, FFTplanner<T>> {
+        // the planner only adds entries to its caches after an instance has been successfully created, so if another
+        // thread panicked while planning, the cache is still valid and we can keep using it
+        self.planner.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
 
-            Arc::new(MixedRadix::new(left_fft, right_fft)) as Arc<FFT<T>>
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    #[cfg(feature = "std")]
+    use std::thread;
+    use test_utils::check_fft_algorithm;
+
+    #[test]
+    fn test_plan_sizes() {
+        for &inverse in &[false, true] {
+            let mut planner = FFTplanner::<f32>::new(inverse);
+            for len in (1..100).chain(vec![256, 360, 441, 1000, 1024, 1201, 1331]) {
+                let fft = planner.plan_fft(len);
+                assert_eq!(fft.len(), len);
+                check_fft_algorithm(&*fft, len, inverse);
+            }
         }
     }
 
+    #[test]
+    fn test_choose_plan() {
+        let mut planner = FFTplanner::<f32>::new(false);
+        assert_eq!(planner.choose_plan(8).algorithm, PlannedAlgorithm::Butterfly);
+        assert_eq!(planner.choose_plan(4096).algorithm, PlannedAlgorithm::Radix4);
+        assert_eq!(planner.choose_plan(1 << 18).algorithm, PlannedAlgorithm::Radix4);
+        assert_eq!(planner.choose_plan(48000).algorithm, PlannedAlgorithm::Stockham);
+        assert_eq!(planner.choose_plan(441).algorithm, PlannedAlgorithm::MixedRadix { width: 7, height: 63 });
+        assert_eq!(planner.choose_plan(823543).algorithm, PlannedAlgorithm::PrimePower { radix: 7, exponent: 7 });
+        assert_eq!(planner.choose_plan(44521).algorithm, PlannedAlgorithm::PrimePower { radix: 211, exponent: 2 });
+        assert_eq!(planner.choose_plan(1201).algorithm, PlannedAlgorithm::Raders);
+        assert_eq!(planner.choose_plan(10007).algorithm, PlannedAlgorithm::RadersZeroPadded { inner_len: 20250 });
+        assert_eq!(planner.choose_plan(30).algorithm, PlannedAlgorithm::GoodThomas { width: 5, height: 6 });
+        assert_eq!(planner.choose_plan(143).algorithm, PlannedAlgorithm::GoodThomas { width: 11, height: 13 });
+        assert_eq!(planner.choose_plan(31).algorithm, PlannedAlgorithm::Butterfly);
+        assert_eq!(planner.choose_plan(29 * 31).algorithm, PlannedAlgorithm::GoodThomas { width: 29, height: 31 });
 
-    fn plan_fft_single_factor(&mut self, len: usize) -> Arc<FFT<T>> {
-        match len {
-            0|1 => Arc::new(DFT::new(len, self.inverse)) as Arc<FFT<T>>,
-            2 => Arc::new(butterflies::Butterfly2::new(self.inverse)) as Arc<FFT<T>>,
-            3 => Arc::new(butterflies::Butterfly3::new(self.inverse)) as Arc<FFT<T>>,
-            4 => Arc::new(butterflies::Butterfly4::new(self.inverse)) as Arc<FFT<T>>,
-            5 => Arc::new(butterflies::Butterfly5::new(self.inverse)) as Arc<FFT<T>>,
-            6 => Arc::new(butterflies::Butterfly6::new(self.inverse)) as Arc<FFT<T>>,
-            7 => Arc::new(butterflies::Butterfly7::new(self.inverse)) as Arc<FFT<T>>,
-            8 => Arc::new(butterflies::Butterfly8::new(self.inverse)) as Arc<FFT<T>>,
-            16 => Arc::new(butterflies::Butterfly16::new(self.inverse)) as Arc<FFT<T>>,
-            32 => Arc::new(butterflies::Butterfly32::new(self.inverse)) as Arc<FFT<T>>,
-            _ => self.plan_prime(len),
+        // FourStep is only worth it for sizes that don't fit in the cache, but then it's used for every power of two
+        assert_eq!(planner.choose_plan(7 << 15).algorithm, PlannedAlgorithm::MixedRadix { width: 7, height: 1 << 15 });
+        // FourStep's scratch buffer holds at least 8 columns, so a short height keeps it small
+        assert_eq!(planner.choose_plan(7 << 17).algorithm, PlannedAlgorithm::FourStep { width: 1 << 17, height: 7 });
+        for bits in 19..31 {
+            // the split depends on how the cost of the inner FFTs compares to the cost of the scratch buffer
+            match planner.choose_plan(1 << bits).algorithm {
+                PlannedAlgorithm::FourStep { width, height } => assert_eq!(width * height, 1 << bits, "bits = {}", bits),
+                other => panic!("bits = {}: expected FourStep, got {:?}", bits, other),
+            }
         }
+
+        // the cost of each plan should include the cost of its inner FFTs
+        let cost_model = DefaultCostModel;
+        let expected_cost = cost_model.good_thomas_double_butterfly(5, 6)
+            + 6.0 * cost_model.butterfly(5) + 5.0 * cost_model.butterfly(6);
+        assert_eq!(planner.choose_plan(30).cost, expected_cost);
+    }
+
+    #[test]
+    fn test_choose_plan_huge_prime() {
+        // 2 * len - 3 doesn't fit in a usize, so the planner can't consider zero padding, but planning shouldn't overflow
+        let len = if cfg!(target_pointer_width = "64") { 18446744073709551557u64 as usize } else { 4294967291 };
+        let mut planner = FFTplanner::<f32>::new(false);
+        assert_eq!(planner.choose_plan(len).algorithm, PlannedAlgorithm::Raders);
     }
 
-    fn plan_prime(&mut self, len: usize) -> Arc<FFT<T>> {
-        let inner_fft_len = len - 1;
-        let factors = math_utils::prime_factors(inner_fft_len);
+    #[test]
+    fn test_choose_plan_memoized() {
+        // FourStep is considered once for every split of every size the search visits
+        struct CountSplits(AtomicUsize);
+        impl CostModel for CountSplits {
+            fn four_step(&self, width: usize, height: usize) -> f64 {
+                self.0.fetch_add(1, Ordering::Relaxed);
+                DefaultCostModel.four_step(width, height)
+            }
+        }
+
+        // 2^6 * 3^3 * 5^2 * 7 * 11 * 13 * 17 has 1344 divisors, and the search should visit each of them exactly once
+        let len = 735134400;
+        let cost_model = Arc::new(CountSplits(AtomicUsize::new(0)));
+        let mut planner = FFTplanner::<f32>::new(false);
+        planner.set_cost_model(Arc::clone(&cost_model) as Arc<CostModel>);
+        planner.choose_plan(len);
+
+        let divisors = math_utils::divisors(&math_utils::prime_factors(len));
+        assert_eq!(divisors.len(), 1344);
+        assert_eq!(planner.plan_cache.len(), divisors.len());
+        assert!(divisors.iter().all(|divisor| planner.plan_cache.contains_key(divisor)));
 
-        let inner_fft = self.plan_fft_with_factors(inner_fft_len, &factors);
+        let expected_splits: usize = divisors.iter()
+            .map(|&divisor| math_utils::prime_factors(divisor))
+            .filter(|factors| factors.len() > 1)
+            .map(|factors| math_utils::divisors(&factors).len() - 2)
+            .sum();
+        assert_eq!(cost_model.0.load(Ordering::Relaxed), expected_splits);
 
-        Arc::new(RadersAlgorithm::new(len, inner_fft)) as Arc<FFT<T>>
+        // planning it again only looks up the cache
+        planner.choose_plan(len);
+        assert_eq!(cost_model.0.load(Ordering::Relaxed), expected_splits);
+    }
+
+    #[test]
+    fn test_radix_n_radices() {
+        let radices = |len| radix_n_radices(&math_utils::prime_factors(len));
+        assert_eq!(radices(2), Some(vec![2]));
+        assert_eq!(radices(64), Some(vec![8, 8]));
+        assert_eq!(radices(128), Some(vec![16, 8]));
+        assert_eq!(radices(32), Some(vec![8, 4]));
+        assert_eq!(radices(4 * 27 * 7 * 7), Some(vec![4, 9, 3, 7, 7]));
+        assert_eq!(radices(823543), Some(vec![7; 7]));
+        assert_eq!(radices(29 * 4), Some(vec![4, 29]));
+        assert_eq!(radices(37 * 4), None);
+    }
+
+    #[test]
+    fn test_custom_cost_model() {
+        struct AvoidRaders;
+        impl CostModel for AvoidRaders {
+            fn raders(&self, len: usize) -> f64 {
+                1000.0 * len as f64 * len as f64
+            }
+            fn raders_zero_padded(&self, len: usize, _inner_len: usize) -> f64 {
+                self.raders(len)
+            }
+        }
+
+        let mut planner = FFTplanner::<f32>::new(false);
+        let default_fft = planner.plan_fft(1201);
+        assert_eq!(planner.choose_plan(1201).algorithm, PlannedAlgorithm::Raders);
+
+        planner.set_cost_model(Arc::new(AvoidRaders));
+        assert_eq!(planner.choose_plan(1201).algorithm, PlannedAlgorithm::Dft);
+
+        // changing the cost model should invalidate previously planned instances
+        let fft = planner.plan_fft(1201);
+        assert!(!Arc::ptr_eq(&default_fft, &fft));
+        check_fft_algorithm(&*fft, 1201, false);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_concurrent_planner() {
+        let planner = Arc::new(ConcurrentFFTplanner::<f32>::new(false));
+
+        let threads: Vec<thread::JoinHandle<Vec<Arc<FFT<f32>>>>> = (0..4).map(|_| {
+            let planner = Arc::clone(&planner);
+            thread::spawn(move || {
+                [60, 97, 128, 1200].iter().map(|&len| planner.plan_fft(len)).collect()
+            })
+        }).collect();
+
+        let results: Vec<Vec<Arc<FFT<f32>>>> = threads.into_iter().map(|thread| thread.join().unwrap()).collect();
+
+        // every thread should have received the same cached instance for each size
+        for thread_ffts in &results[1..] {
+            for (first, other) in results[0].iter().zip(thread_ffts.iter()) {
+                assert!(Arc::ptr_eq(first, other), "len = {}", first.len());
+            }
+        }
+
+        for fft in &results[0] {
+            check_fft_algorithm(&**fft, fft.len(), false);
+        }
     }
 }
@@ -0,0 +1,265 @@
+use core::cmp;
+use alloc::vec::Vec;
+
+use num_complex::Complex;
+use num_traits::Zero;
+
+use common::FFTnum;
+use plan::FFTplanner;
+
+/// Computes the [analytic signal](https://en.wikipedia.org/wiki/Analytic_signal) of a real-valued `signal`
+///
+/// The real part of the result is the original signal, and the imaginary part is its Hilbert transform. The magnitude
+/// of each element is the instantaneous amplitude (envelope) of the signal, and the argument is its instantaneous phase.
+///
+/// This is computed by performing a forward FFT of the signal, zeroing the negative frequencies and doubling the
+/// positive frequencies, then performing an inverse FFT. For even lengths, the Nyquist bin is shared between the
+/// positive and negative frequencies, so it is left unchanged, along with the DC bin.
+///
+/// ~~~
+/// // Compute the envelope of a real signal
+/// use rustfft::signal::analytic_signal;
+///
+/// let signal: Vec<f32> = (0..64).map(|i| (i as f32 * 0.5).cos()).collect();
+///
+/// let analytic = analytic_signal(&signal);
+/// let envelope: Vec<f32> = analytic.iter().map(|c| c.norm_sqr().sqrt()).collect();
+/// ~~~
+pub fn analytic_signal<T: FFTnum>(signal: &[T]) -> Vec<Complex<T>> {
+    let len = signal.len();
+    if len == 0 {
+        return Vec::new();
+    }
+
+    let mut buffer: Vec<Complex<T>> = signal.iter().map(|&x| Complex::new(x, Zero::zero())).collect();
+    let mut spectrum = vec![Zero::zero(); len];
+
+    let mut planner = FFTplanner::new(false);
+    let fft = planner.plan_fft(len);
+    fft.process(&mut buffer, &mut spectrum);
+
+    // the positive frequencies are bins 1..positive_end. if len is even, bin len/2 is the nyquist frequency and is left alone
+    let two = T::from_f32(2.0).unwrap();
+    let positive_end = len - len / 2;
+    for element in &mut spectrum[1..positive_end] {
+        *element = *element * two;
+    }
+    let negative_start = len / 2 + 1;
+    for element in &mut spectrum[negative_start..] {
+        *element = Zero::zero();
+    }
+
+    let mut inverse_planner = FFTplanner::new(true);
+    let inverse_fft = inverse_planner.plan_fft(len);
+    inverse_fft.process(&mut spectrum, &mut buffer);
+
+    // the inverse FFT isn't normalized, so we have to scale by 1/len ourselves
+    let scale = T::from_f64(1f64 / len as f64).unwrap();
+    for element in &mut buffer {
+        *element = *element * scale;
+    }
+
+    buffer
+}
+
+/// Computes the [Hilbert transform](https://en.wikipedia.org/wiki/Hilbert_transform) of a real-valued `signal`
+///
+/// This is the imaginary part of the [`analytic_signal`](fn.analytic_signal.html) of the input. For example, the
+/// Hilbert transform of a cosine is a sine of the same frequency.
+///
+/// ~~~
+/// // Compute the Hilbert transform of a real signal
+/// use rustfft::signal::hilbert;
+///
+/// let signal: Vec<f64> = (0..100).map(|i| (i as f64 * 0.25).cos()).collect();
+///
+/// let transformed = hilbert(&signal);
+/// assert_eq!(transformed.len(), signal.len());
+/// ~~~
+pub fn hilbert<T: FFTnum>(signal: &[T]) -> Vec<T> {
+    analytic_signal(signal).into_iter().map(|c| c.im).collect()
+}
+
+/// Resamples `signal` to `num_samples` samples using Fourier interpolation, similar to SciPy's `signal.resample`
+///
+/// The signal is assumed to be periodic. This is computed by performing a forward FFT of size `signal.len()`, then
+/// zero-padding the spectrum (to upsample) or truncating its highest frequencies (to downsample), then performing an
+/// inverse FFT of size `num_samples`. The output is scaled so that the amplitude of each sinusoid is preserved.
+///
+/// When the smaller of the two lengths is even, its Nyquist bin is ambiguous: when upsampling, the Nyquist component
+/// of the input is split evenly between the positive and negative frequencies of the output, and when downsampling,
+/// the positive and negative components of the input are summed into the Nyquist bin of the output.
+///
+/// ~~~
+/// // Upsample a signal from 100 samples to 250 samples
+/// use rustfft::signal::resample;
+/// use rustfft::num_complex::Complex;
+///
+/// let signal: Vec<Complex<f32>> = (0..100).map(|i| Complex::new((i as f32 * 0.1).sin(), 0.0)).collect();
+///
+/// let resampled = resample(&signal, 250);
+/// assert_eq!(resampled.len(), 250);
+/// ~~~
+pub fn resample<T: FFTnum>(signal: &[Complex<T>], num_samples: usize) -> Vec<Complex<T>> {
+    let input_len = signal.len();
+    if input_len == 0 || num_samples == 0 {
+        return vec![Zero::zero(); num_samples];
+    }
+
+    let mut input_buffer = signal.to_vec();
+    let mut input_spectrum = vec![Zero::zero(); input_len];
+
+    let mut planner = FFTplanner::new(false);
+    let fft = planner.plan_fft(input_len);
+    fft.process(&mut input_buffer, &mut input_spectrum);
+
+    // copy the positive frequencies (including the nyquist bin, if there is one) and the negative frequencies
+    // of the smaller of the two lengths. everything between them is left as zero
+    let min_len = cmp::min(num_samples, input_len);
+    let positive_end = min_len / 2 + 1;
+    let negative_count = min_len - positive_end;
+
+    let mut output_spectrum = vec![Zero::zero(); num_samples];
+    output_spectrum[..positive_end].copy_from_slice(&input_spectrum[..positive_end]);
+    output_spectrum[num_samples - negative_count..].copy_from_slice(&input_spectrum[input_len - negative_count..]);
+
+    // if min_len is even, the bin at min_len/2 is the nyquist frequency, which needs special handling
+    if min_len % 2 == 0 {
+        let nyquist = min_len / 2;
+        if num_samples < input_len {
+            // downsampling: the +nyquist and -nyquist components of the input both fold into the output's nyquist bin
+            output_spectrum[nyquist] = output_spectrum[nyquist] + input_spectrum[input_len - nyquist];
+        } else if num_samples > input_len {
+            // upsampling: split the input's nyquist component evenly between the positive and negative frequencies
+            let half = input_spectrum[nyquist] * T::from_f32(0.5).unwrap();
+            output_spectrum[nyquist] = half;
+            output_spectrum[num_samples - nyquist] = half;
+        }
+    }
+
+    let mut output = vec![Zero::zero(); num_samples];
+
+    let mut inverse_planner = FFTplanner::new(true);
+    let inverse_fft = inverse_planner.plan_fft(num_samples);
+    inverse_fft.process(&mut output_spectrum, &mut output);
+
+    // the two unnormalized FFTs scale the signal by num_samples, but we want to preserve the amplitude of each
+    // sinusoid, so we need an overall factor of 1/input_len
+    let scale = T::from_f64(1f64 / input_len as f64).unwrap();
+    for element in &mut output {
+        *element = *element * scale;
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use core::f64;
+    use test_utils::{random_signal, compare_vectors};
+
+    #[test]
+    fn test_analytic_signal_sinusoid() {
+        // for a cosine with an integer number of periods, the analytic signal is exactly e^(i*w*t)
+        for &len in &[16, 17, 30, 31, 64, 97] {
+            let periods = 3;
+            let omega = 2f64 * f64::consts::PI * periods as f64 / len as f64;
+            let signal: Vec<f64> = (0..len).map(|i| (omega * i as f64).cos()).collect();
+
+            let analytic = analytic_signal(&signal);
+            assert_eq!(analytic.len(), len);
+
+            for (i, value) in analytic.iter().enumerate() {
+                let expected = Complex::new((omega * i as f64).cos(), (omega * i as f64).sin());
+                assert!((value - expected).norm_sqr().sqrt() < 1e-10, "len = {}, i = {}, got {}, expected {}", len, i, value, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_analytic_signal_preserves_real_part() {
+        for len in 1..40 {
+            let signal: Vec<f32> = random_signal(len).iter().map(|c| c.re).collect();
+            let analytic = analytic_signal(&signal);
+
+            for (&original, value) in signal.iter().zip(analytic.iter()) {
+                assert!((original - value.re).abs() < 1e-3, "len = {}", len);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hilbert() {
+        // the hilbert transform of cos is sin
+        for &len in &[20, 21] {
+            let omega = 2f64 * f64::consts::PI * 2f64 / len as f64;
+            let signal: Vec<f64> = (0..len).map(|i| (omega * i as f64).cos()).collect();
+
+            let transformed = hilbert(&signal);
+            for (i, &value) in transformed.iter().enumerate() {
+                assert!((value - (omega * i as f64).sin()).abs() < 1e-10, "len = {}, i = {}", len, i);
+            }
+        }
+
+        let empty: Vec<f32> = Vec::new();
+        assert!(hilbert(&empty).is_empty());
+    }
+
+    #[test]
+    fn test_resample_sinusoid() {
+        // a band-limited periodic signal should be reproduced exactly at the new sample rate
+        let sizes = [8, 9, 16, 17, 20, 33, 64];
+        for &input_len in &sizes {
+            for &output_len in &sizes {
+                let signal: Vec<Complex<f64>> = (0..input_len).map(|i| test_tone(i, input_len)).collect();
+                let resampled = resample(&signal, output_len);
+
+                assert_eq!(resampled.len(), output_len);
+                for (i, value) in resampled.iter().enumerate() {
+                    let expected = test_tone(i, output_len);
+                    assert!((value - expected).norm_sqr().sqrt() < 1e-10,
+                        "input_len = {}, output_len = {}, i = {}, got {}, expected {}", input_len, output_len, i, value, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_resample_nyquist() {
+        // upsampling a signal containing only its nyquist frequency should split it into a real cosine
+        let signal: Vec<Complex<f64>> = (0..4).map(|i| Complex::new(if i % 2 == 0 { 1f64 } else { -1f64 }, 0f64)).collect();
+        let resampled = resample(&signal, 8);
+        for (i, value) in resampled.iter().enumerate() {
+            let expected = (f64::consts::PI * i as f64 / 2f64).cos();
+            assert!((value - Complex::new(expected, 0f64)).norm_sqr().sqrt() < 1e-10, "i = {}, got {}", i, value);
+        }
+
+        // downsampling an even-length signal should fold both nyquist components together
+        let signal: Vec<Complex<f64>> = (0..8).map(|i| {
+            let angle = -f64::consts::PI * i as f64 / 2f64;
+            Complex::new(angle.cos(), angle.sin())
+        }).collect();
+        let resampled = resample(&signal, 4);
+        for (i, value) in resampled.iter().enumerate() {
+            let expected = Complex::new(if i % 2 == 0 { 1f64 } else { -1f64 }, 0f64);
+            assert!((value - expected).norm_sqr().sqrt() < 1e-10, "i = {}, got {}", i, value);
+        }
+    }
+
+    #[test]
+    fn test_resample_same_length() {
+        let signal = random_signal(30);
+        let resampled = resample(&signal, 30);
+        assert!(compare_vectors(&signal, &resampled));
+
+        assert!(resample(&signal, 0).is_empty());
+    }
+
+    /// a sum of low-frequency complex sinusoids, sampled at `len` points per period
+    fn test_tone(i: usize, len: usize) -> Complex<f64> {
+        let t = 2f64 * f64::consts::PI * i as f64 / len as f64;
+        Complex::new(t.cos(), t.sin()) + Complex::new((-2f64 * t).cos(), (-2f64 * t).sin()) * 0.5f64
+            + Complex::new(0.25f64, 0f64)
+    }
+}
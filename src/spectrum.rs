@@ -0,0 +1,274 @@
+use alloc::vec::Vec;
+
+use common::FFTnum;
+
+/// Reorders the output of a FFT so that the zero-frequency element is in the center, storing the result in `output`
+///
+/// FFT outputs are ordered by ascending frequency starting at frequency 0, with the negative frequencies stored in the
+/// second half of the buffer. This function moves the negative frequencies to the front, so that the result is ordered
+/// from the most negative frequency to the most positive. For even lengths, the Nyquist element is placed first.
+///
+/// ~~~
+/// use rustfft::spectrum::fftshift;
+///
+/// let input = [0, 1, 2, 3, -4, -3, -2, -1];
+/// let mut output = [0; 8];
+///
+/// fftshift(&input, &mut output);
+/// assert_eq!(output, [-4, -3, -2, -1, 0, 1, 2, 3]);
+/// ~~~
+pub fn fftshift<T: Copy>(input: &[T], output: &mut [T]) {
+    output.copy_from_slice(input);
+    fftshift_inplace(output);
+}
+
+/// In-place version of [`fftshift`](fn.fftshift.html)
+pub fn fftshift_inplace<T>(buffer: &mut [T]) {
+    let len = buffer.len();
+    buffer.rotate_right(len / 2);
+}
+
+/// The inverse of [`fftshift`](fn.fftshift.html): Moves the zero-frequency element from the center back to the front,
+/// storing the result in `output`
+///
+/// For even lengths this is identical to `fftshift`, but for odd lengths the two functions differ by one element.
+///
+/// ~~~
+/// use rustfft::spectrum::ifftshift;
+///
+/// let input = [-2, -1, 0, 1, 2];
+/// let mut output = [0; 5];
+///
+/// ifftshift(&input, &mut output);
+/// assert_eq!(output, [0, 1, 2, -2, -1]);
+/// ~~~
+pub fn ifftshift<T: Copy>(input: &[T], output: &mut [T]) {
+    output.copy_from_slice(input);
+    ifftshift_inplace(output);
+}
+
+/// In-place version of [`ifftshift`](fn.ifftshift.html)
+pub fn ifftshift_inplace<T>(buffer: &mut [T]) {
+    let len = buffer.len();
+    buffer.rotate_left(len / 2);
+}
+
+/// Applies [`fftshift`](fn.fftshift.html) along each of the given `axes` of a multidimensional array, storing the
+/// result in `output`
+///
+/// The array is stored contiguously in row-major order, with dimensions given by `shape`. For example, a 2D array
+/// with `shape = [height, width]` is stored as `height` rows of `width` elements, and shifting both axes centers the
+/// zero frequency of a 2D FFT.
+///
+/// ~~~
+/// use rustfft::spectrum::fftshift_axes;
+///
+/// // a 2x3 array, shifted along both axes
+/// let input = [1, 2, 3,
+///              4, 5, 6];
+/// let mut output = [0; 6];
+///
+/// fftshift_axes(&input, &mut output, &[2, 3], &[0, 1]);
+/// assert_eq!(output, [6, 4, 5,
+///                     3, 1, 2]);
+/// ~~~
+pub fn fftshift_axes<T: Copy>(input: &[T], output: &mut [T], shape: &[usize], axes: &[usize]) {
+    output.copy_from_slice(input);
+    fftshift_axes_inplace(output, shape, axes);
+}
+
+/// In-place version of [`fftshift_axes`](fn.fftshift_axes.html)
+pub fn fftshift_axes_inplace<T>(buffer: &mut [T], shape: &[usize], axes: &[usize]) {
+    verify_axes(buffer, shape, axes);
+
+    for &axis in axes {
+        let axis_len = shape[axis];
+        roll_axis(buffer, shape, axis, axis_len / 2);
+    }
+}
+
+/// Applies [`ifftshift`](fn.ifftshift.html) along each of the given `axes` of a multidimensional array, storing the
+/// result in `output`
+///
+/// See [`fftshift_axes`](fn.fftshift_axes.html) for a description of the array layout.
+pub fn ifftshift_axes<T: Copy>(input: &[T], output: &mut [T], shape: &[usize], axes: &[usize]) {
+    output.copy_from_slice(input);
+    ifftshift_axes_inplace(output, shape, axes);
+}
+
+/// In-place version of [`ifftshift_axes`](fn.ifftshift_axes.html)
+pub fn ifftshift_axes_inplace<T>(buffer: &mut [T], shape: &[usize], axes: &[usize]) {
+    verify_axes(buffer, shape, axes);
+
+    for &axis in axes {
+        let axis_len = shape[axis];
+        roll_axis(buffer, shape, axis, axis_len - axis_len / 2);
+    }
+}
+
+/// Panics if `buffer` doesn't have `shape`, or if any of `axes` isn't an axis of `shape`
+fn verify_axes<T>(buffer: &[T], shape: &[usize], axes: &[usize]) {
+    for &axis in axes {
+        assert!(axis < shape.len(), "Axis {} is out of bounds for an array with {} dimensions", axis, shape.len());
+    }
+    assert_eq!(buffer.len(), shape.iter().product::<usize>(),
+        "Buffer is the wrong length for the given shape. Expected {}, got {}", shape.iter().product::<usize>(), buffer.len());
+}
+
+/// Cyclically shifts every element of a row-major array `shift` places forward along `axis`. The caller must have
+/// checked `axis` and `shape` with `verify_axes`
+fn roll_axis<T>(buffer: &mut [T], shape: &[usize], axis: usize, shift: usize) {
+    // every index along `axis` corresponds to a contiguous block of `stride` elements, and each complete pass along the
+    // axis is a contiguous chunk of `axis_len` blocks. so rolling along the axis is just rotating each chunk
+    let stride: usize = shape[axis + 1..].iter().product();
+    let chunk_len = shape[axis] * stride;
+    if chunk_len > 0 {
+        for chunk in buffer.chunks_mut(chunk_len) {
+            chunk.rotate_right(shift * stride);
+        }
+    }
+}
+
+/// Returns the frequency corresponding to each element of the output of a FFT of size `len`
+///
+/// `sample_spacing` is the time between samples of the input (ie the inverse of the sample rate). The frequencies are
+/// in cycles per unit of `sample_spacing`, in the same order as the FFT output: Zero, then the positive frequencies,
+/// then the negative frequencies. For even lengths, the Nyquist frequency is reported as negative.
+///
+/// ~~~
+/// use rustfft::spectrum::fftfreq;
+///
+/// // 8 samples taken at a sample rate of 100Hz
+/// let frequencies = fftfreq(8, 0.01f64);
+/// assert_eq!(frequencies, vec![0.0, 12.5, 25.0, 37.5, -50.0, -37.5, -25.0, -12.5]);
+/// ~~~
+pub fn fftfreq<T: FFTnum>(len: usize, sample_spacing: T) -> Vec<T> {
+    let scale = T::one() / (sample_spacing * T::from_usize(len).unwrap());
+    let positive_len = len - len / 2;
+
+    let positive = (0..positive_len).map(|i| T::from_usize(i).unwrap() * scale);
+    let negative = (positive_len..len).map(|i| -T::from_usize(len - i).unwrap() * scale);
+    positive.chain(negative).collect()
+}
+
+/// Returns the frequency corresponding to each of the first `len / 2 + 1` elements of the output of a FFT of size `len`
+///
+/// These are the non-negative frequencies, which is all that's needed to describe the spectrum of a real-valued
+/// signal. See [`fftfreq`](fn.fftfreq.html) for a description of `sample_spacing`. Unlike `fftfreq`, for even lengths
+/// the Nyquist frequency is reported as positive.
+///
+/// ~~~
+/// use rustfft::spectrum::rfftfreq;
+///
+/// // 8 samples taken at a sample rate of 100Hz
+/// let frequencies = rfftfreq(8, 0.01f64);
+/// assert_eq!(frequencies, vec![0.0, 12.5, 25.0, 37.5, 50.0]);
+/// ~~~
+pub fn rfftfreq<T: FFTnum>(len: usize, sample_spacing: T) -> Vec<T> {
+    if len == 0 {
+        return Vec::new();
+    }
+    let scale = T::one() / (sample_spacing * T::from_usize(len).unwrap());
+
+    (0..len / 2 + 1).map(|i| T::from_usize(i).unwrap() * scale).collect()
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn test_fftshift() {
+        let test_list: Vec<(Vec<i32>, Vec<i32>)> = vec![
+            (vec![], vec![]),
+            (vec![0], vec![0]),
+            (vec![0, -1], vec![-1, 0]),
+            (vec![0, 1, -1], vec![-1, 0, 1]),
+            (vec![0, 1, 2, -3, -2, -1], vec![-3, -2, -1, 0, 1, 2]),
+            (vec![0, 1, 2, 3, -3, -2, -1], vec![-3, -2, -1, 0, 1, 2, 3]),
+        ];
+
+        for (input, expected) in test_list {
+            let mut output = vec![0; input.len()];
+            fftshift(&input, &mut output);
+            assert_eq!(output, expected);
+
+            // ifftshift should undo the shift for both even and odd lengths
+            let mut roundtrip = vec![0; input.len()];
+            ifftshift(&output, &mut roundtrip);
+            assert_eq!(roundtrip, input);
+
+            let mut inplace = input.clone();
+            fftshift_inplace(&mut inplace);
+            assert_eq!(inplace, expected);
+            ifftshift_inplace(&mut inplace);
+            assert_eq!(inplace, input);
+        }
+    }
+
+    #[test]
+    fn test_fftshift_axes() {
+        // a 3x4 array
+        let input: Vec<i32> = (0..12).collect();
+
+        let mut rows = vec![0; 12];
+        fftshift_axes(&input, &mut rows, &[3, 4], &[0]);
+        assert_eq!(rows, vec![8, 9, 10, 11, 0, 1, 2, 3, 4, 5, 6, 7]);
+
+        let mut columns = vec![0; 12];
+        fftshift_axes(&input, &mut columns, &[3, 4], &[1]);
+        assert_eq!(columns, vec![2, 3, 0, 1, 6, 7, 4, 5, 10, 11, 8, 9]);
+
+        let mut both = vec![0; 12];
+        fftshift_axes(&input, &mut both, &[3, 4], &[0, 1]);
+        assert_eq!(both, vec![10, 11, 8, 9, 2, 3, 0, 1, 6, 7, 4, 5]);
+
+        let mut roundtrip = vec![0; 12];
+        ifftshift_axes(&both, &mut roundtrip, &[3, 4], &[0, 1]);
+        assert_eq!(roundtrip, input);
+
+        // a 3D array, shifting only the middle axis
+        let input: Vec<i32> = (0..2 * 3 * 2).collect();
+        let mut buffer = input.clone();
+        fftshift_axes_inplace(&mut buffer, &[2, 3, 2], &[1]);
+        assert_eq!(buffer, vec![4, 5, 0, 1, 2, 3, 10, 11, 6, 7, 8, 9]);
+        ifftshift_axes_inplace(&mut buffer, &[2, 3, 2], &[1]);
+        assert_eq!(buffer, input);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fftshift_axes_wrong_shape() {
+        let mut buffer = vec![0; 12];
+        fftshift_axes_inplace(&mut buffer, &[3, 5], &[0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Axis 2 is out of bounds for an array with 2 dimensions")]
+    fn test_fftshift_axes_out_of_bounds() {
+        let mut buffer = vec![0; 12];
+        fftshift_axes_inplace(&mut buffer, &[3, 4], &[2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Axis 2 is out of bounds for an array with 2 dimensions")]
+    fn test_ifftshift_axes_out_of_bounds() {
+        let mut buffer = vec![0; 12];
+        ifftshift_axes_inplace(&mut buffer, &[3, 4], &[0, 2]);
+    }
+
+    #[test]
+    fn test_fftfreq() {
+        assert_eq!(fftfreq(1, 1f32), vec![0f32]);
+        assert_eq!(fftfreq(4, 1f32), vec![0f32, 0.25, -0.5, -0.25]);
+        assert_eq!(fftfreq(5, 0.5f64), vec![0f64, 0.4, 0.8, -0.8, -0.4]);
+
+        assert_eq!(rfftfreq(1, 1f32), vec![0f32]);
+        assert_eq!(rfftfreq(4, 1f32), vec![0f32, 0.25, 0.5]);
+        assert_eq!(rfftfreq(5, 0.5f64), vec![0f64, 0.4, 0.8]);
+
+        let empty: Vec<f32> = Vec::new();
+        assert_eq!(fftfreq(0, 1f32), empty);
+        assert_eq!(rfftfreq(0, 1f32), empty);
+    }
+}
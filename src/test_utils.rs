@@ -1,7 +1,8 @@
 use num_complex::Complex;
 use num_traits::Zero;
 
-use std::sync::Arc;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
 
 use rand::{StdRng, SeedableRng};
 use rand::distributions::{Normal, Distribution};
@@ -31,7 +32,7 @@ pub fn compare_vectors(vec1: &[Complex<f32>], vec2: &[Complex<f32>]) -> bool {
     assert_eq!(vec1.len(), vec2.len());
     let mut sse = 0f32;
     for (&a, &b) in vec1.iter().zip(vec2.iter()) {
-        sse = sse + (a - b).norm();
+        sse = sse + (a - b).norm_sqr().sqrt();
     }
     return (sse / vec1.len() as f32) < 0.1f32;
 }
@@ -67,15 +68,5 @@ pub fn check_fft_algorithm(fft: &FFT<f32>, size: usize, inverse: bool) {
 }
 
 pub fn make_butterfly(len: usize, inverse: bool) -> Arc<butterflies::FFTButterfly<f32>> {
-    match len {
-        2 => Arc::new(butterflies::Butterfly2::new(inverse)),
-        3 => Arc::new(butterflies::Butterfly3::new(inverse)),
-        4 => Arc::new(butterflies::Butterfly4::new(inverse)),
-        5 => Arc::new(butterflies::Butterfly5::new(inverse)),
-        6 => Arc::new(butterflies::Butterfly6::new(inverse)),
-        7 => Arc::new(butterflies::Butterfly7::new(inverse)),
-        8 => Arc::new(butterflies::Butterfly8::new(inverse)),
-        16 => Arc::new(butterflies::Butterfly16::new(inverse)),
-        _ => panic!("Invalid butterfly size: {}", len),
-    }
+    butterflies::make_butterfly(len, inverse)
 }
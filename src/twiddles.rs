@@ -1,8 +1,9 @@
 
-use std::f64;
+use core::f64;
+use alloc::vec::Vec;
 
 use num_complex::Complex;
-use num_traits::{FromPrimitive, One};
+use num_traits::FromPrimitive;
 
 use common::FFTnum;
 
@@ -10,22 +11,77 @@ pub fn generate_twiddle_factors<T: FFTnum>(fft_len: usize, inverse: bool) -> Vec
     (0..fft_len).map(|i| single_twiddle(i, fft_len, inverse)).collect()
 }
 
+/// Computes twiddle(i, fft_len) = e^(-2πi * i / fft_len) for forward FFTs, or its conjugate for inverse FFTs
+///
+/// Computing the angle `2π * i / fft_len` directly and passing it to sin/cos loses precision as `i` grows, because
+/// the rounding error of the angle is proportional to its magnitude. Instead, we use exact integer arithmetic to find
+/// which octant of the unit circle the twiddle factor is in, and compute sin and cos of an angle in [0, π/4]. The
+/// rest of the circle is reconstructed using symmetry, which is exact, so multiples of π/4 are exact as well.
 #[inline(always)]
 pub fn single_twiddle<T: FFTnum>(i: usize, fft_len: usize, inverse: bool) -> Complex<T> {
-    let constant = if inverse {
-        2f64 * f64::consts::PI
-    } else {
-        -2f64 * f64::consts::PI
-    };
+    let (cos, sin) = unit_circle_point(i, fft_len);
 
-    let c = Complex::from_polar(&One::one(), &(constant * i as f64 / fft_len as f64));
+    // forward FFTs rotate clockwise, inverse FFTs rotate counterclockwise
+    let im = if inverse { sin } else { -sin };
 
     Complex {
-        re: FromPrimitive::from_f64(c.re).unwrap(),
-        im: FromPrimitive::from_f64(c.im).unwrap(),
+        re: FromPrimitive::from_f64(cos).unwrap(),
+        im: FromPrimitive::from_f64(im).unwrap(),
+    }
+}
+
+/// Returns (cos(2π * i / len), sin(2π * i / len)), computed with octant symmetry
+fn unit_circle_point(i: usize, len: usize) -> (f64, f64) {
+    // the angle is (octant + remainder / len) * π/4. u128 can't overflow, even when multiplying usize::MAX by 8
+    let scaled_index = (i as u128 % len as u128) * 8;
+    let octant = (scaled_index / len as u128) as usize;
+    let remainder = (scaled_index % len as u128) as u64;
+
+    // in even octants, measure the angle from the start of the octant. in odd octants, measure from the end, so that
+    // the angle we pass to sin/cos is always in [0, π/4] and the nearest multiple of π/2 is always exact
+    let (cos, sin) = if octant % 2 == 0 {
+        if remainder == 0 {
+            (1f64, 0f64)
+        } else {
+            octant_point(remainder as f64 / len as f64)
+        }
+    } else {
+        if remainder == 0 {
+            (f64::consts::FRAC_1_SQRT_2, f64::consts::FRAC_1_SQRT_2)
+        } else {
+            octant_point((len as u64 - remainder) as f64 / len as f64)
+        }
+    };
+
+    match octant {
+        0 => (cos, sin),
+        1 => (sin, cos),
+        2 => (-sin, cos),
+        3 => (-cos, sin),
+        4 => (-cos, -sin),
+        5 => (-sin, -cos),
+        6 => (sin, -cos),
+        _ => (cos, -sin),
     }
 }
 
+/// Returns (cos, sin) of `fraction` * π/4, where `fraction` is in [0, 1]
+#[cfg(feature = "std")]
+#[inline(always)]
+fn octant_point(fraction: f64) -> (f64, f64) {
+    let angle = fraction * f64::consts::FRAC_PI_4;
+    (angle.cos(), angle.sin())
+}
+
+/// Returns (cos, sin) of `fraction` * π/4, where `fraction` is in [0, 1]. Without std, f64 has no trig methods, so
+/// this uses libm's
+#[cfg(not(feature = "std"))]
+#[inline(always)]
+fn octant_point(fraction: f64) -> (f64, f64) {
+    let angle = fraction * f64::consts::FRAC_PI_4;
+    (::libm::cos(angle), ::libm::sin(angle))
+}
+
 pub fn rotate_90<T: FFTnum>(value: Complex<T>, inverse:bool) -> Complex<T>
 {
     if inverse {
@@ -38,7 +94,7 @@ pub fn rotate_90<T: FFTnum>(value: Complex<T>, inverse:bool) -> Complex<T>
 #[cfg(test)]
 mod unit_tests {
 	use super::*;
-	use std::f32;
+	use core::{f32, f64};
     use test_utils::{compare_vectors};
 
     #[test]
@@ -51,7 +107,10 @@ mod unit_tests {
 
         for len in 1..10 {
             let actual: Vec<Complex<f32>> = generate_twiddle_factors(len, false);
-            let expected: Vec<Complex<f32>> = (0..len).map(|i| Complex::from_polar(&1f32, &(constant * i as f32 / len as f32))).collect();
+            let expected: Vec<Complex<f32>> = (0..len).map(|i| {
+                let angle = constant * i as f32 / len as f32;
+                Complex::new(angle.cos(), angle.sin())
+            }).collect();
 
             assert!(compare_vectors(&actual, &expected), "len = {}", len)
         }
@@ -70,6 +129,41 @@ mod unit_tests {
         }
     }
 
+    #[test]
+    fn test_octant_symmetry() {
+        // multiples of π/4 should be exact
+        let len = 8 * 12345;
+        let expected = [
+            (1f64, 0f64),
+            (f64::consts::FRAC_1_SQRT_2, -f64::consts::FRAC_1_SQRT_2),
+            (0f64, -1f64),
+            (-f64::consts::FRAC_1_SQRT_2, -f64::consts::FRAC_1_SQRT_2),
+            (-1f64, 0f64),
+            (-f64::consts::FRAC_1_SQRT_2, f64::consts::FRAC_1_SQRT_2),
+            (0f64, 1f64),
+            (f64::consts::FRAC_1_SQRT_2, f64::consts::FRAC_1_SQRT_2),
+        ];
+        for (octant, &(re, im)) in expected.iter().enumerate() {
+            let twiddle: Complex<f64> = single_twiddle(octant * len / 8, len, false);
+            assert_eq!(twiddle, Complex::new(re, im), "octant = {}", octant);
+        }
+
+        // twiddle(len - i) should be exactly the conjugate of twiddle(i), and every twiddle should be on the unit circle
+        for &len in &[7, 30, 1024, 65537, 1 << 24] {
+            let step = if len > 10000 { 997 } else { 1 };
+            for i in (1..len).filter(|i| i % step == 0) {
+                let twiddle: Complex<f64> = single_twiddle(i, len, false);
+                let mirrored: Complex<f64> = single_twiddle(len - i, len, false);
+                assert_eq!(twiddle, mirrored.conj(), "len = {}, i = {}", len, i);
+                assert!((twiddle.norm_sqr().sqrt() - 1f64).abs() < 1e-15, "len = {}, i = {}", len, i);
+            }
+        }
+
+        // indexes beyond the length should wrap around
+        let wrapped: Complex<f64> = single_twiddle(1000 + 3, 1000, false);
+        assert_eq!(wrapped, single_twiddle(3, 1000, false));
+    }
+
     #[test]
     fn test_single() {
         let len = 20;
@@ -30,7 +30,7 @@ fn compare_vectors(vec1: &[Complex<f32>], vec2: &[Complex<f32>]) -> bool {
     assert_eq!(vec1.len(), vec2.len());
     let mut sse = 0f32;
     for (&a, &b) in vec1.iter().zip(vec2.iter()) {
-        sse = sse + (a - b).norm();
+        sse = sse + (a - b).norm_sqr().sqrt();
     }
     return (sse / vec1.len() as f32) < 0.1f32;
 }